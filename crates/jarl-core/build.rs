@@ -0,0 +1,69 @@
+//! Extract the documentation of each rule from the doc comments of its lint
+//! so that it can be displayed by `jarl rule <name>`.
+//!
+//! This follows the same conventions as `docs/make_docs.R`: the docs of a rule
+//! named `foo` live in `src/lints/**/foo.rs`, start at the `## What it does`
+//! heading, and end at the first line that is not a doc comment.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/lints");
+
+    let mut files = Vec::new();
+    collect_rs_files(Path::new("src/lints"), &mut files);
+    files.sort();
+
+    let mut output = String::from("pub(crate) static RULE_DOCS: &[(&str, &str, &str)] = &[\n");
+
+    for file in files {
+        let Some(name) = file.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let content = fs::read_to_string(&file).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+
+        let Some(start) = lines.iter().position(|l| l.trim() == "/// ## What it does") else {
+            continue;
+        };
+
+        let version_added = lines
+            .iter()
+            .find_map(|l| l.trim().strip_prefix("/// Version added:"))
+            .map(str::trim)
+            .unwrap_or_default();
+
+        let doc = lines[start..]
+            .iter()
+            .map(|l| l.trim())
+            .take_while(|l| l.starts_with("///"))
+            .map(|l| {
+                let l = l.strip_prefix("///").unwrap();
+                l.strip_prefix(' ').unwrap_or(l)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        writeln!(output, "    ({name:?}, {version_added:?}, {doc:?}),").unwrap();
+    }
+
+    output.push_str("];\n");
+
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("rule_docs.rs"), output).unwrap();
+}
+
+fn collect_rs_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_rs_files(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "rs")
+            && path.file_name().is_some_and(|name| name != "mod.rs")
+        {
+            files.push(path);
+        }
+    }
+}
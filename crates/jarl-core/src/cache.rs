@@ -0,0 +1,235 @@
+//! Persistent cache of lint results.
+//!
+//! The diagnostics of each file are stored on disk with a key that depends on
+//! everything that can change them: the content of the file, the resolved
+//! configuration, the package information (DESCRIPTION, NAMESPACE, and
+//! cross-file analyses), the installed R packages used by package-specific
+//! rules, and the version of Jarl. If the key of a file didn't change since
+//! the last run, its diagnostics are reused without parsing it.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::config::Config;
+use crate::diagnostic::Diagnostic;
+use crate::hash::fnv1a_hash;
+use crate::package::{FilePackageInfo, PackageAnalysis, PackageContext};
+
+/// Name of the cache directory, created in the current working directory.
+pub const CACHE_DIR_NAME: &str = ".jarl_cache";
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    key: String,
+    diagnostics: Vec<Diagnostic>,
+}
+
+pub struct LintCache {
+    /// File in which the cache is stored. It is specific to the version of
+    /// Jarl so that upgrading doesn't read results of an older version.
+    path: PathBuf,
+    /// Cached diagnostics, keyed by the relativized path of the linted file.
+    entries: RwLock<HashMap<String, CacheEntry>>,
+    /// Whether some entries were added or updated since the cache was loaded.
+    modified: AtomicBool,
+}
+
+impl LintCache {
+    /// Load the cache stored in `cache_dir`. A missing or invalid cache is
+    /// treated as empty.
+    pub fn load(cache_dir: &Path) -> Self {
+        let path = cache_dir.join(env!("CARGO_PKG_VERSION")).join("lint.json");
+
+        let mut entries: HashMap<String, CacheEntry> = fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+
+        // Forget files that were deleted or renamed, otherwise the cache
+        // would keep growing.
+        let n_entries = entries.len();
+        entries.retain(|file, _| Path::new(file).exists());
+
+        Self {
+            path,
+            modified: AtomicBool::new(entries.len() < n_entries),
+            entries: RwLock::new(entries),
+        }
+    }
+
+    /// Write the cache to disk if it was modified.
+    pub fn save(&self) -> Result<()> {
+        if !self.modified.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        let Some(version_dir) = self.path.parent() else {
+            return Ok(());
+        };
+        fs::create_dir_all(version_dir)
+            .with_context(|| format!("Failed to create {}", version_dir.display()))?;
+
+        if let Some(cache_dir) = version_dir.parent() {
+            // Keep the cache out of version control without requiring users
+            // to edit their own `.gitignore`.
+            let gitignore = cache_dir.join(".gitignore");
+            if !gitignore.exists() {
+                fs::write(&gitignore, "# Automatically created by Jarl.\n*\n")?;
+            }
+
+            // Results of other versions of Jarl are never read again.
+            for entry in fs::read_dir(cache_dir)?.flatten() {
+                let other = entry.path();
+                if other.is_dir() && other != version_dir {
+                    let _ = fs::remove_dir_all(&other);
+                }
+            }
+        }
+
+        let entries = self.entries.read().unwrap();
+        // Sort entries so that the file doesn't change when results don't.
+        let entries: BTreeMap<&String, &CacheEntry> = entries.iter().collect();
+        let contents = serde_json::to_string(&entries)?;
+
+        // Write to a temporary file first so that an interrupted run doesn't
+        // leave a corrupted cache.
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, contents).with_context(|| format!("Failed to write {}", tmp.display()))?;
+        fs::rename(&tmp, &self.path)
+            .with_context(|| format!("Failed to write {}", self.path.display()))?;
+
+        Ok(())
+    }
+
+    /// Get the cached diagnostics of `path` if they were computed with the
+    /// same `key`.
+    pub fn get(&self, path: &str, key: &str) -> Option<Vec<Diagnostic>> {
+        let entries = self.entries.read().unwrap();
+        entries
+            .get(path)
+            .filter(|entry| entry.key == key)
+            .map(|entry| entry.diagnostics.clone())
+    }
+
+    pub fn insert(&self, path: String, key: String, diagnostics: Vec<Diagnostic>) {
+        let mut entries = self.entries.write().unwrap();
+        entries.insert(path, CacheEntry { key, diagnostics });
+        self.modified.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Remove the cache directory in `dir`. Returns `false` if there was no cache.
+pub fn clean_cache(dir: &Path) -> Result<bool> {
+    let cache_dir = dir.join(CACHE_DIR_NAME);
+    if !cache_dir.exists() {
+        return Ok(false);
+    }
+    fs::remove_dir_all(&cache_dir)
+        .with_context(|| format!("Failed to remove {}", cache_dir.display()))?;
+    Ok(true)
+}
+
+/// Compute the cache key of a file.
+///
+/// Returns `None` if the results can't be cached.
+pub(crate) fn cache_key(
+    contents: &str,
+    file: &Path,
+    config: &Config,
+    pkg: &PackageAnalysis,
+    pkg_contexts: &HashMap<PathBuf, PackageContext>,
+    file_pkg_info: &HashMap<PathBuf, FilePackageInfo>,
+) -> Option<String> {
+    // All the settings that can change the diagnostics of the file. Settings
+    // that can differ between files are taken from the overrides of `file`.
+    let settings = serde_json::to_string(&(
+        config,
+        config.rules_for(file),
+        config.rule_options_for(file).as_ref(),
+    ))
+    .ok()?;
+
+    // Package information of the file: DESCRIPTION and NAMESPACE of its
+    // package, and the results of cross-file analyses that concern it.
+    let file_info = file_pkg_info.get(file);
+    let package = match file_info {
+        Some(FilePackageInfo::InPackage { package_root, .. }) => pkg_contexts
+            .get(package_root)
+            .map(|ctx| {
+                format!(
+                    "{:?}\n{:?}",
+                    ctx.loaded_packages,
+                    ctx.namespace_content.as_deref().unwrap_or_default()
+                )
+            })
+            .unwrap_or_default(),
+        _ => String::new(),
+    };
+    let cross_file = format!(
+        "{:?}\n{:?}\n{:?}",
+        file_info,
+        pkg.duplicate_assignments.get(file),
+        pkg.unused_functions.get(file)
+    );
+
+    // Installed R packages used by package-specific rules.
+    let installed = config
+        .package_cache
+        .as_ref()
+        .map(|cache| cache.fingerprint())
+        .unwrap_or_default();
+
+    let key = fnv1a_hash(&[
+        env!("CARGO_PKG_VERSION"),
+        &file.to_string_lossy(),
+        contents,
+        &settings,
+        &package,
+        &cross_file,
+        &installed,
+    ]);
+
+    Some(format!("{key:016x}"))
+}
+
+/// Serialize a set in sorted order. The iteration order of a `HashSet`
+/// changes from one run to another, which would change the cache keys.
+pub(crate) fn sorted_set<S: Serializer>(
+    set: &HashSet<String>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(set.iter().collect::<BTreeSet<_>>())
+}
+
+/// Same as `sorted_set()`, for optional sets.
+pub(crate) fn sorted_optional_set<S: Serializer>(
+    set: &Option<HashSet<String>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    set.as_ref()
+        .map(|set| set.iter().collect::<BTreeSet<_>>())
+        .serialize(serializer)
+}
+
+/// Serialize a map in sorted order, see `sorted_set()`.
+pub(crate) fn sorted_map<V: Serialize, S: Serializer>(
+    map: &HashMap<String, V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(map.iter().collect::<BTreeMap<_, _>>())
+}
+
+/// Serialize regular expressions as their pattern.
+pub(crate) fn regex_patterns<S: Serializer>(
+    regexes: &[Regex],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(regexes.iter().map(Regex::as_str))
+}
@@ -1,3 +1,4 @@
+use crate::cache::cache_key;
 use crate::error::ParseError;
 use crate::package::{
     FilePackageInfo, FileScope, PackageAnalysis, PackageContext, make_package_analysis,
@@ -25,7 +26,15 @@ use crate::diagnostic::*;
 use crate::fix::*;
 use crate::utils::*;
 
-pub fn check(config: Config) -> Vec<(String, Result<Vec<Diagnostic>, anyhow::Error>)> {
+type PackageInfo = (
+    PackageAnalysis,
+    HashMap<PathBuf, PackageContext>,
+    HashMap<PathBuf, FilePackageInfo>,
+);
+
+/// Gather the package context (DESCRIPTION, NAMESPACE, cross-file analysis)
+/// of all the paths in `config`.
+fn package_info(config: &Config) -> PackageInfo {
     let (pkg_contexts, file_pkg_info) = summarize_package_info(&config.paths);
 
     let namespace_contents: HashMap<PathBuf, String> = pkg_contexts
@@ -36,10 +45,12 @@ pub fn check(config: Config) -> Vec<(String, Result<Vec<Diagnostic>, anyhow::Err
                 .map(|c| (root.clone(), c.clone()))
         })
         .collect();
-    let pkg = make_package_analysis(&config.paths, &config, &namespace_contents);
-    let pkg_contexts = Arc::new(pkg_contexts);
-    let file_pkg_info = Arc::new(file_pkg_info);
+    let pkg = make_package_analysis(&config.paths, config, &namespace_contents);
 
+    (pkg, pkg_contexts, file_pkg_info)
+}
+
+pub fn check(config: Config) -> Vec<(String, Result<Vec<Diagnostic>, anyhow::Error>)> {
     // Ensure that all paths are covered by VCS. This is conservative because
     // technically we could apply fixes on those that are covered by VCS and
     // error for the others, but I'd rather be on the safe side and force the
@@ -52,6 +63,32 @@ pub fn check(config: Config) -> Vec<(String, Result<Vec<Diagnostic>, anyhow::Err
         }
     }
 
+    map_paths(config, check_path)
+}
+
+/// Compute the fixes of all paths in `config` without writing them to disk.
+///
+/// This returns the original and the fixed content of each file. Since files
+/// are left untouched, there is no need to check the VCS status here.
+pub fn preview_fixes(config: Config) -> Vec<(String, Result<(String, String), anyhow::Error>)> {
+    map_paths(config, preview_path)
+}
+
+/// Run `f` on all paths of `config` in parallel.
+fn map_paths<T: Send>(
+    config: Config,
+    f: fn(
+        &PathBuf,
+        Arc<Config>,
+        Arc<PackageAnalysis>,
+        Arc<HashMap<PathBuf, PackageContext>>,
+        Arc<HashMap<PathBuf, FilePackageInfo>>,
+    ) -> Result<T, anyhow::Error>,
+) -> Vec<(String, Result<T, anyhow::Error>)> {
+    let (pkg, pkg_contexts, file_pkg_info) = package_info(&config);
+    let pkg_contexts = Arc::new(pkg_contexts);
+    let file_pkg_info = Arc::new(file_pkg_info);
+
     // Wrap config and package analysis in Arc to avoid expensive clones in parallel execution
     let config = Arc::new(config);
     let pkg = Arc::new(pkg);
@@ -60,7 +97,7 @@ pub fn check(config: Config) -> Vec<(String, Result<Vec<Diagnostic>, anyhow::Err
         .paths
         .par_iter()
         .map(|file| {
-            let res = check_path(
+            let res = f(
                 file,
                 Arc::clone(&config),
                 Arc::clone(&pkg),
@@ -104,9 +141,29 @@ pub fn lint_only(
         return Ok(Vec::new());
     }
 
+    let file = PathBuf::from(&path);
+
+    // Reuse the diagnostics of the previous run if neither the file nor the
+    // settings changed.
+    let cache_key = config.lint_cache.as_ref().and_then(|_| {
+        cache_key(
+            &contents,
+            &file,
+            &config,
+            &pkg,
+            &pkg_contexts,
+            &file_pkg_info,
+        )
+    });
+    if let (Some(cache), Some(key)) = (&config.lint_cache, &cache_key)
+        && let Some(checks) = cache.get(&path, key)
+    {
+        return Ok(checks);
+    }
+
     let checks = get_checks(
         &contents,
-        &PathBuf::from(&path),
+        &file,
         &config,
         &pkg,
         &pkg_contexts,
@@ -114,6 +171,10 @@ pub fn lint_only(
     )
     .with_context(|| format!("Failed to get checks for file: {path}"))?;
 
+    if let (Some(cache), Some(key)) = (&config.lint_cache, cache_key) {
+        cache.insert(path, key, checks.clone());
+    }
+
     Ok(checks)
 }
 
@@ -130,33 +191,121 @@ pub fn lint_fix(
     }
 
     let path = relativize_path(path);
+    let contents = fs::read_to_string(Path::new(&path))
+        .with_context(|| format!("Failed to read file: {path}",))?;
 
-    let mut checks: Vec<Diagnostic>;
+    // Skip auto-generated files: no diagnostics, no fixes.
+    if crate::fs::looks_generated(&contents) {
+        return Ok(Vec::new());
+    }
 
-    loop {
-        let contents = fs::read_to_string(Path::new(&path))
-            .with_context(|| format!("Failed to read file: {path}",))?;
+    let (checks, fixed_text) = fix_in_memory(
+        contents.clone(),
+        &PathBuf::from(&path),
+        &config,
+        &pkg,
+        &pkg_contexts,
+        &file_pkg_info,
+        true,
+    )?;
 
-        // Skip auto-generated files: no diagnostics, no fixes.
-        if crate::fs::looks_generated(&contents) {
-            return Ok(Vec::new());
-        }
+    if fixed_text != contents {
+        fs::write(&path, fixed_text).with_context(|| format!("Failed to write file: {path}",))?;
+    }
 
-        checks = get_checks(
-            &contents,
-            &PathBuf::from(&path),
-            &config,
-            &pkg,
-            &pkg_contexts,
-            &file_pkg_info,
-        )
-        .with_context(|| format!("Failed to get checks for file: {path}",))?;
+    Ok(checks)
+}
+
+/// Compute the fixed content of `path` without writing it to disk. This
+/// returns the original and the fixed content.
+pub fn preview_path(
+    path: &PathBuf,
+    config: Arc<Config>,
+    pkg: Arc<PackageAnalysis>,
+    pkg_contexts: Arc<HashMap<PathBuf, PackageContext>>,
+    file_pkg_info: Arc<HashMap<PathBuf, FilePackageInfo>>,
+) -> Result<(String, String), anyhow::Error> {
+    let path = relativize_path(path);
+    let contents = fs::read_to_string(Path::new(&path))
+        .with_context(|| format!("Failed to read file: {path}"))?;
+
+    // Rmd/Qmd files never get autofixes applied, and auto-generated files are
+    // skipped.
+    let file = PathBuf::from(&path);
+    if crate::fs::has_rmd_extension(&file) || crate::fs::looks_generated(&contents) {
+        return Ok((contents.clone(), contents));
+    }
+
+    let (_, fixed) = fix_in_memory(
+        contents.clone(),
+        &file,
+        &config,
+        &pkg,
+        &pkg_contexts,
+        &file_pkg_info,
+        true,
+    )?;
+
+    Ok((contents, fixed))
+}
+
+/// Lint `contents` as if they were the content of the single path in
+/// `config.paths`. This is used when reading code from stdin: the path
+/// doesn't need to exist but it is used to resolve the package context.
+///
+/// Fixes are applied in memory only. This returns the remaining diagnostics
+/// and the (possibly fixed) source code.
+pub fn check_source(config: Config, contents: String) -> Result<(Vec<Diagnostic>, String)> {
+    let Some(path) = config.paths.first() else {
+        return Err(anyhow::anyhow!("No path was provided for the source code."));
+    };
+    let file = PathBuf::from(relativize_path(path));
+
+    if crate::fs::looks_generated(&contents) {
+        return Ok((Vec::new(), contents));
+    }
+
+    let (pkg, pkg_contexts, file_pkg_info) = package_info(&config);
+
+    // Rmd/Qmd files never get autofixes applied.
+    let should_fix =
+        (config.apply_fixes || config.apply_unsafe_fixes) && !crate::fs::has_rmd_extension(&file);
+
+    fix_in_memory(
+        contents,
+        &file,
+        &config,
+        &pkg,
+        &pkg_contexts,
+        &file_pkg_info,
+        should_fix,
+    )
+}
+
+/// Lint `contents` and, if `should_fix` is `true`, apply fixes until there is
+/// nothing left to fix. Nothing is written to disk. This returns the remaining
+/// diagnostics and the (possibly fixed) source code.
+///
+/// This is shared by `--fix`, `--diff`, and stdin so that they always produce
+/// the same fixes.
+fn fix_in_memory(
+    mut contents: String,
+    file: &Path,
+    config: &Config,
+    pkg: &PackageAnalysis,
+    pkg_contexts: &HashMap<PathBuf, PackageContext>,
+    file_pkg_info: &HashMap<PathBuf, FilePackageInfo>,
+    should_fix: bool,
+) -> Result<(Vec<Diagnostic>, String)> {
+    loop {
+        let checks = get_checks(&contents, file, config, pkg, pkg_contexts, file_pkg_info)
+            .with_context(|| format!("Failed to get checks for file: {}", file.display()))?;
 
         let has_fixable = checks
             .iter()
             .any(|d| d.has_safe_fix() || d.has_unsafe_fix());
-        if !has_fixable {
-            break;
+        if !should_fix || !has_fixable {
+            return Ok((checks, contents));
         }
 
         let fixed_text = apply_fixes(&checks, &contents);
@@ -164,13 +313,11 @@ pub fn lint_fix(
         // No progress was made (e.g. all fixes overlap), stop to avoid an
         // infinite loop.
         if fixed_text == contents {
-            break;
+            return Ok((checks, contents));
         }
 
-        fs::write(&path, fixed_text).with_context(|| format!("Failed to write file: {path}",))?;
+        contents = fixed_text;
     }
-
-    Ok(checks)
 }
 
 // Takes the R code as a string, parses it, and obtains a (possibly empty)
@@ -200,10 +347,11 @@ pub fn get_checks(
     let syntax = &parsed.syntax();
     let expressions = &parsed.tree().expressions();
 
-    let suppression = SuppressionManager::from_node(syntax, contents);
+    let suppression =
+        SuppressionManager::from_node(syntax, contents, config.lintr_compat_suppressions);
 
-    let mut checker = Checker::new(suppression, config.rule_options.clone());
-    checker.rule_set = config.rules_to_apply.clone();
+    let mut checker = Checker::new(suppression, config.rule_options_for(file).clone());
+    checker.rule_set = config.rules_for(file).clone();
     checker.minimum_r_version = config.minimum_r_version;
 
     // Wire up package context for package-specific rules.
@@ -278,6 +426,10 @@ pub fn get_checks(
         .into_iter()
         .map(|mut x| {
             x.filename = file.to_path_buf();
+            // Apply severity overrides from `[lint.severity]`.
+            if let Some(severity) = config.severity.get(&x.message.name) {
+                x.severity = *severity;
+            }
             // Check if fix should be skipped based on fixable/unfixable settings
             if rules_without_fix.contains(&x.message.name) {
                 x.fix = Fix::empty();
@@ -363,10 +515,11 @@ fn get_checks_roxygen(
 
         let expressions = &parsed.tree().expressions();
         let syntax = parsed.syntax();
-        let suppression = SuppressionManager::from_node(&syntax, &chunk.code);
+        let suppression =
+            SuppressionManager::from_node(&syntax, &chunk.code, config.lintr_compat_suppressions);
         let has_suppressions = suppression.has_any_suppressions;
-        let mut checker = Checker::new(suppression, config.rule_options.clone());
-        checker.rule_set = config.rules_to_apply.clone();
+        let mut checker = Checker::new(suppression, config.rule_options_for(file).clone());
+        checker.rule_set = config.rules_for(file).clone();
         checker.minimum_r_version = config.minimum_r_version;
 
         for expr in expressions {
@@ -419,9 +572,10 @@ fn get_checks_rmd(contents: &str, file: &Path, config: &Config) -> Result<Vec<Di
     }
 
     let syntax = parsed.syntax();
-    let suppression = SuppressionManager::from_node(&syntax, &virtual_source);
-    let mut checker = Checker::new(suppression, config.rule_options.clone());
-    checker.rule_set = config.rules_to_apply.clone();
+    let suppression =
+        SuppressionManager::from_node(&syntax, &virtual_source, config.lintr_compat_suppressions);
+    let mut checker = Checker::new(suppression, config.rule_options_for(file).clone());
+    checker.rule_set = config.rules_for(file).clone();
     checker.minimum_r_version = config.minimum_r_version;
 
     let expressions = &parsed.tree().expressions();
@@ -441,6 +595,9 @@ fn get_checks_rmd(contents: &str, file: &Path, config: &Config) -> Result<Vec<Di
             d.filename = file.to_path_buf();
             d.fix = Fix::empty();
             d.range = offset_map.remap_range(d.range);
+            if let Some(severity) = config.severity.get(&d.message.name) {
+                d.severity = *severity;
+            }
             d
         })
         .collect();
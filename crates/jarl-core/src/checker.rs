@@ -137,11 +137,9 @@ impl Checker {
         }
     }
 
-    /// Look up the installed version of a package.
+    /// Look up the version of a package, either installed or recorded in
+    /// `renv.lock`.
     pub fn package_version(&self, pkg_name: &str) -> Option<(u32, u32, u32)> {
-        self.package_cache
-            .as_ref()?
-            .get(pkg_name)
-            .and_then(|info| info.version)
+        self.package_cache.as_ref()?.package_version(pkg_name)
     }
 }
@@ -1,14 +1,22 @@
 use crate::{
+    cache::{LintCache, sorted_map, sorted_optional_set, sorted_set},
     description::Description,
     lints::all_rules_enabled_by_default,
     package_cache::PackageCache,
     rule_options::ResolvedRuleOptions,
-    rule_set::{Category, Rule, RuleSet},
-    settings::Settings,
+    rule_set::{Category, Rule, RuleSet, Severity},
+    settings::{OverrideSettings, Settings},
 };
+use air_fs::relativize_path;
 use air_r_syntax::RSyntaxKind;
 use anyhow::Result;
-use std::{collections::HashSet, fs, path::PathBuf, sync::Arc};
+use serde::Serialize;
+use std::{
+    collections::{HashMap, HashSet, hash_map::Entry},
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use crate::rule_options::assignment::ResolvedAssignmentOptions;
 
@@ -48,11 +56,16 @@ pub struct ArgsConfig {
     pub allow_no_vcs: bool,
     /// Which assignment operator to use? Can be `"<-"` or `"="`.
     pub assignment: Option<String>,
+    /// Did the user pass the --preview flag?
+    pub preview: bool,
 }
 
-#[derive(Clone)]
+/// Resolved configuration. It is serialized to compute the keys of the lint
+/// cache, so fields that can't change diagnostics are skipped.
+#[derive(Clone, Serialize)]
 pub struct Config {
     /// Paths to files to lint.
+    #[serde(skip)]
     pub paths: Vec<PathBuf>,
     /// List of rules and whether they have an associated safe fix, passed by
     /// the user and/or recovered from the config file. Those will
@@ -71,23 +84,122 @@ pub struct Config {
     /// grepv() introduced in R 4.5.0.
     pub minimum_r_version: Option<(u32, u32, u32)>,
     /// Apply fixes even if the Git branch still has uncommitted files?
+    #[serde(skip)]
     pub allow_dirty: bool,
     /// Apply fixes even if there is no version control system?
+    #[serde(skip)]
     pub allow_no_vcs: bool,
     /// Rules that should not have their fixes applied (from unfixable setting)
+    #[serde(serialize_with = "sorted_set")]
     pub unfixable: HashSet<String>,
     /// Rules that are allowed to have fixes applied (from fixable setting)
     /// None means all rules with fixes can be applied
+    #[serde(serialize_with = "sorted_optional_set")]
     pub fixable: Option<HashSet<String>>,
+    /// Severity overrides from `[lint.severity]`, keyed by rule name. Rules
+    /// that are absent use their default severity.
+    #[serde(serialize_with = "sorted_map")]
+    pub severity: HashMap<String, Severity>,
     /// Whether to lint R code inside roxygen `@examples` sections
     pub check_roxygen: bool,
     /// Whether to apply autofixes to roxygen examples
     pub fix_roxygen: bool,
-    /// Resolved per-rule options (wrapped in Arc to avoid expensive clones)
+    /// Whether lintr's `# nolint` comments suppress violations
+    pub lintr_compat_suppressions: bool,
+    /// Whether preview rules and behaviors are enabled, from `--preview` or
+    /// `preview` in `jarl.toml`
+    pub preview: bool,
+    /// Preview rules that were selected by name but are ignored because
+    /// preview is disabled
+    pub ignored_preview_rules: Vec<String>,
+    /// Resolved per-rule options (wrapped in Arc to avoid expensive clones).
+    /// The cache key uses `rule_options_for()` instead.
+    #[serde(skip)]
     pub rule_options: Arc<ResolvedRuleOptions>,
     /// Shared cache of installed R package metadata for package-specific rules.
     /// `None` if library path discovery was not performed (e.g., no package rules enabled).
+    #[serde(skip)]
     pub package_cache: Option<Arc<PackageCache>>,
+    /// Persistent cache of lint results. `None` if results shouldn't be
+    /// cached (e.g., `--no-cache` or when linting from the language server).
+    #[serde(skip)]
+    pub lint_cache: Option<Arc<LintCache>>,
+    /// Rules and rule options of the files matched by `[lint.per-file-ignores]`
+    /// or `[[lint.overrides]]`, keyed by their relativized path. Files that are
+    /// absent use `rules_to_apply` and `rule_options`. The cache key uses
+    /// `rules_for()` and `rule_options_for()` instead.
+    #[serde(skip)]
+    pub file_overrides: HashMap<PathBuf, Arc<FileOverride>>,
+}
+
+/// Settings of a file after applying the overrides that match it.
+#[derive(Clone, Debug)]
+pub struct FileOverride {
+    pub rules_to_apply: RuleSet,
+    pub rule_options: Arc<ResolvedRuleOptions>,
+}
+
+impl Config {
+    /// Rules to apply to `file`, taking per-file overrides into account.
+    pub fn rules_for(&self, file: &Path) -> &RuleSet {
+        match self.file_overrides.get(file) {
+            Some(file_override) => &file_override.rules_to_apply,
+            None => &self.rules_to_apply,
+        }
+    }
+
+    /// Rule options of `file`, taking per-file overrides into account.
+    pub fn rule_options_for(&self, file: &Path) -> &Arc<ResolvedRuleOptions> {
+        match self.file_overrides.get(file) {
+            Some(file_override) => &file_override.rule_options,
+            None => &self.rule_options,
+        }
+    }
+
+    /// Whether `rule` is applied to at least one file.
+    pub fn applies_rule(&self, rule: &Rule) -> bool {
+        self.rules_to_apply.contains(rule)
+            || self
+                .file_overrides
+                .values()
+                .any(|file_override| file_override.rules_to_apply.contains(rule))
+    }
+
+    /// Rules applied to at least one file, i.e. those of `rules_to_apply` and
+    /// of all per-file overrides.
+    pub fn all_rules_to_apply(&self) -> RuleSet {
+        let mut rules: Vec<Rule> = self.rules_to_apply.iter().copied().collect();
+        for file_override in self.file_overrides.values() {
+            for rule in file_override.rules_to_apply.iter() {
+                if !rules.contains(rule) {
+                    rules.push(*rule);
+                }
+            }
+        }
+        RuleSet::from_rules(rules)
+    }
+
+    /// Remove the rules that don't match `predicate`, in all files.
+    pub fn retain_rules(&mut self, predicate: impl Fn(&Rule) -> bool) {
+        self.rules_to_apply = self
+            .rules_to_apply
+            .iter()
+            .filter(|r| predicate(r))
+            .collect();
+        for file_override in self.file_overrides.values_mut() {
+            let file_override = Arc::make_mut(file_override);
+            file_override.rules_to_apply = file_override
+                .rules_to_apply
+                .iter()
+                .filter(|r| predicate(r))
+                .collect();
+        }
+    }
+
+    /// Remove the rules that require a `PackageCache`, in all files.
+    pub fn remove_package_specific_rules(&mut self) {
+        self.retain_rules(|r| !r.categories().iter().any(|c| c.is_package_specific()));
+    }
 }
 
 pub fn build_config(
@@ -100,6 +212,11 @@ pub fn build_config(
     // selected.
     let minimum_r_version = determine_minimum_r_version(check_config, &paths)?;
 
+    let preview = check_config.preview
+        || toml_settings
+            .and_then(|s| s.linter.preview)
+            .unwrap_or(false);
+
     let rules_cli = parse_rules_cli(
         &check_config.select,
         &check_config.extend_select,
@@ -109,38 +226,29 @@ pub fn build_config(
     let rules = reconcile_rules(rules_cli, rules_toml)?;
 
     let rules = filter_rules_by_version(&rules, minimum_r_version);
+    let rules = filter_rules_by_preview(&rules, preview);
+
+    let named_rules: Vec<&str> = check_config
+        .select
+        .split(',')
+        .chain(check_config.extend_select.split(','))
+        .chain(
+            toml_settings
+                .into_iter()
+                .flat_map(|s| s.linter.select.iter().chain(s.linter.extend_select.iter()))
+                .flatten()
+                .map(String::as_str),
+        )
+        .collect();
+    let ignored_preview_rules = ignored_preview_rules(&named_rules, preview, Rule::is_preview);
 
     // Parse fixable/unfixable rules from TOML.
     // These will be stored in Config and checked when applying fixes.
     let (fixable_toml, unfixable_toml) = parse_fixable_toml(toml_settings)?;
 
-    // Resolve the interaction between --fix and --unsafe-fixes first. Using
-    // --unsafe-fixes implies using --fix, but the opposite is not true.
-    let rules_to_apply = match (check_config.fix, check_config.unsafe_fixes) {
-        (false, false) => rules.clone(),
-
-        (true, false) => rules
-            .iter()
-            .filter(|r| r.has_no_fix() || r.has_safe_fix())
-            .collect::<RuleSet>(),
-
-        (_, true) => rules
-            .iter()
-            .filter(|r| r.has_no_fix() || r.has_safe_fix() || r.has_unsafe_fix())
-            .collect::<RuleSet>(),
-    };
+    let severity = parse_severity_toml(toml_settings)?;
 
-    // We can now drop rules that don't have any fix if the user passed
-    // --fix-only. This could maybe be done above but dealing with the three
-    // args at the same time makes it much more complex.
-    let rules_to_apply = if check_config.fix_only {
-        rules
-            .iter()
-            .filter(|r| !r.has_no_fix())
-            .collect::<RuleSet>()
-    } else {
-        rules_to_apply
-    };
+    let rules_to_apply = filter_rules_by_fix(check_config, &rules);
 
     let mut rule_options = toml_settings
         .map(|s| s.linter.rule_options.clone())
@@ -159,6 +267,23 @@ pub fn build_config(
         .and_then(|s| s.linter.fix_roxygen)
         .unwrap_or(false);
 
+    let lintr_compat_suppressions = toml_settings
+        .and_then(|s| s.linter.lintr_compat_suppressions)
+        .unwrap_or(false);
+
+    let file_overrides = match toml_settings {
+        Some(settings) if !settings.linter.overrides.is_empty() => resolve_file_overrides(
+            check_config,
+            &settings.linter.overrides,
+            &paths,
+            &rules,
+            minimum_r_version,
+            preview,
+            &rule_options,
+        )?,
+        _ => HashMap::new(),
+    };
+
     Ok(Config {
         paths,
         rules,
@@ -170,13 +295,184 @@ pub fn build_config(
         allow_no_vcs: check_config.allow_no_vcs,
         unfixable: unfixable_toml,
         fixable: fixable_toml,
+        severity,
         check_roxygen,
         fix_roxygen,
+        lintr_compat_suppressions,
+        preview,
+        ignored_preview_rules,
         rule_options: Arc::new(rule_options),
         package_cache: None,
+        lint_cache: None,
+        file_overrides,
     })
 }
 
+/// Keep the rules that can be applied given --fix, --unsafe-fixes, and
+/// --fix-only.
+fn filter_rules_by_fix(check_config: &ArgsConfig, rules: &RuleSet) -> RuleSet {
+    // Resolve the interaction between --fix and --unsafe-fixes first. Using
+    // --unsafe-fixes implies using --fix, but the opposite is not true.
+    let rules_to_apply = match (check_config.fix, check_config.unsafe_fixes) {
+        (false, false) => rules.clone(),
+
+        (true, false) => rules
+            .iter()
+            .filter(|r| r.has_no_fix() || r.has_safe_fix())
+            .collect::<RuleSet>(),
+
+        (_, true) => rules
+            .iter()
+            .filter(|r| r.has_no_fix() || r.has_safe_fix() || r.has_unsafe_fix())
+            .collect::<RuleSet>(),
+    };
+
+    // We can now drop rules that don't have any fix if the user passed
+    // --fix-only. This could maybe be done above but dealing with the three
+    // args at the same time makes it much more complex.
+    if check_config.fix_only {
+        rules
+            .iter()
+            .filter(|r| !r.has_no_fix())
+            .collect::<RuleSet>()
+    } else {
+        rules_to_apply
+    }
+}
+
+/// Rule names of the `select`, `extend-select`, and `ignore` fields of an
+/// override, with groups of rules expanded.
+struct OverrideRules {
+    selected: Option<HashSet<String>>,
+    extended: HashSet<String>,
+    ignored: HashSet<String>,
+}
+
+/// Resolve the rules and rule options of the files matched by
+/// `[lint.per-file-ignores]` and `[[lint.overrides]]`.
+///
+/// Overrides are applied in order on top of the rules and options used for
+/// all files. Arguments passed in the CLI still take precedence: `--select`
+/// replaces the `select` of overrides, and `--extend-select`, `--ignore`, and
+/// `--assignment` apply to all files.
+fn resolve_file_overrides(
+    check_config: &ArgsConfig,
+    overrides: &[OverrideSettings],
+    paths: &[PathBuf],
+    rules: &RuleSet,
+    minimum_r_version: Option<(u32, u32, u32)>,
+    preview: bool,
+    rule_options: &ResolvedRuleOptions,
+) -> Result<HashMap<PathBuf, Arc<FileOverride>>> {
+    let all_rules = Rule::all();
+    let rules_cli = parse_rules_cli(
+        &check_config.select,
+        &check_config.extend_select,
+        &check_config.ignore,
+    )?;
+
+    // Validate the rules of all overrides upfront, even those that don't
+    // match any file.
+    let expand = |names: &Vec<String>, field: &str| -> Result<HashSet<String>> {
+        let passed_by_user: Vec<&str> = names.iter().map(|s| s.as_str()).collect();
+        let expanded_rules = replace_group_rules(&passed_by_user, all_rules);
+        if let Some(invalid_rules) = get_invalid_rules(all_rules, &expanded_rules) {
+            return Err(anyhow::anyhow!(
+                "Unknown rules in field `{field}` of `[lint.per-file-ignores]` or \
+                 `[[lint.overrides]]` in 'jarl.toml': {}",
+                invalid_rules.join(", ")
+            ));
+        }
+        Ok(expanded_rules.into_iter().collect())
+    };
+    let override_rules = overrides
+        .iter()
+        .map(|o| -> Result<OverrideRules> {
+            Ok(OverrideRules {
+                selected: o
+                    .select
+                    .as_ref()
+                    .map(|select| expand(select, "select"))
+                    .transpose()?,
+                extended: o
+                    .extend_select
+                    .as_ref()
+                    .map(|extend| expand(extend, "extend-select"))
+                    .transpose()?
+                    .unwrap_or_default(),
+                ignored: o
+                    .ignore
+                    .as_ref()
+                    .map(|ignore| expand(ignore, "ignore"))
+                    .transpose()?
+                    .unwrap_or_default(),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Files matched by the same overrides share the same settings.
+    let mut resolved: HashMap<Vec<usize>, Arc<FileOverride>> = HashMap::new();
+    let mut file_overrides = HashMap::new();
+
+    for path in paths {
+        let matching: Vec<usize> = overrides
+            .iter()
+            .enumerate()
+            .filter(|(_, o)| o.matches(path))
+            .map(|(i, _)| i)
+            .collect();
+        if matching.is_empty() {
+            continue;
+        }
+
+        let file_override = match resolved.entry(matching) {
+            Entry::Occupied(entry) => Arc::clone(entry.get()),
+            Entry::Vacant(entry) => {
+                let mut names: HashSet<String> =
+                    rules.iter().map(|r| r.name().to_string()).collect();
+                let mut options = rule_options.clone();
+
+                for &i in entry.key() {
+                    let o = &override_rules[i];
+                    if let Some(selected) = &o.selected
+                        && rules_cli.selected.is_none()
+                    {
+                        names = selected.clone();
+                    }
+                    names.extend(o.extended.iter().cloned());
+                    names.retain(|name| !o.ignored.contains(name));
+                    overrides[i].rule_options.apply(&mut options);
+                }
+
+                if let Some(extended) = &rules_cli.extended {
+                    names.extend(extended.iter().cloned());
+                }
+                names.retain(|name| !rules_cli.ignored.contains(name));
+                if let Some(cli_assignment) = &check_config.assignment {
+                    options.assignment = parse_assignment_cli(cli_assignment)?;
+                }
+
+                let rules: RuleSet = all_rules
+                    .iter()
+                    .filter(|r| names.contains(r.name()))
+                    .collect();
+                let rules = filter_rules_by_version(&rules, minimum_r_version);
+                let rules = filter_rules_by_preview(&rules, preview);
+
+                let file_override = Arc::new(FileOverride {
+                    rules_to_apply: filter_rules_by_fix(check_config, &rules),
+                    rule_options: Arc::new(options),
+                });
+                Arc::clone(entry.insert(file_override))
+            }
+        };
+
+        file_overrides.insert(PathBuf::from(relativize_path(path)), file_override);
+    }
+
+    Ok(file_overrides)
+}
+
 /// Parse CLI rule arguments and return (selected_rules, ignored_rules).
 ///
 /// Returns None for selected_rules if no --select was specified.
@@ -407,6 +703,57 @@ pub fn parse_fixable_toml(
     Ok((fixable_rules, unfixable_rules))
 }
 
+/// Parse the `[lint.severity]` table from TOML configuration.
+///
+/// Keys can be rule names or names of groups of rules. Group entries are
+/// expanded first, keeping the most severe level when a rule belongs to
+/// several listed groups, and rule entries are applied last so that they
+/// always take precedence.
+pub fn parse_severity_toml(toml_settings: Option<&Settings>) -> Result<HashMap<String, Severity>> {
+    let all_rules = Rule::all();
+    let mut severity: HashMap<String, Severity> = HashMap::new();
+
+    let Some(table) = toml_settings.and_then(|s| s.linter.severity.as_ref()) else {
+        return Ok(severity);
+    };
+
+    let rule_groups_set: HashSet<&str> = Category::ALL.iter().map(|c| c.as_str()).collect();
+    let mut rule_entries: Vec<(String, Severity)> = Vec::new();
+    let mut invalid_names: Vec<String> = Vec::new();
+
+    for (name, value) in table {
+        let level = value.parse::<Severity>().map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid value for `{name}` in `[lint.severity]` in 'jarl.toml': \"{value}\". \
+                 Expected \"error\", \"warning\", or \"info\"."
+            )
+        })?;
+
+        let trimmed = name.trim();
+        if trimmed == "ALL" || rule_groups_set.contains(trimmed) {
+            for rule in replace_group_rules(&vec![trimmed], all_rules) {
+                let entry = severity.entry(rule).or_insert(level);
+                *entry = (*entry).max(level);
+            }
+        } else if Rule::from_name(trimmed).is_some() {
+            rule_entries.push((trimmed.to_string(), level));
+        } else {
+            invalid_names.push(name.clone());
+        }
+    }
+
+    if !invalid_names.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Unknown rules in field `severity` in 'jarl.toml': {}",
+            invalid_names.join(", ")
+        ));
+    }
+
+    severity.extend(rule_entries);
+
+    Ok(severity)
+}
+
 // This takes rules that refer to groups (e.g. "PERF", "READ") and replaces them
 // with the rule names.
 // Returns a vector with the original rule names left unmodified and the expanded
@@ -586,6 +933,49 @@ pub fn parse_r_version(min_r_version: String) -> Result<(u32, u32, u32)> {
     Ok((major, minor, patch))
 }
 
+/// Remove preview rules unless preview is enabled. Preview rules are dropped
+/// even if they are selected explicitly, so that `select = ["ALL"]` doesn't
+/// enable new rules when Jarl is upgraded.
+fn filter_rules_by_preview(rules: &RuleSet, preview: bool) -> RuleSet {
+    filter_preview_rules(rules, preview, Rule::is_preview)
+}
+
+fn filter_preview_rules(
+    rules: &RuleSet,
+    preview: bool,
+    is_preview: impl Fn(Rule) -> bool,
+) -> RuleSet {
+    if preview {
+        return rules.clone();
+    }
+    rules
+        .iter()
+        .copied()
+        .filter(|rule| !is_preview(*rule))
+        .collect::<RuleSet>()
+}
+
+/// Names of the preview rules among `names` that are ignored because preview
+/// is disabled, sorted. `names` are the rules named explicitly in `select` or
+/// `extend-select`: preview rules selected with a group, such as `ALL`, are
+/// ignored silently.
+fn ignored_preview_rules(
+    names: &[&str],
+    preview: bool,
+    is_preview: impl Fn(Rule) -> bool,
+) -> Vec<String> {
+    if preview {
+        return Vec::new();
+    }
+    let mut ignored: Vec<String> = Rule::all()
+        .iter()
+        .filter(|rule| is_preview(**rule) && names.contains(&rule.name()))
+        .map(|rule| rule.name().to_string())
+        .collect();
+    ignored.sort_unstable();
+    ignored
+}
+
 /// Filter rules based on minimum R version compatibility
 fn filter_rules_by_version(rules: &RuleSet, minimum_r_version: Option<(u32, u32, u32)>) -> RuleSet {
     match minimum_r_version {
@@ -627,3 +1017,40 @@ fn parse_assignment_cli(value: &str) -> Result<ResolvedAssignmentOptions> {
         )),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(rules: &RuleSet) -> Vec<&'static str> {
+        rules.iter().map(|rule| rule.name()).collect()
+    }
+
+    #[test]
+    fn test_filter_preview_rules() {
+        let rules = RuleSet::from_rules(vec![Rule::AnyIsNa, Rule::AnyDuplicated]);
+        let is_preview = |rule: Rule| rule == Rule::AnyIsNa;
+
+        assert_eq!(
+            names(&filter_preview_rules(&rules, false, is_preview)),
+            vec!["any_duplicated"]
+        );
+        assert_eq!(
+            names(&filter_preview_rules(&rules, true, is_preview)),
+            vec!["any_is_na", "any_duplicated"]
+        );
+    }
+
+    #[test]
+    fn test_ignored_preview_rules() {
+        let is_preview = |rule: Rule| rule == Rule::AnyIsNa;
+
+        assert_eq!(
+            ignored_preview_rules(&["any_is_na", "any_duplicated"], false, is_preview),
+            vec!["any_is_na"]
+        );
+        assert!(ignored_preview_rules(&["any_is_na"], true, is_preview).is_empty());
+        // Rules selected with a group are not reported.
+        assert!(ignored_preview_rules(&["ALL", "PERF"], false, is_preview).is_empty());
+    }
+}
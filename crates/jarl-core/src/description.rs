@@ -37,6 +37,26 @@ impl Description {
         packages
     }
 
+    /// Extract the `Config/jarl/*` fields of a DESCRIPTION file, sorted by
+    /// name and without the `Config/jarl/` prefix, e.g.
+    /// `Config/jarl/ignore: quotes` -> `("ignore", "quotes")`.
+    pub fn get_jarl_config(contents: &str) -> Vec<(String, String)> {
+        let mut fields: Vec<(String, String)> = parse_dcf(contents)
+            .into_iter()
+            .filter_map(|(key, value)| {
+                key.strip_prefix("Config/jarl/")
+                    .map(|name| (name.to_string(), value))
+            })
+            .collect();
+        fields.sort();
+        fields
+    }
+
+    /// Extract the `Version` field of a DESCRIPTION file, e.g. `"1.1.4"`.
+    pub fn get_version(contents: &str) -> Option<String> {
+        parse_dcf(contents).remove("Version")
+    }
+
     /// Extract R version requirements from the Depends field of a DESCRIPTION file
     ///
     /// Returns a vector of version strings found in R dependencies.
@@ -82,8 +102,9 @@ fn extract_version_from_dependency(dep: &str) -> Option<String> {
 }
 
 /// Parse a DCF (Debian Control File) format string into a key-value map
-/// Minimal implementation focused on extracting the Depends field
-fn parse_dcf(input: &str) -> HashMap<String, String> {
+/// Minimal implementation focused on extracting the Depends field. It is also
+/// used to read `.lintr` files, which use the same format.
+pub(crate) fn parse_dcf(input: &str) -> HashMap<String, String> {
     let mut fields = HashMap::new();
     let mut current_key: Option<String> = None;
     let mut current_value = String::new();
@@ -251,4 +272,24 @@ Imports: dplyr, tidyr
         let result = Description::get_package_deps(description, &["Depends", "Imports"]);
         assert_eq!(result, vec!["dplyr", "tidyr"]);
     }
+
+    #[test]
+    fn test_get_jarl_config() {
+        let description = r#"
+Package: mypackage
+Version: 1.0.0
+Config/testthat/edition: 3
+Config/jarl/select: PERF,
+    any_is_na
+Config/jarl/exclude: R/generated.R
+"#;
+        let result = Description::get_jarl_config(description);
+        assert_eq!(
+            result,
+            vec![
+                ("exclude".to_string(), "R/generated.R".to_string()),
+                ("select".to_string(), "PERF, any_is_na".to_string())
+            ]
+        );
+    }
 }
@@ -5,9 +5,9 @@ use std::cmp::Ordering;
 use std::path::PathBuf;
 
 use crate::location::Location;
-use crate::rule_set::{FixStatus, Rule};
+use crate::rule_set::{FixStatus, Rule, Severity};
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 // The fix to apply to the violation.
 pub struct Fix {
     pub content: String,
@@ -41,18 +41,21 @@ pub trait Violation {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct ViolationData {
     pub name: String,
     pub body: String,
     pub suggestion: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 // The object that is eventually reported and printed in the console.
 pub struct Diagnostic {
     // The name and description of the violated rule.
     pub message: ViolationData,
+    // How serious the violation is. Defaults to the rule's severity and can be
+    // overridden in `[lint.severity]`.
+    pub severity: Severity,
     // Location of the violated rule.
     pub filename: PathBuf,
     pub range: TextRange,
@@ -87,8 +90,13 @@ impl ViolationData {
 
 impl Diagnostic {
     pub fn new<T: Into<ViolationData>>(message: T, range: TextRange, fix: Fix) -> Self {
+        let message: ViolationData = message.into();
+        let severity = Rule::from_name(&message.name)
+            .map(|r| r.default_severity())
+            .unwrap_or_default();
         Self {
-            message: message.into(),
+            message,
+            severity,
             range,
             location: None,
             fix,
@@ -99,6 +107,7 @@ impl Diagnostic {
     pub fn empty() -> Self {
         Self {
             message: ViolationData::empty(),
+            severity: Severity::default(),
             range: TextRange::empty(0.into()),
             location: None,
             fix: Fix::empty(),
@@ -153,7 +162,8 @@ impl PartialOrd for Diagnostic {
 /// Render a single diagnostic as an annotated code snippet.
 ///
 /// Uses `annotate_snippets` to produce a formatted message with the source
-/// context, warning label, and optional suggestion footer.
+/// context, a label matching the diagnostic severity, and optional suggestion
+/// footer.
 ///
 /// The `title` parameter allows callers to customize the message title
 /// (e.g. the CLI uses a hyperlinked rule name, while tests use the plain name).
@@ -173,16 +183,22 @@ pub fn render_diagnostic(
     // that contain the annotation span to avoid scanning the entire file.
     let (expanded, adj_start, adj_end) = expand_span_line_tabs(source, start_offset, end_offset);
 
+    let level = match diagnostic.severity {
+        Severity::Error => Level::Error,
+        Severity::Warning => Level::Warning,
+        Severity::Info => Level::Info,
+    };
+
     let snippet = Snippet::source(&expanded)
         .origin(origin)
         .fold(true)
         .annotation(
-            Level::Warning
+            level
                 .span(adj_start..adj_end)
                 .label(&diagnostic.message.body),
         );
 
-    let mut message = Level::Warning.title(title).snippet(snippet);
+    let mut message = level.title(title).snippet(snippet);
 
     if let Some(suggestion_text) = &diagnostic.message.suggestion {
         message = message.footer(Level::Help.title(suggestion_text));
@@ -325,3 +325,74 @@ fn parse_rule_only(text: &str) -> Option<Rule> {
     // Validate rule name against known rules
     Rule::from_name(rule_name)
 }
+
+/// A lintr `# nolint` directive. These are only recognized when
+/// `lintr-compat-suppressions` is enabled.
+///
+/// The linters are kept with their lintr names, `None` meaning that all
+/// linters are suppressed.
+#[derive(Debug, PartialEq, Clone)]
+pub enum NolintDirective {
+    /// Skip the line of the comment: `# nolint` or `# nolint: <linters>.`
+    Line(Option<Vec<String>>),
+    /// Start a range suppression: `# nolint start` or `# nolint start: <linters>.`
+    Start(Option<Vec<String>>),
+    /// End a range suppression: `# nolint end`
+    End,
+}
+
+/// Parse a lintr `# nolint` comment
+///
+/// Supported formats, following the default `exclude*` settings of lintr:
+///
+/// ```text
+/// # nolint
+/// # nolint: <linter>, <linter>.
+/// # nolint start
+/// # nolint start: <linter>, <linter>.
+/// # nolint end
+/// ```
+///
+/// As in lintr, the directive doesn't have to be at the start of the comment,
+/// e.g. `# TODO: remove this # nolint` is valid.
+pub fn parse_nolint_directive(text: &str) -> Option<NolintDirective> {
+    let rest = text.match_indices('#').find_map(|(pos, _)| {
+        text[pos + 1..]
+            .trim_start_matches(' ')
+            .strip_prefix("nolint")
+            .filter(|rest| rest.is_empty() || rest.starts_with([' ', ':', '.']))
+    })?;
+
+    if let Some(after_start) = rest.strip_prefix(" start") {
+        Some(NolintDirective::Start(parse_nolint_linters(after_start)))
+    } else if rest.starts_with(" end") {
+        Some(NolintDirective::End)
+    } else {
+        Some(NolintDirective::Line(parse_nolint_linters(rest)))
+    }
+}
+
+/// Parse the list of linters of a `# nolint` directive, e.g.
+/// `: object_name_linter, seq_linter.`
+///
+/// Returns `None` if there is no list, meaning that all linters are skipped.
+fn parse_nolint_linters(text: &str) -> Option<Vec<String>> {
+    let list = text.trim_start().strip_prefix(':')?;
+    let list = match list.find('.') {
+        Some(dot_pos) => &list[..dot_pos],
+        None => list,
+    };
+
+    let linters: Vec<String> = list
+        .split([',', ' '])
+        .map(str::trim)
+        .filter(|linter| !linter.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    if linters.is_empty() {
+        None
+    } else {
+        Some(linters)
+    }
+}
@@ -13,8 +13,12 @@ use crate::fs;
 use crate::fs::has_r_extension;
 use crate::fs::has_rmd_extension;
 use crate::settings::Settings;
+use crate::toml::TomlOptions;
+use crate::toml::find_description_config_in_directory;
 use crate::toml::find_jarl_toml_in_directory;
-use crate::toml::parse_jarl_toml;
+use crate::toml::is_description_file;
+use crate::toml::load_description_config;
+use crate::toml::load_jarl_toml;
 use air_workspace::resolve::PathResolver;
 use etcetera::BaseStrategy;
 
@@ -38,8 +42,18 @@ pub struct DiscoveredSettings {
     pub config_path: Option<PathBuf>,
 }
 
+/// Configuration passed in the command line with `--config`.
+#[derive(Clone, Debug, Default)]
+pub struct ConfigArguments {
+    /// Configuration file to use instead of discovering `jarl.toml` files.
+    pub config_file: Option<PathBuf>,
+    /// Inline options, e.g. `lint.quotes.quote = "single"`. They are merged
+    /// in order over the options of configuration files.
+    pub overrides: Vec<TomlOptions>,
+}
+
 /// Get the user config directory for jarl
-fn get_user_config_dir() -> Option<PathBuf> {
+pub fn get_user_config_dir() -> Option<PathBuf> {
     let strategy = etcetera::base_strategy::choose_base_strategy().ok()?;
     Some(strategy.config_dir().join("jarl"))
 }
@@ -54,6 +68,93 @@ fn get_user_config_dir() -> Option<PathBuf> {
 /// - If no config found in ancestors, fall back to checking the user config directory
 /// - If `path` is a directory, also walk down into it to find any nested `jarl.toml`s
 pub fn discover_settings<P: AsRef<Path>>(paths: &[P]) -> anyhow::Result<Vec<DiscoveredSettings>> {
+    discover_settings_with_args(paths, &ConfigArguments::default())
+}
+
+/// Like [discover_settings()], but taking `--config` arguments into account.
+///
+/// If a configuration file is passed, discovery is skipped and it is used for
+/// all `paths`. Its patterns are resolved relative to its own directory, like
+/// for a discovered `jarl.toml`. Inline options are applied to all discovered
+/// settings, and are also used on their own for the `paths` that no
+/// configuration file applies to.
+pub fn discover_settings_with_args<P: AsRef<Path>>(
+    paths: &[P],
+    args: &ConfigArguments,
+) -> anyhow::Result<Vec<DiscoveredSettings>> {
+    if args.config_file.is_none() && args.overrides.is_empty() {
+        return discover_settings_impl(paths, &[]);
+    }
+
+    let cwd = fs::normalize_path(std::env::current_dir()?);
+    let paths: Vec<PathBuf> = paths.iter().map(fs::normalize_path).collect();
+
+    if let Some(config_file) = &args.config_file {
+        let config_file = fs::normalize_path(config_file);
+        let config_dir = config_file.parent().unwrap_or(&cwd);
+        let settings = parse_settings(&config_file, config_dir, &args.overrides)?;
+        return Ok(vec![DiscoveredSettings {
+            directory: common_ancestor(paths.iter().chain([&cwd])),
+            settings,
+            config_path: Some(config_file),
+        }]);
+    }
+
+    let mut discovered_settings = discover_settings_impl(&paths, &args.overrides)?;
+
+    let uncovered: Vec<&PathBuf> = paths
+        .iter()
+        .chain([&cwd])
+        .filter(|path| {
+            !discovered_settings
+                .iter()
+                .any(|ds| path.starts_with(&ds.directory))
+        })
+        .collect();
+
+    if !uncovered.is_empty() {
+        let options = args
+            .overrides
+            .iter()
+            .cloned()
+            .fold(TomlOptions::default(), |options, inline| {
+                inline.combine(options)
+            });
+        discovered_settings.push(DiscoveredSettings {
+            directory: common_ancestor(uncovered.into_iter()),
+            settings: options.into_settings(&cwd)?,
+            config_path: None,
+        });
+    }
+
+    Ok(discovered_settings)
+}
+
+/// Find the deepest directory containing all `paths`, which must be absolute.
+fn common_ancestor<'a>(mut paths: impl Iterator<Item = &'a PathBuf>) -> PathBuf {
+    let Some(first) = paths.next() else {
+        return PathBuf::new();
+    };
+    let mut ancestor = if first.is_dir() {
+        first.as_path()
+    } else {
+        first.parent().unwrap_or(first)
+    };
+    for path in paths {
+        while !path.starts_with(ancestor) {
+            match ancestor.parent() {
+                Some(parent) => ancestor = parent,
+                None => break,
+            }
+        }
+    }
+    ancestor.to_path_buf()
+}
+
+fn discover_settings_impl<P: AsRef<Path>>(
+    paths: &[P],
+    overrides: &[TomlOptions],
+) -> anyhow::Result<Vec<DiscoveredSettings>> {
     let paths: Vec<PathBuf> = paths.iter().map(fs::normalize_path).collect();
 
     let mut seen = FxHashSet::default();
@@ -72,8 +173,8 @@ pub fn discover_settings<P: AsRef<Path>>(paths: &[P]) -> anyhow::Result<Vec<Disc
                 break;
             }
 
-            if let Some(toml) = find_jarl_toml_in_directory(ancestor) {
-                let settings = parse_settings(&toml, ancestor)?;
+            if let Some(toml) = find_config_in_directory(ancestor) {
+                let settings = parse_settings(&toml, ancestor, overrides)?;
                 discovered_settings.push(DiscoveredSettings {
                     directory: ancestor.to_path_buf(),
                     settings,
@@ -97,7 +198,7 @@ pub fn discover_settings<P: AsRef<Path>>(paths: &[P]) -> anyhow::Result<Vec<Disc
             && seen.insert(config_dir.as_path())
             && let Some(toml) = find_jarl_toml_in_directory(config_dir)
         {
-            let settings = parse_settings(&toml, config_dir)?;
+            let settings = parse_settings(&toml, config_dir, overrides)?;
             discovered_settings.push(DiscoveredSettings {
                 directory: config_dir.clone(),
                 settings,
@@ -116,7 +217,12 @@ pub fn discover_settings<P: AsRef<Path>>(paths: &[P]) -> anyhow::Result<Vec<Disc
 
     for path in &paths {
         if path.is_dir() {
-            discover_nested_settings(path, &mut already_found, &mut discovered_settings)?;
+            discover_nested_settings(
+                path,
+                &mut already_found,
+                &mut discovered_settings,
+                overrides,
+            )?;
         }
     }
 
@@ -128,6 +234,7 @@ fn discover_nested_settings(
     root: &Path,
     already_found: &mut FxHashSet<PathBuf>,
     discovered_settings: &mut Vec<DiscoveredSettings>,
+    overrides: &[TomlOptions],
 ) -> anyhow::Result<()> {
     let walker = ignore::WalkBuilder::new(root)
         .hidden(true)
@@ -161,9 +268,9 @@ fn discover_nested_settings(
             continue;
         }
 
-        if let Some(toml) = find_jarl_toml_in_directory(path) {
+        if let Some(toml) = find_config_in_directory(path) {
             already_found.insert(path.to_path_buf());
-            let settings = parse_settings(&toml, path)?;
+            let settings = parse_settings(&toml, path, overrides)?;
             discovered_settings.push(DiscoveredSettings {
                 directory: path.to_path_buf(),
                 settings,
@@ -175,12 +282,38 @@ fn discover_nested_settings(
     Ok(())
 }
 
-/// Parse [Settings] from a given `jarl.toml`
-// TODO(hierarchical): Allow for an `extends` option in `jarl.toml`, which will make things
-// more complex, but will be very useful once we support hierarchical configuration as a
-// way of "inheriting" most top level configuration while slightly tweaking it in a nested directory.
-fn parse_settings(toml: &Path, root_directory: &Path) -> anyhow::Result<Settings> {
-    let options = parse_jarl_toml(toml)?;
+/// Return the configuration file of `directory`: its `jarl.toml`, or else its
+/// `DESCRIPTION` if it has `Config/jarl/*` fields. A `jarl.toml` in a parent
+/// directory takes precedence over the `DESCRIPTION`, so that a nested package
+/// doesn't escape the configuration of the project it belongs to.
+pub fn find_config_in_directory(directory: &Path) -> Option<PathBuf> {
+    if let Some(toml) = find_jarl_toml_in_directory(directory) {
+        return Some(toml);
+    }
+
+    let description = find_description_config_in_directory(directory)?;
+    let has_parent_toml = directory
+        .ancestors()
+        .skip(1)
+        .any(|dir| find_jarl_toml_in_directory(dir).is_some());
+    (!has_parent_toml).then_some(description)
+}
+
+/// Parse [Settings] from a given `jarl.toml` or `DESCRIPTION`, with inline
+/// `overrides` merged over its options.
+fn parse_settings(
+    toml: &Path,
+    root_directory: &Path,
+    overrides: &[TomlOptions],
+) -> anyhow::Result<Settings> {
+    let mut options = if is_description_file(toml) {
+        load_description_config(toml)?
+    } else {
+        load_jarl_toml(toml)?
+    };
+    for inline in overrides {
+        options = inline.clone().combine(options);
+    }
     let settings = options
         .into_settings(root_directory)
         .map_err(|err| anyhow::anyhow!("Invalid configuration in {}:\n{err}", toml.display()))?;
@@ -0,0 +1,17 @@
+/// 64-bit FNV-1a hash. Unlike `std::hash::DefaultHasher`, its output is
+/// guaranteed to be the same across Rust versions and platforms, which is
+/// required for fingerprints and cache keys to be stable across runs.
+pub fn fnv1a_hash(parts: &[&str]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let mut hash = OFFSET_BASIS;
+    for part in parts {
+        // Separate parts so that ("ab", "c") and ("a", "bc") differ.
+        for byte in part.bytes().chain(std::iter::once(0)) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(PRIME);
+        }
+    }
+    hash
+}
@@ -0,0 +1,392 @@
+//! Generation of a starter `jarl.toml` with `jarl init`.
+//!
+//! The project is inspected to write settings that fit it: its kind (R
+//! package, Quarto or R Markdown site, renv project, or plain scripts), the
+//! packages it uses to enable the matching groups of rules, and the dominant
+//! assignment operator, pipe, and quote delimiter in its code.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use air_r_parser::RParserOptions;
+use air_r_syntax::{RBinaryExpression, RStringValue, RSyntaxKind};
+use biome_rowan::AstNode;
+
+use crate::description::Description;
+use crate::fs::has_r_extension;
+use crate::package::{FileScope, file_scope_from_path};
+use crate::package_cache::{any_file_references_packages, find_r_project_root};
+use crate::renv::read_lockfile_versions;
+use crate::rule_set::Category;
+
+/// Files that mark the root of a Quarto or R Markdown website or book.
+const SITE_FILES: &[&str] = &["_quarto.yml", "_quarto.yaml", "_site.yml", "_bookdown.yml"];
+
+/// Groups of rules that are disabled by default and the package they are
+/// about.
+const PACKAGE_CATEGORIES: &[(&str, Category)] =
+    &[("testthat", Category::Testthat), ("dplyr", Category::Dplyr)];
+
+/// Kind of project in which `jarl init` is run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProjectKind {
+    /// R package, with a `DESCRIPTION` file
+    Package,
+    /// Quarto or R Markdown website or book
+    Site,
+    /// Project whose packages are managed with renv
+    Renv,
+    /// Plain R scripts
+    Scripts,
+}
+
+impl ProjectKind {
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Package => "an R package",
+            Self::Site => "a Quarto or R Markdown site",
+            Self::Renv => "an renv project",
+            Self::Scripts => "R scripts",
+        }
+    }
+}
+
+/// Number of uses of each style in the code of the project.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CodeStyle {
+    pub arrow_assignments: usize,
+    pub equal_assignments: usize,
+    pub base_pipes: usize,
+    pub magrittr_pipes: usize,
+    pub double_quotes: usize,
+    pub single_quotes: usize,
+}
+
+impl CodeStyle {
+    /// Count the styles used in `source`. Files that can't be parsed are
+    /// skipped.
+    pub fn from_source(source: &str) -> Self {
+        let mut style = Self::default();
+
+        let parsed = air_r_parser::parse(source, RParserOptions::default());
+        if parsed.has_error() {
+            return style;
+        }
+
+        for node in parsed.syntax().descendants() {
+            if let Some(binary) = RBinaryExpression::cast(node.clone()) {
+                let Ok(operator) = binary.operator() else {
+                    continue;
+                };
+                match operator.kind() {
+                    RSyntaxKind::ASSIGN => style.arrow_assignments += 1,
+                    RSyntaxKind::EQUAL => style.equal_assignments += 1,
+                    RSyntaxKind::PIPE => style.base_pipes += 1,
+                    RSyntaxKind::SPECIAL if operator.text_trimmed() == "%>%" => {
+                        style.magrittr_pipes += 1
+                    }
+                    _ => {}
+                }
+            } else if let Some(string) = RStringValue::cast(node.clone())
+                && let Ok(token) = string.value_token()
+            {
+                // Raw strings start with `r` or `R` and are ignored.
+                match token.text_trimmed().chars().next() {
+                    Some('"') => style.double_quotes += 1,
+                    Some('\'') => style.single_quotes += 1,
+                    _ => {}
+                }
+            }
+        }
+
+        style
+    }
+
+    fn add(&mut self, other: Self) {
+        self.arrow_assignments += other.arrow_assignments;
+        self.equal_assignments += other.equal_assignments;
+        self.base_pipes += other.base_pipes;
+        self.magrittr_pipes += other.magrittr_pipes;
+        self.double_quotes += other.double_quotes;
+        self.single_quotes += other.single_quotes;
+    }
+}
+
+/// What `jarl init` found in the project.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProjectInfo {
+    pub kind: ProjectKind,
+    /// Minimum R version from the `Depends` field of `DESCRIPTION`
+    pub min_r_version: Option<String>,
+    /// Groups of rules about packages used in the project, with the package
+    pub categories: Vec<(&'static str, Category)>,
+    pub style: CodeStyle,
+}
+
+/// Inspect the project in `root`. `files` are the R files of the project.
+pub fn detect_project(root: &Path, files: &[PathBuf]) -> ProjectInfo {
+    let description = fs::read_to_string(root.join("DESCRIPTION")).ok();
+    let renv_root = find_r_project_root(root);
+
+    let kind = if description.is_some() {
+        ProjectKind::Package
+    } else if SITE_FILES.iter().any(|file| root.join(file).is_file()) {
+        ProjectKind::Site
+    } else if renv_root.is_some() {
+        ProjectKind::Renv
+    } else {
+        ProjectKind::Scripts
+    };
+
+    let min_r_version = description
+        .as_deref()
+        .and_then(|contents| Description::get_depend_r_version(contents).ok())
+        .and_then(|versions| versions.into_iter().next());
+
+    // Packages declared in DESCRIPTION or recorded by renv, in addition to
+    // those that are loaded or used with `pkg::` in the code.
+    let mut declared = description
+        .as_deref()
+        .map(|contents| {
+            Description::get_package_deps(contents, &["Depends", "Imports", "Suggests"])
+        })
+        .unwrap_or_default();
+    if let Some(lockfile) = renv_root.as_deref().and_then(read_lockfile_versions) {
+        declared.extend(lockfile.into_keys());
+    }
+
+    // testthat is rarely loaded explicitly in the tests of a package.
+    let has_testthat_tests = kind == ProjectKind::Package
+        && files.iter().any(|file| {
+            file.strip_prefix(root).is_ok_and(|relative| {
+                file_scope_from_path(relative) == FileScope::Tests
+                    && relative.starts_with("tests/testthat")
+            })
+        });
+
+    let categories = PACKAGE_CATEGORIES
+        .iter()
+        .filter(|&&(package, category)| {
+            declared.iter().any(|name| name == package)
+                || (category == Category::Testthat && has_testthat_tests)
+                || any_file_references_packages(files, &[package])
+        })
+        .copied()
+        .collect();
+
+    let mut style = CodeStyle::default();
+    for file in files {
+        if has_r_extension(file)
+            && let Ok(source) = fs::read_to_string(file)
+        {
+            style.add(CodeStyle::from_source(&source));
+        }
+    }
+
+    ProjectInfo { kind, min_r_version, categories, style }
+}
+
+/// Write the content of a commented `jarl.toml` for the project.
+pub fn init_toml(info: &ProjectInfo) -> String {
+    let mut toml = format!(
+        "# Generated by `jarl init` for {}.\n\
+         # All settings are described in https://jarl.etiennebacher.com/reference/config-file\n\
+         \n\
+         [lint]\n",
+        info.kind.description()
+    );
+
+    if info.categories.is_empty() {
+        toml.push_str(
+            "# Rules enabled by default are used. Use `select`, `extend-select`, and\n\
+             # `ignore` to change the set of rules, e.g. `extend-select = [\"TESTTHAT\"]`.\n",
+        );
+    } else {
+        let packages: Vec<&str> = info
+            .categories
+            .iter()
+            .map(|(package, _)| *package)
+            .collect();
+        let categories: Vec<String> = info
+            .categories
+            .iter()
+            .map(|(_, category)| format!("\"{category}\""))
+            .collect();
+        toml.push_str(&format!(
+            "# Rules enabled by default are used, as well as the rules about packages\n\
+             # used in the project: {}.\n\
+             extend-select = [{}]\n",
+            packages.join(", "),
+            categories.join(", ")
+        ));
+    }
+
+    toml.push('\n');
+    match &info.min_r_version {
+        Some(version) => toml.push_str(&format!(
+            "# Rules that require a recent version of R are enabled based on the\n\
+             # minimum R version in the `Depends` field of DESCRIPTION ({version}).\n"
+        )),
+        None => toml.push_str(
+            "# Rules that require a recent version of R are disabled because the\n\
+             # minimum R version of the project is unknown. Pass `--min-r-version` to\n\
+             # enable them.\n",
+        ),
+    }
+
+    let style = &info.style;
+    let options = [
+        (
+            "assignment",
+            "operator",
+            ("<-", style.arrow_assignments),
+            ("=", style.equal_assignments),
+        ),
+        (
+            "pipe_consistency",
+            "pipe",
+            ("|>", style.base_pipes),
+            ("%>%", style.magrittr_pipes),
+        ),
+        (
+            "quotes",
+            "quote",
+            ("double", style.double_quotes),
+            ("single", style.single_quotes),
+        ),
+    ];
+
+    let detected: Vec<_> = options
+        .iter()
+        .filter(|(_, _, (_, default), (_, other))| default + other > 0)
+        .collect();
+    if detected.is_empty() {
+        return toml;
+    }
+
+    let rules: Vec<String> = detected
+        .iter()
+        .map(|(rule, ..)| format!("\"{rule}\""))
+        .collect();
+    toml.push_str(&format!(
+        "\n# The options below follow the style of the existing code. The rules they\n\
+         # configure are disabled by default, add them to `extend-select` to enforce\n\
+         # them: {}.\n",
+        rules.join(", ")
+    ));
+
+    for (rule, option, (default_value, default_count), (other_value, other_count)) in detected {
+        // Ties keep the default value of the option.
+        let value = if other_count > default_count {
+            other_value
+        } else {
+            default_value
+        };
+        toml.push_str(&format!(
+            "\n[lint.{rule}]\n\
+             # Number of uses: \"{default_value}\" = {default_count}, \"{other_value}\" = {other_count}.\n\
+             {option} = \"{value}\"\n"
+        ));
+    }
+
+    toml
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::toml_validation::validate_jarl_toml;
+
+    fn write(root: &Path, path: &str, contents: &str) -> PathBuf {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_code_style() {
+        let style =
+            CodeStyle::from_source("x = 'a'\ny <- f(a = \"b\", r\"(c)\")\nz <- x |> g() %>% h()\n");
+        assert_eq!(
+            style,
+            CodeStyle {
+                arrow_assignments: 2,
+                equal_assignments: 1,
+                base_pipes: 1,
+                magrittr_pipes: 1,
+                double_quotes: 1,
+                single_quotes: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_package() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "DESCRIPTION",
+            "Package: foo\nVersion: 0.1.0\nDepends: R (>= 4.1.0)\nImports: dplyr\n",
+        );
+        let files = vec![
+            write(
+                root,
+                "R/foo.R",
+                "f = function(x) {\n  y = 'a'\n  x %>% g()\n}\n",
+            ),
+            write(
+                root,
+                "tests/testthat/test-foo.R",
+                "expect_equal(f(1), 'a')\n",
+            ),
+        ];
+
+        let info = detect_project(root, &files);
+        assert_eq!(info.kind, ProjectKind::Package);
+        assert_eq!(info.min_r_version.as_deref(), Some("4.1.0"));
+        assert_eq!(
+            info.categories,
+            vec![("testthat", Category::Testthat), ("dplyr", Category::Dplyr)]
+        );
+
+        let toml = init_toml(&info);
+        assert!(toml.contains("extend-select = [\"TESTTHAT\", \"DPLYR\"]"));
+        assert!(toml.contains("operator = \"=\""));
+        assert!(toml.contains("pipe = \"%>%\""));
+        assert!(toml.contains("quote = \"single\""));
+        assert_eq!(validate_jarl_toml(&toml), vec![]);
+    }
+
+    #[test]
+    fn test_scripts() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        let files = vec![write(root, "analysis.R", "x <- 1\n")];
+
+        let info = detect_project(root, &files);
+        assert_eq!(info.kind, ProjectKind::Scripts);
+        assert_eq!(info.min_r_version, None);
+        assert!(info.categories.is_empty());
+
+        let toml = init_toml(&info);
+        assert!(toml.contains("operator = \"<-\""));
+        assert!(!toml.contains("[lint.quotes]"));
+        assert_eq!(validate_jarl_toml(&toml), vec![]);
+    }
+
+    #[test]
+    fn test_site() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        write(root, "_quarto.yml", "project:\n  type: website\n");
+
+        let info = detect_project(root, &[]);
+        assert_eq!(info.kind, ProjectKind::Site);
+    }
+}
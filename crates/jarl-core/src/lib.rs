@@ -10,6 +10,7 @@
 pub mod utils;
 
 pub mod analyze;
+pub mod cache;
 pub mod check;
 pub mod checker;
 pub mod config;
@@ -20,13 +21,17 @@ pub mod discovery;
 pub mod error;
 pub mod fix;
 pub mod fs;
+pub mod hash;
+pub mod init;
 pub mod library_calls;
 pub mod library_paths;
+pub mod lintr;
 pub mod lints;
 pub mod location;
 pub mod namespace;
 pub mod package;
 pub mod package_cache;
+pub mod renv;
 pub mod rmd;
 pub mod roxygen;
 pub mod rule_options;
@@ -35,6 +40,7 @@ pub mod settings;
 pub mod suppression;
 pub mod suppression_edit;
 pub mod toml;
+pub mod toml_validation;
 pub mod utils_ast;
 pub mod vcs;
 
@@ -1,12 +1,25 @@
-//! R availability check.
+//! R availability check and discovery of R libraries.
 //!
 //! Provides a quick check for whether R is installed, used by the CLI
 //! to give a clear error when package-specific rules are enabled but R
 //! is not available.
+//!
+//! When R is not available, the library directories are found by following
+//! the conventions of R and renv, so that the metadata of installed packages
+//! can still be read from their files.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
+/// Usual locations of site libraries and of the library of R installations.
+const SYSTEM_LIBRARIES: &[&str] = &[
+    "/usr/local/lib/R/site-library",
+    "/usr/lib/R/site-library",
+    "/usr/local/lib/R/library",
+    "/usr/lib/R/library",
+    "/Library/Frameworks/R.framework/Resources/library",
+];
+
 /// Check whether R is available on this system.
 ///
 /// First checks the `R_HOME` environment variable (set by CI setup actions
@@ -27,3 +40,189 @@ pub fn is_r_available() -> bool {
         .output()
         .is_ok_and(|o| o.status.success())
 }
+
+/// Find the R library directories without running R, in the order in which R
+/// would search them in `.libPaths()`:
+///
+/// - the renv library of `project_root`, if any;
+/// - the directories in `R_LIBS` and `R_LIBS_USER`;
+/// - the default user library, e.g. `~/R/x86_64-pc-linux-gnu-library/4.5`;
+/// - the directories in `R_LIBS_SITE` and the usual site libraries;
+/// - the library of the R installation in `R_HOME`, or of the R versions
+///   installed in the usual places.
+///
+/// When there are several versions of R, libraries of the most recent ones
+/// come first. Only existing directories are returned.
+pub fn find_library_paths(project_root: Option<&Path>) -> Vec<PathBuf> {
+    let home = etcetera::home_dir().ok();
+    let mut candidates = Vec::new();
+
+    if let Some(root) = project_root {
+        candidates.extend(renv_libraries(&root.join("renv").join("library")));
+    }
+
+    candidates.extend(paths_from_env("R_LIBS", home.as_deref()));
+    candidates.extend(paths_from_env("R_LIBS_USER", home.as_deref()));
+    if let Some(home) = &home {
+        // Linux: ~/R/<platform>-library/<version>
+        for platform in subdirectories(&home.join("R")) {
+            candidates.extend(subdirectories(&platform));
+        }
+        // macOS: ~/Library/R/<arch>/<version>/library
+        for arch in subdirectories(&home.join("Library").join("R")) {
+            candidates.extend(
+                subdirectories(&arch)
+                    .into_iter()
+                    .map(|version| version.join("library")),
+            );
+        }
+    }
+    // Windows: %LOCALAPPDATA%/R/win-library/<version>
+    if let Some(local_app_data) = std::env::var_os("LOCALAPPDATA") {
+        let win_library = PathBuf::from(local_app_data).join("R").join("win-library");
+        candidates.extend(subdirectories(&win_library));
+    }
+
+    candidates.extend(paths_from_env("R_LIBS_SITE", home.as_deref()));
+    candidates.extend(SYSTEM_LIBRARIES.iter().map(PathBuf::from));
+
+    if let Ok(r_home) = std::env::var("R_HOME")
+        && !r_home.is_empty()
+    {
+        candidates.push(PathBuf::from(r_home).join("library"));
+    }
+    // R versions installed with rig on Linux, or with the installer on Windows.
+    for version in subdirectories(Path::new("/opt/R")) {
+        candidates.push(version.join("lib").join("R").join("library"));
+    }
+    if let Some(program_files) = std::env::var_os("ProgramFiles") {
+        for version in subdirectories(&PathBuf::from(program_files).join("R")) {
+            candidates.push(version.join("library"));
+        }
+    }
+
+    let mut libraries: Vec<PathBuf> = Vec::new();
+    for path in candidates {
+        if path.is_dir() && !libraries.contains(&path) {
+            libraries.push(path);
+        }
+    }
+    libraries
+}
+
+/// Find the libraries in an renv library directory. Depending on the version
+/// of renv, they are stored in `renv/library/<R version>/<platform>` or
+/// `renv/library/<os>/<R version>/<platform>`.
+fn renv_libraries(dir: &Path) -> Vec<PathBuf> {
+    let mut libraries = Vec::new();
+    let mut to_visit = vec![(dir.to_path_buf(), 0)];
+
+    while let Some((dir, depth)) = to_visit.pop() {
+        let children = subdirectories(&dir);
+        if children
+            .iter()
+            .any(|child| child.join("DESCRIPTION").is_file())
+        {
+            libraries.push(dir);
+        } else if depth < 3 {
+            // Visit children in order, most recent versions first.
+            to_visit.extend(children.into_iter().rev().map(|child| (child, depth + 1)));
+        }
+    }
+
+    libraries
+}
+
+/// Paths in the environment variable `name`, with a leading `~` expanded.
+/// Paths containing R's specifiers (e.g. `%v` for the R version) are skipped
+/// since they can't be expanded without R.
+fn paths_from_env(name: &str, home: Option<&Path>) -> Vec<PathBuf> {
+    let Some(value) = std::env::var_os(name) else {
+        return Vec::new();
+    };
+
+    std::env::split_paths(&value)
+        .filter(|path| !path.as_os_str().is_empty())
+        .filter(|path| !path.to_string_lossy().contains('%'))
+        .filter_map(|path| match path.strip_prefix("~") {
+            Ok(rest) => home.map(|home| home.join(rest)),
+            Err(_) => Some(path),
+        })
+        .collect()
+}
+
+/// Subdirectories of `dir`, sorted in reverse order so that the most recent
+/// versions come first.
+fn subdirectories(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort_unstable_by(|a, b| b.cmp(a));
+    dirs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn install_fake_package(library: &Path, name: &str) {
+        let pkg_dir = library.join(name);
+        std::fs::create_dir_all(&pkg_dir).unwrap();
+        std::fs::write(pkg_dir.join("DESCRIPTION"), format!("Package: {name}\n")).unwrap();
+    }
+
+    #[test]
+    fn test_renv_libraries() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let renv_library = dir.path().join("renv").join("library");
+
+        let old = renv_library.join("R-4.4").join("x86_64-pc-linux-gnu");
+        let new = renv_library
+            .join("linux-ubuntu-noble")
+            .join("R-4.5")
+            .join("x86_64-pc-linux-gnu");
+        install_fake_package(&old, "dplyr");
+        install_fake_package(&new, "dplyr");
+
+        let libraries = find_library_paths(Some(dir.path()));
+        let renv: Vec<&PathBuf> = libraries
+            .iter()
+            .filter(|lib| lib.starts_with(&renv_library))
+            .collect();
+        assert_eq!(renv, vec![&new, &old]);
+    }
+
+    #[test]
+    fn test_renv_library_without_packages() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let renv_library = dir.path().join("renv").join("library");
+        std::fs::create_dir_all(renv_library.join("R-4.5").join("x86_64-pc-linux-gnu")).unwrap();
+
+        assert!(renv_libraries(&renv_library).is_empty());
+    }
+
+    #[test]
+    fn test_subdirectories_most_recent_first() {
+        let dir = tempfile::TempDir::new().unwrap();
+        for version in ["4.3", "4.5", "4.4"] {
+            std::fs::create_dir(dir.path().join(version)).unwrap();
+        }
+        std::fs::write(dir.path().join("file.txt"), "").unwrap();
+
+        let dirs = subdirectories(dir.path());
+        assert_eq!(
+            dirs,
+            vec![
+                dir.path().join("4.5"),
+                dir.path().join("4.4"),
+                dir.path().join("4.3")
+            ]
+        );
+    }
+}
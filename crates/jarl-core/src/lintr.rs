@@ -0,0 +1,1112 @@
+//! Migration of lintr configuration files.
+//!
+//! lintr is configured with a `.lintr` file that uses the DCF format and whose
+//! fields contain R code, for instance:
+//!
+//! ```text
+//! linters: linters_with_defaults(
+//!     quotes_linter(delimiter = "'"),
+//!     object_name_linter = NULL
+//!   )
+//! exclusions: list("R/generated.R", "tests/testthat.R" = list(quotes_linter = Inf))
+//! ```
+//!
+//! This R code is never evaluated: only the calls that are commonly used in
+//! `.lintr` files are recognized. Everything that can't be converted to an
+//! equivalent `jarl.toml` setting is reported so that users know what to
+//! check manually.
+//!
+//! lintr's `# nolint` comments can also be converted to `# jarl-ignore`
+//! comments with [`migrate_nolint()`].
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use air_r_parser::RParserOptions;
+use air_r_syntax::*;
+use biome_rowan::{AstNode, AstSeparatedList, Direction, TextRange, TextSize};
+
+use crate::description::parse_dcf;
+use crate::directive::{NolintDirective, parse_nolint_directive};
+use crate::rule_set::Rule;
+use crate::suppression_edit::format_suppression_comments;
+use crate::utils::get_function_name;
+
+pub const LINTR_FILE_NAME: &str = ".lintr";
+
+/// Linters enabled by default in lintr that have an equivalent in Jarl. Other
+/// default linters are about code style, which is handled by formatters.
+const LINTR_DEFAULT_LINTERS: &[&str] = &[
+    "assignment_linter",
+    "equals_na_linter",
+    "quotes_linter",
+    "seq_linter",
+    "T_and_F_symbol_linter",
+    "vector_logic_linter",
+];
+
+/// Fields of `.lintr` used to ignore specific lines, which can only be done
+/// with comments in Jarl.
+const LINE_EXCLUSION_FIELDS: &[&str] = &[
+    "exclude",
+    "exclude_start",
+    "exclude_end",
+    "exclude_linter",
+    "exclude_linter_sep",
+];
+
+/// Result of the migration of a `.lintr` file.
+#[derive(Debug)]
+pub struct LintrMigration {
+    /// Content of the equivalent `jarl.toml`.
+    pub toml: String,
+    /// Description of each part of `.lintr` that couldn't be migrated.
+    pub unmapped: Vec<String>,
+}
+
+/// Convert the content of a `.lintr` file to an equivalent `jarl.toml`.
+///
+/// `root` is the directory containing `.lintr`. It is used to know whether
+/// the excluded paths are files or directories.
+pub fn migrate_lintr(contents: &str, root: &Path) -> anyhow::Result<LintrMigration> {
+    let fields: BTreeMap<String, String> = parse_dcf(contents).into_iter().collect();
+    let mut migration = Migration::default();
+
+    for (field, value) in &fields {
+        match field.as_str() {
+            "linters" => migration.linters(&parse_field(field, value)?),
+            "exclusions" => migration.exclusions(&parse_field(field, value)?, root),
+            // Jarl only reads UTF-8 files.
+            "encoding" => {}
+            other if LINE_EXCLUSION_FIELDS.contains(&other) => migration.unmapped.push(format!(
+                "Field `{other}` has no equivalent in Jarl. Use `# jarl-ignore` comments to ignore specific lines."
+            )),
+            other => migration
+                .unmapped
+                .push(format!("Field `{other}` has no equivalent in Jarl.")),
+        }
+    }
+
+    Ok(LintrMigration {
+        toml: migration.to_toml(),
+        unmapped: migration.unmapped,
+    })
+}
+
+/// A `# nolint` comment that couldn't be converted by [`migrate_nolint()`].
+#[derive(Debug, PartialEq)]
+pub struct UnconvertedNolint {
+    /// 1-based line of the comment
+    pub line: usize,
+    pub message: String,
+}
+
+/// Result of [`migrate_nolint()`].
+#[derive(Debug)]
+pub struct NolintMigration {
+    /// The code with `# jarl-ignore` comments instead of `# nolint` comments
+    pub source: String,
+    /// Number of `# nolint` comments that were converted
+    pub converted: usize,
+    /// `# nolint` comments that were left untouched
+    pub unconverted: Vec<UnconvertedNolint>,
+}
+
+/// Convert the `# nolint` comments of `source` to `# jarl-ignore` comments,
+/// using `reason` as explanation.
+///
+/// - `x # nolint: seq_linter.` adds `# jarl-ignore seq: <reason>` (and
+///   `seq2`) above the line and removes the `# nolint` comment.
+/// - `# nolint start: seq_linter.` and `# nolint end` are replaced by
+///   `# jarl-ignore-start` and `# jarl-ignore-end` comments.
+///
+/// `# jarl-ignore` comments always need a rule name, so blanket `# nolint`
+/// comments and comments that only list linters without equivalent in Jarl
+/// are left untouched and reported.
+pub fn migrate_nolint(source: &str, reason: &str) -> anyhow::Result<NolintMigration> {
+    let parsed = air_r_parser::parse(source, RParserOptions::default());
+    if parsed.has_error() {
+        return Err(anyhow::anyhow!(
+            "Failed to parse: this is not valid R code."
+        ));
+    }
+
+    let mut edits: Vec<(TextRange, String)> = Vec::new();
+    let mut converted = 0;
+    let mut unconverted = Vec::new();
+    // Rules of the last `# nolint start`, `None` if it wasn't converted.
+    let mut open_range: Option<Option<Vec<Rule>>> = None;
+
+    for token in parsed.syntax().descendants_tokens(Direction::Next) {
+        let pieces = token
+            .leading_trivia()
+            .pieces()
+            .chain(token.trailing_trivia().pieces());
+
+        for piece in pieces.filter(|piece| piece.is_comments()) {
+            let text = piece.text();
+            let Some(directive) = parse_nolint_directive(text) else {
+                continue;
+            };
+
+            let range = piece.text_range();
+            let start = usize::from(range.start());
+            let line_start = source[..start].rfind('\n').map_or(0, |pos| pos + 1);
+            let line = source[..start].matches('\n').count() + 1;
+            let indent: String = source[line_start..]
+                .chars()
+                .take_while(|c| *c == ' ' || *c == '\t')
+                .collect();
+            let code_before = source[line_start..start].trim_end();
+            // The comment and the whitespace separating it from the code.
+            let comment_range = TextRange::new(
+                TextSize::from((line_start + code_before.len()) as u32),
+                range.end(),
+            );
+
+            let mut report = |message: &str| {
+                unconverted.push(UnconvertedNolint { line, message: message.to_string() });
+            };
+
+            if !text[1..].trim_start_matches(' ').starts_with("nolint") {
+                report("`# nolint` is not at the start of the comment.");
+                if matches!(directive, NolintDirective::Start(_)) {
+                    open_range = Some(None);
+                }
+                continue;
+            }
+
+            match directive {
+                NolintDirective::Line(linters) => {
+                    let Some(rules) = nolint_linters_to_rules(linters, &mut report) else {
+                        continue;
+                    };
+                    if code_before.is_empty() {
+                        report("`# nolint` is on its own line so it doesn't skip any code.");
+                        continue;
+                    }
+                    let names: Vec<&str> = rules.iter().map(|rule| rule.name()).collect();
+                    let comments = format_suppression_comments(&names, reason, &indent, false);
+                    let line_start = TextSize::from(line_start as u32);
+                    edits.push((TextRange::empty(line_start), comments));
+                    edits.push((comment_range, String::new()));
+                    converted += 1;
+                }
+                NolintDirective::Start(linters) => {
+                    let rules = nolint_linters_to_rules(linters, &mut report);
+                    if rules.is_some() && !code_before.is_empty() {
+                        report("`# nolint start` must be on its own line.");
+                        open_range = Some(None);
+                        continue;
+                    }
+                    if let Some(rules) = &rules {
+                        let comments: Vec<String> = rules
+                            .iter()
+                            .map(|rule| format!("# jarl-ignore-start {}: {reason}", rule.name()))
+                            .collect();
+                        edits.push((range, comments.join(&format!("\n{indent}"))));
+                        converted += 1;
+                    }
+                    open_range = Some(rules);
+                }
+                NolintDirective::End => {
+                    // Unmatched `# nolint end` comments don't do anything, and
+                    // those of unconverted ranges are left untouched.
+                    let Some(Some(rules)) = open_range.take() else {
+                        continue;
+                    };
+                    let comments: Vec<String> = rules
+                        .iter()
+                        .map(|rule| format!("# jarl-ignore-end {}", rule.name()))
+                        .collect();
+                    let comments = comments.join(&format!("\n{indent}"));
+                    if code_before.is_empty() {
+                        edits.push((range, comments));
+                    } else {
+                        edits.push((comment_range, format!("\n{indent}{comments}")));
+                    }
+                    converted += 1;
+                }
+            }
+        }
+    }
+
+    // Apply the edits from the end so that the ranges stay valid.
+    edits.sort_by_key(|(range, _)| range.start());
+    let mut new_source = source.to_string();
+    for (range, replacement) in edits.iter().rev() {
+        new_source.replace_range(
+            usize::from(range.start())..usize::from(range.end()),
+            replacement,
+        );
+    }
+
+    Ok(NolintMigration { source: new_source, converted, unconverted })
+}
+
+/// Get the Jarl rules of the linters of a `# nolint` comment. Returns `None`
+/// and reports why if the comment can't be converted.
+fn nolint_linters_to_rules(
+    linters: Option<Vec<String>>,
+    report: &mut impl FnMut(&str),
+) -> Option<Vec<Rule>> {
+    let Some(linters) = linters else {
+        report(
+            "Blanket `# nolint` comments can't be converted since `# jarl-ignore` requires a rule name.",
+        );
+        return None;
+    };
+
+    let mut rules: Vec<Rule> = Vec::new();
+    for linter in &linters {
+        let Some(linter_rules) = nolint_rules(linter) else {
+            report(&format!("Linter `{linter}` has no equivalent in Jarl."));
+            return None;
+        };
+        for rule in linter_rules {
+            if !rules.contains(&rule) {
+                rules.push(rule);
+            }
+        }
+    }
+
+    Some(rules)
+}
+
+/// Parse the R code of a field of `.lintr`.
+fn parse_field(field: &str, value: &str) -> anyhow::Result<AnyRExpression> {
+    let parsed = air_r_parser::parse(value, RParserOptions::default());
+    if parsed.has_error() {
+        return Err(anyhow::anyhow!(
+            "Failed to parse field `{field}` of `{LINTR_FILE_NAME}`: `{value}` is not valid R code."
+        ));
+    }
+
+    parsed
+        .tree()
+        .expressions()
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("Field `{field}` of `{LINTR_FILE_NAME}` is empty."))
+}
+
+/// How the set of linters is defined in `.lintr`.
+#[derive(Default, PartialEq)]
+enum Selection {
+    /// `linters_with_defaults()`: lintr's defaults, with some linters added
+    /// or removed.
+    #[default]
+    Defaults,
+    /// `list()`: only the listed linters.
+    Explicit,
+    /// `all_linters()`: all linters, with some linters removed.
+    All,
+}
+
+/// Value of an option in a rule table, e.g. `[lint.quotes]`.
+enum OptionValue {
+    String(String),
+    List(Vec<String>),
+}
+
+#[derive(Default)]
+struct Migration {
+    selection: Selection,
+    enabled: Vec<Rule>,
+    disabled: Vec<Rule>,
+    /// Options of each rule, keyed by rule name.
+    rule_options: BTreeMap<&'static str, Vec<(&'static str, OptionValue)>>,
+    exclude: Vec<String>,
+    per_file_ignores: BTreeMap<String, Vec<Rule>>,
+    unmapped: Vec<String>,
+}
+
+impl Migration {
+    /// Migrate the `linters` field.
+    fn linters(&mut self, expr: &AnyRExpression) {
+        let Some(call) = expr.as_r_call() else {
+            self.unmapped.push(format!(
+                "Field `linters` couldn't be migrated: `{}` is not supported.",
+                expr.to_trimmed_string()
+            ));
+            return;
+        };
+        let Ok(function) = call.function() else {
+            return;
+        };
+        let Ok(args) = call.arguments().map(|args| args.items()) else {
+            return;
+        };
+
+        match get_function_name(function).as_str() {
+            "linters_with_defaults" | "with_defaults" => {
+                self.selection = Selection::Defaults;
+                for arg in args.iter().filter_map(Result::ok) {
+                    if argument_name(&arg).as_deref() == Some("defaults") {
+                        self.linter_defaults(arg.value());
+                    } else {
+                        self.linter(&arg);
+                    }
+                }
+                if self.selection == Selection::Defaults {
+                    let defaults = LINTR_DEFAULT_LINTERS
+                        .iter()
+                        .filter_map(|linter| jarl_rules(linter))
+                        .flatten();
+                    for rule in defaults {
+                        if !self.enabled.contains(&rule) && !self.disabled.contains(&rule) {
+                            self.enabled.push(rule);
+                        }
+                    }
+                }
+            }
+            "list" | "c" => {
+                self.selection = Selection::Explicit;
+                for arg in args.iter().filter_map(Result::ok) {
+                    self.linter(&arg);
+                }
+            }
+            "all_linters" => {
+                self.selection = Selection::All;
+                for arg in args.iter().filter_map(Result::ok) {
+                    // `packages` lists the packages in which linters are
+                    // searched, all Jarl rules are built-in.
+                    if argument_name(&arg).as_deref() != Some("packages") {
+                        self.linter(&arg);
+                    }
+                }
+            }
+            _ => self.unmapped.push(format!(
+                "Field `linters` couldn't be migrated: `{}` is not supported.",
+                expr.to_trimmed_string()
+            )),
+        }
+    }
+
+    /// Migrate the `defaults` argument of `linters_with_defaults()`.
+    fn linter_defaults(&mut self, value: Option<AnyRExpression>) {
+        let Some(value) = value else { return };
+
+        if value
+            .as_r_identifier()
+            .is_some_and(|id| id.to_trimmed_string() == "default_linters")
+        {
+            return;
+        }
+
+        // `defaults = list()` means that only the listed linters are used.
+        if let Some(call) = value.as_r_call()
+            && call
+                .function()
+                .is_ok_and(|f| get_function_name(f) == "list")
+            && call.arguments().is_ok_and(|args| args.items().is_empty())
+        {
+            self.selection = Selection::Explicit;
+            return;
+        }
+
+        self.unmapped.push(format!(
+            "Argument `defaults = {}` of `linters_with_defaults()` couldn't be migrated, lintr's default linters are used instead.",
+            value.to_trimmed_string()
+        ));
+    }
+
+    /// Migrate one element of the list of linters, which can be:
+    /// - `quotes_linter()` or `quotes_linter = quotes_linter()` to enable a
+    ///   linter;
+    /// - `quotes_linter = NULL` to disable a linter.
+    fn linter(&mut self, arg: &RArgument) {
+        let Some(value) = arg.value() else { return };
+        let name = argument_name(arg);
+
+        if value.as_r_null_expression().is_some() {
+            if let Some(rules) = name.as_deref().and_then(jarl_rules) {
+                self.enabled.retain(|rule| !rules.contains(rule));
+                self.disabled.extend(rules);
+            }
+            return;
+        }
+
+        let (linter, args) = if let Some(call) = value.as_r_call() {
+            let Ok(function) = call.function() else {
+                return;
+            };
+            (
+                get_function_name(function),
+                call.arguments().ok().map(|a| a.items()),
+            )
+        } else if let Some(id) = value.as_r_identifier() {
+            (id.to_trimmed_string(), None)
+        } else {
+            self.unmapped.push(format!(
+                "Linter `{}` couldn't be migrated.",
+                value.to_trimmed_string()
+            ));
+            return;
+        };
+
+        let Some(rules) = jarl_rules(&linter) else {
+            self.unmapped
+                .push(format!("Linter `{linter}` has no equivalent in Jarl."));
+            return;
+        };
+
+        if let Some(args) = args {
+            self.linter_arguments(&linter, &args);
+        }
+        self.disabled.retain(|rule| !rules.contains(rule));
+        for rule in rules {
+            if !self.enabled.contains(&rule) {
+                self.enabled.push(rule);
+            }
+        }
+    }
+
+    /// Migrate the arguments of a linter to the options of the equivalent
+    /// rule.
+    fn linter_arguments(&mut self, linter: &str, args: &RArgumentList) {
+        // Only one argument per linter has an equivalent in Jarl. It is also
+        // the first parameter of the linter, so it can be passed unnamed.
+        let parameter = match linter {
+            "assignment_linter" => Some("operator"),
+            "quotes_linter" => Some("delimiter"),
+            "pipe_consistency_linter" => Some("pipe"),
+            "duplicate_argument_linter" | "implicit_assignment_linter" => Some("except"),
+            "undesirable_function_linter" => Some("fun"),
+            _ => None,
+        };
+        let args: Vec<RArgument> = args.iter().filter_map(Result::ok).collect();
+        let parameter_is_named = args
+            .iter()
+            .any(|arg| parameter.is_some() && argument_name(arg).as_deref() == parameter);
+
+        for (i, arg) in args.iter().enumerate() {
+            let Some(value) = arg.value() else { continue };
+            let name = argument_name(arg);
+
+            let is_parameter = match &name {
+                Some(name) => Some(name.as_str()) == parameter,
+                None => i == 0 && !parameter_is_named && parameter.is_some(),
+            };
+            let name = name.or(parameter.map(String::from).filter(|_| is_parameter));
+
+            if is_parameter && self.linter_option(linter, &value) {
+                continue;
+            }
+
+            self.unmapped.push(match name {
+                Some(name) => format!(
+                    "Argument `{name} = {}` of `{linter}` has no equivalent in Jarl.",
+                    value.to_trimmed_string()
+                ),
+                None => format!(
+                    "Argument `{}` of `{linter}` has no equivalent in Jarl.",
+                    value.to_trimmed_string()
+                ),
+            });
+        }
+    }
+
+    /// Convert the value of the main argument of `linter` to a rule option.
+    /// Returns `false` if the value has no equivalent.
+    fn linter_option(&mut self, linter: &str, value: &AnyRExpression) -> bool {
+        let (rule, option, value) = match linter {
+            "assignment_linter" => {
+                let Some(operators) = string_vector(value) else {
+                    return false;
+                };
+                let operator = match (
+                    operators.iter().any(|op| op == "="),
+                    operators.iter().any(|op| op == "<-"),
+                ) {
+                    (true, false) => "=",
+                    (false, true) => "<-",
+                    _ => return false,
+                };
+                (
+                    "assignment",
+                    "operator",
+                    OptionValue::String(operator.to_string()),
+                )
+            }
+            "quotes_linter" => {
+                let quote = match string_vector(value).as_deref() {
+                    Some([delimiter]) if delimiter == "\"" => "double",
+                    Some([delimiter]) if delimiter == "'" => "single",
+                    _ => return false,
+                };
+                ("quotes", "quote", OptionValue::String(quote.to_string()))
+            }
+            "pipe_consistency_linter" => {
+                let pipe = match string_vector(value).as_deref() {
+                    Some([pipe]) if pipe == "|>" || pipe == "%>%" => pipe.clone(),
+                    _ => return false,
+                };
+                ("pipe_consistency", "pipe", OptionValue::String(pipe))
+            }
+            "duplicate_argument_linter" | "implicit_assignment_linter" => {
+                let Some(functions) = string_vector(value) else {
+                    return false;
+                };
+                let rule = if linter == "duplicate_argument_linter" {
+                    "duplicated_arguments"
+                } else {
+                    "implicit_assignment"
+                };
+                (rule, "skipped-functions", OptionValue::List(functions))
+            }
+            "undesirable_function_linter" => {
+                return self.undesirable_functions(value);
+            }
+            _ => return false,
+        };
+
+        self.rule_options
+            .entry(rule)
+            .or_default()
+            .push((option, value));
+        true
+    }
+
+    /// Migrate the argument `fun` of `undesirable_function_linter()`, which is
+    /// a named vector whose names are the undesirable functions, e.g.
+    /// `c(sapply = "use vapply() instead")` or
+    /// `modify_defaults(defaults = default_undesirable_functions, sapply = NA)`.
+    fn undesirable_functions(&mut self, value: &AnyRExpression) -> bool {
+        if value.as_r_identifier().is_some_and(|id| {
+            let name = id.to_trimmed_string();
+            name == "default_undesirable_functions" || name == "all_undesirable_functions"
+        }) {
+            return true;
+        }
+
+        let Some(call) = value.as_r_call() else {
+            return false;
+        };
+        let Ok(function) = call.function() else {
+            return false;
+        };
+        let Ok(args) = call.arguments().map(|args| args.items()) else {
+            return false;
+        };
+
+        let option = match get_function_name(function).as_str() {
+            "c" | "list" => "functions",
+            "modify_defaults" => "extend-functions",
+            _ => return false,
+        };
+
+        let mut functions = Vec::new();
+        for arg in args.iter().filter_map(Result::ok) {
+            let Some(value) = arg.value() else { continue };
+            match argument_name(&arg) {
+                Some(name) if name == "defaults" => {}
+                // `modify_defaults(defaults, fun = NULL)` removes a function.
+                Some(_) if value.as_r_null_expression().is_some() => {}
+                Some(name) => functions.push(name),
+                None => match string_value(&value) {
+                    Some(name) => functions.push(name),
+                    None => return false,
+                },
+            }
+        }
+
+        if !functions.is_empty() {
+            self.rule_options
+                .entry("undesirable_function")
+                .or_default()
+                .push((option, OptionValue::List(functions)));
+        }
+        true
+    }
+
+    /// Migrate the `exclusions` field. Files and directories that are fully
+    /// excluded go to `exclude`, linters excluded for some files go to
+    /// `per-file-ignores`.
+    fn exclusions(&mut self, expr: &AnyRExpression, root: &Path) {
+        if let Some(path) = string_value(expr) {
+            self.exclude_path(&path, root);
+            return;
+        }
+
+        let args = expr
+            .as_r_call()
+            .filter(|call| {
+                call.function()
+                    .is_ok_and(|f| matches!(get_function_name(f).as_str(), "list" | "c"))
+            })
+            .and_then(|call| call.arguments().ok())
+            .map(|args| args.items());
+        let Some(args) = args else {
+            self.unmapped.push(format!(
+                "Field `exclusions` couldn't be migrated: `{}` is not supported.",
+                expr.to_trimmed_string()
+            ));
+            return;
+        };
+
+        for arg in args.iter().filter_map(Result::ok) {
+            let Some(value) = arg.value() else { continue };
+
+            let Some(path) = argument_name(&arg) else {
+                match string_value(&value) {
+                    Some(path) => self.exclude_path(&path, root),
+                    None => self.unmapped.push(format!(
+                        "Exclusion `{}` couldn't be migrated.",
+                        value.to_trimmed_string()
+                    )),
+                }
+                continue;
+            };
+
+            if matches!(value, AnyRExpression::RInfExpression(_)) {
+                self.exclude_path(&path, root);
+            } else if let Some(linters) = value.as_r_call().filter(|call| {
+                call.function()
+                    .is_ok_and(|f| matches!(get_function_name(f).as_str(), "list" | "c"))
+            }) {
+                let Ok(linters) = linters.arguments().map(|args| args.items()) else {
+                    continue;
+                };
+                self.excluded_linters(&path, &linters, root);
+            } else {
+                self.line_exclusion(&path, &value);
+            }
+        }
+    }
+
+    /// Migrate the linters excluded for a specific path, e.g.
+    /// `"R/foo.R" = list(quotes_linter = Inf)`.
+    fn excluded_linters(&mut self, path: &str, linters: &RArgumentList, root: &Path) {
+        let Some(pattern) = self.path_pattern(path, root) else {
+            return;
+        };
+
+        for arg in linters.iter().filter_map(Result::ok) {
+            let Some(value) = arg.value() else { continue };
+            let Some(linter) = argument_name(&arg) else {
+                self.line_exclusion(path, &value);
+                continue;
+            };
+
+            if !matches!(value, AnyRExpression::RInfExpression(_)) {
+                self.line_exclusion(path, &value);
+                continue;
+            }
+
+            // Linters without equivalent don't need to be ignored.
+            if let Some(rules) = jarl_rules(&linter) {
+                let ignored = self.per_file_ignores.entry(pattern.clone()).or_default();
+                for rule in rules {
+                    if !ignored.contains(&rule) {
+                        ignored.push(rule);
+                    }
+                }
+            }
+        }
+    }
+
+    fn exclude_path(&mut self, path: &str, root: &Path) {
+        if let Some(pattern) = self.path_pattern(path, root)
+            && !self.exclude.contains(&pattern)
+        {
+            self.exclude.push(pattern);
+        }
+    }
+
+    fn line_exclusion(&mut self, path: &str, lines: &AnyRExpression) {
+        self.unmapped.push(format!(
+            "Exclusion of lines `{}` in \"{path}\" has no equivalent in Jarl. Use `# jarl-ignore` comments to ignore specific lines.",
+            lines.to_trimmed_string()
+        ));
+    }
+
+    /// Convert a path relative to the directory of `.lintr` to a pattern
+    /// matching only this path in `jarl.toml`.
+    fn path_pattern(&mut self, path: &str, root: &Path) -> Option<String> {
+        if Path::new(path).is_absolute() || path.starts_with('~') {
+            self.unmapped.push(format!(
+                "Exclusion of \"{path}\" couldn't be migrated, only paths relative to the directory of `{LINTR_FILE_NAME}` are supported."
+            ));
+            return None;
+        }
+
+        let path = path.strip_prefix("./").unwrap_or(path);
+        let trimmed = path.trim_end_matches('/');
+
+        // Patterns without inner slash match files at any depth, so they are
+        // anchored to the root.
+        let mut pattern = if trimmed.contains('/') {
+            trimmed.to_string()
+        } else {
+            format!("/{trimmed}")
+        };
+        if path.ends_with('/') || root.join(trimmed).is_dir() {
+            pattern.push('/');
+        }
+
+        Some(pattern)
+    }
+
+    fn to_toml(&self) -> String {
+        let mut lint = Vec::new();
+
+        match self.selection {
+            Selection::Defaults => {
+                // Rules disabled by default in Jarl must be enabled
+                // explicitly, and rules enabled by default in Jarl must be
+                // ignored explicitly.
+                let extend_select: Vec<&Rule> = self
+                    .enabled
+                    .iter()
+                    .filter(|rule| !rule.is_enabled_by_default())
+                    .collect();
+                let ignore: Vec<&Rule> = self
+                    .disabled
+                    .iter()
+                    .filter(|rule| rule.is_enabled_by_default())
+                    .collect();
+                if !extend_select.is_empty() {
+                    lint.push(format!("extend-select = {}", rule_list(extend_select)));
+                }
+                if !ignore.is_empty() {
+                    lint.push(format!("ignore = {}", rule_list(ignore)));
+                }
+            }
+            Selection::Explicit => {
+                lint.push(format!("select = {}", rule_list(self.enabled.iter())));
+            }
+            Selection::All => {
+                lint.push(format!("select = {}", toml_list(["ALL"])));
+                if !self.disabled.is_empty() {
+                    lint.push(format!("ignore = {}", rule_list(self.disabled.iter())));
+                }
+            }
+        }
+
+        if !self.exclude.is_empty() {
+            lint.push(format!("exclude = {}", toml_list(&self.exclude)));
+        }
+
+        let mut toml =
+            format!("# Generated by `jarl migrate lintr` from `{LINTR_FILE_NAME}`.\n\n[lint]\n");
+        for line in lint {
+            toml.push_str(&line);
+            toml.push('\n');
+        }
+
+        if !self.per_file_ignores.is_empty() {
+            toml.push_str("\n[lint.per-file-ignores]\n");
+            for (pattern, rules) in &self.per_file_ignores {
+                toml.push_str(&format!(
+                    "{} = {}\n",
+                    toml_string(pattern),
+                    rule_list(rules)
+                ));
+            }
+        }
+
+        for (rule, options) in &self.rule_options {
+            toml.push_str(&format!("\n[lint.{rule}]\n"));
+            for (option, value) in options {
+                let value = match value {
+                    OptionValue::String(value) => toml_string(value),
+                    OptionValue::List(values) => toml_list(values),
+                };
+                toml.push_str(&format!("{option} = {value}\n"));
+            }
+        }
+
+        toml
+    }
+}
+
+/// Get the Jarl rules equivalent to a linter listed in a `# nolint` comment.
+/// lintr accepts linter names with or without the `_linter` suffix.
+pub(crate) fn nolint_rules(linter: &str) -> Option<Vec<Rule>> {
+    if linter.ends_with("_linter") {
+        jarl_rules(linter)
+    } else {
+        jarl_rules(&format!("{linter}_linter"))
+    }
+}
+
+/// Get the Jarl rules equivalent to a lintr linter, e.g. `"seq_linter"` is
+/// covered by `seq` and `seq2`.
+fn jarl_rules(linter: &str) -> Option<Vec<Rule>> {
+    let name = linter.strip_suffix("_linter")?;
+
+    let rules = match name {
+        "duplicate_argument" => vec![Rule::DuplicatedArguments],
+        "seq" => vec![Rule::Seq, Rule::Seq2],
+        "T_and_F_symbol" => vec![Rule::TrueFalseSymbol],
+        _ => vec![Rule::from_name(name).filter(|rule| !rule.is_deprecated())?],
+    };
+
+    Some(rules)
+}
+
+/// Get the name of an argument, without quotes or backticks.
+fn argument_name(arg: &RArgument) -> Option<String> {
+    let name = arg.name_clause()?.name().ok()?.to_trimmed_string();
+    Some(name.trim_matches(['"', '\'', '`']).to_string())
+}
+
+/// Get the content of a string literal.
+fn string_value(expr: &AnyRExpression) -> Option<String> {
+    let text = expr
+        .as_any_r_value()?
+        .as_r_string_value()?
+        .to_trimmed_string();
+    let quote = text.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let content = text.strip_prefix(quote)?.strip_suffix(quote)?;
+
+    Some(
+        content
+            .replace(&format!("\\{quote}"), &quote.to_string())
+            .replace("\\\\", "\\"),
+    )
+}
+
+/// Get the content of a string literal or of a vector of string literals,
+/// e.g. `c("mutate", "transmute")`.
+fn string_vector(expr: &AnyRExpression) -> Option<Vec<String>> {
+    if let Some(value) = string_value(expr) {
+        return Some(vec![value]);
+    }
+
+    let call = expr.as_r_call()?;
+    if get_function_name(call.function().ok()?) != "c" {
+        return None;
+    }
+    call.arguments()
+        .ok()?
+        .items()
+        .iter()
+        .map(|arg| string_value(&arg.ok()?.value()?))
+        .collect()
+}
+
+fn toml_string(value: &str) -> String {
+    toml::Value::String(value.to_string()).to_string()
+}
+
+fn toml_list<I, S>(values: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let values: Vec<String> = values
+        .into_iter()
+        .map(|value| toml_string(value.as_ref()))
+        .collect();
+    format!("[{}]", values.join(", "))
+}
+
+fn rule_list<'a>(rules: impl IntoIterator<Item = &'a Rule>) -> String {
+    toml_list(rules.into_iter().map(|rule| rule.name()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migrate(contents: &str) -> LintrMigration {
+        let dir = tempfile::TempDir::new().unwrap();
+        migrate_lintr(contents, dir.path()).unwrap()
+    }
+
+    #[test]
+    fn test_linters_with_defaults() {
+        let migration = migrate(
+            r#"linters: linters_with_defaults(
+    line_length_linter(120),
+    T_and_F_symbol_linter = NULL,
+    any_is_na_linter = NULL,
+    quotes_linter(delimiter = "'"),
+    undesirable_function_linter(fun = c(sapply = "use vapply()", library = NA))
+  )
+encoding: "UTF-8"
+"#,
+        );
+
+        insta::assert_snapshot!(migration.toml, @r#"
+        # Generated by `jarl migrate lintr` from `.lintr`.
+
+        [lint]
+        extend-select = ["quotes", "assignment"]
+        ignore = ["true_false_symbol", "any_is_na"]
+
+        [lint.quotes]
+        quote = "single"
+
+        [lint.undesirable_function]
+        functions = ["sapply", "library"]
+        "#);
+        assert_eq!(
+            migration.unmapped,
+            vec!["Linter `line_length_linter` has no equivalent in Jarl."]
+        );
+    }
+
+    #[test]
+    fn test_explicit_list_of_linters() {
+        let migration = migrate(
+            r#"linters: list(
+    assignment_linter(operator = "="),
+    seq_linter(),
+    duplicate_argument_linter(c("mutate", "list")),
+    implicit_assignment_linter(allow_lazy = TRUE)
+  )
+"#,
+        );
+
+        insta::assert_snapshot!(migration.toml, @r#"
+        # Generated by `jarl migrate lintr` from `.lintr`.
+
+        [lint]
+        select = ["assignment", "seq", "seq2", "duplicated_arguments", "implicit_assignment"]
+
+        [lint.assignment]
+        operator = "="
+
+        [lint.duplicated_arguments]
+        skipped-functions = ["mutate", "list"]
+        "#);
+        assert_eq!(
+            migration.unmapped,
+            vec![
+                "Argument `allow_lazy = TRUE` of `implicit_assignment_linter` has no equivalent in Jarl."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_defaults_replaced_and_all_linters() {
+        let migration =
+            migrate("linters: linters_with_defaults(defaults = list(), any_is_na_linter())\n");
+        assert!(migration.toml.contains("select = [\"any_is_na\"]\n"));
+
+        let migration = migrate(
+            "linters: all_linters(packages = \"lintr\", undesirable_function_linter(modify_defaults(defaults = default_undesirable_functions, sapply = NA)), nzchar_linter = NULL)\n",
+        );
+        insta::assert_snapshot!(migration.toml, @r#"
+        # Generated by `jarl migrate lintr` from `.lintr`.
+
+        [lint]
+        select = ["ALL"]
+        ignore = ["nzchar"]
+
+        [lint.undesirable_function]
+        extend-functions = ["sapply"]
+        "#);
+        assert!(migration.unmapped.is_empty());
+    }
+
+    #[test]
+    fn test_exclusions() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(dir.path().join("data-raw")).unwrap();
+
+        let migration = migrate_lintr(
+            r##"exclusions: list(
+    "generated.R",
+    "data-raw",
+    "tests/testthat/setup.R" = Inf,
+    "R/foo.R" = list(quotes_linter = Inf, object_name_linter = Inf, seq_linter = 1:3),
+    "R/bar.R" = 10:20
+  )
+exclude: "# nolint"
+"##,
+            dir.path(),
+        )
+        .unwrap();
+
+        insta::assert_snapshot!(migration.toml, @r#"
+        # Generated by `jarl migrate lintr` from `.lintr`.
+
+        [lint]
+        exclude = ["/generated.R", "/data-raw/", "tests/testthat/setup.R"]
+
+        [lint.per-file-ignores]
+        "R/foo.R" = ["quotes"]
+        "#);
+        assert_eq!(
+            migration.unmapped,
+            vec![
+                "Field `exclude` has no equivalent in Jarl. Use `# jarl-ignore` comments to ignore specific lines.",
+                "Exclusion of lines `1:3` in \"R/foo.R\" has no equivalent in Jarl. Use `# jarl-ignore` comments to ignore specific lines.",
+                "Exclusion of lines `10:20` in \"R/bar.R\" has no equivalent in Jarl. Use `# jarl-ignore` comments to ignore specific lines.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_lintr() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let err = migrate_lintr("linters: list(\n", dir.path()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse field `linters` of `.lintr`: `list(` is not valid R code."
+        );
+    }
+
+    #[test]
+    fn test_nolint_rules() {
+        assert_eq!(nolint_rules("any_is_na_linter"), Some(vec![Rule::AnyIsNa]));
+        assert_eq!(nolint_rules("any_is_na"), Some(vec![Rule::AnyIsNa]));
+        assert_eq!(nolint_rules("seq"), Some(vec![Rule::Seq, Rule::Seq2]));
+        assert_eq!(nolint_rules("object_name_linter"), None);
+    }
+
+    #[test]
+    fn test_migrate_nolint() {
+        let migration = migrate_nolint(
+            r#"f <- function() {
+  x <- T # nolint: T_and_F_symbol_linter.
+  y <- F # nolint
+  z <- 1:length(x) # nolint: seq, object_name_linter.
+}
+
+# nolint start: any_is_na_linter, seq_linter.
+any(is.na(x))
+1:length(x)
+# nolint end
+"#,
+            "<reason>",
+        )
+        .unwrap();
+
+        insta::assert_snapshot!(migration.source, @r#"
+        f <- function() {
+          # jarl-ignore true_false_symbol: <reason>
+          x <- T
+          y <- F # nolint
+          z <- 1:length(x) # nolint: seq, object_name_linter.
+        }
+
+        # jarl-ignore-start any_is_na: <reason>
+        # jarl-ignore-start seq: <reason>
+        # jarl-ignore-start seq2: <reason>
+        any(is.na(x))
+        1:length(x)
+        # jarl-ignore-end any_is_na
+        # jarl-ignore-end seq
+        # jarl-ignore-end seq2
+        "#);
+        assert_eq!(migration.converted, 3);
+        assert_eq!(
+            migration.unconverted,
+            vec![
+                UnconvertedNolint {
+                    line: 3,
+                    message: "Blanket `# nolint` comments can't be converted since `# jarl-ignore` requires a rule name.".to_string()
+                },
+                UnconvertedNolint {
+                    line: 4,
+                    message: "Linter `object_name_linter` has no equivalent in Jarl.".to_string()
+                },
+            ]
+        );
+    }
+}
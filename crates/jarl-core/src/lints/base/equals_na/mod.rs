@@ -14,11 +14,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("x == NA"),
             @"
-        warning: equals_na
+        error: equals_na
          --> <test>:1:1
           |
         1 | x == NA
-          | ------- Comparing to NA with `==` is problematic.
+          | ^^^^^^^ Comparing to NA with `==` is problematic.
           |
           = help: Use `is.na()` instead.
         Found 1 error.
@@ -27,11 +27,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("x == NA_integer_"),
             @"
-        warning: equals_na
+        error: equals_na
          --> <test>:1:1
           |
         1 | x == NA_integer_
-          | ---------------- Comparing to NA with `==` is problematic.
+          | ^^^^^^^^^^^^^^^^ Comparing to NA with `==` is problematic.
           |
           = help: Use `is.na()` instead.
         Found 1 error.
@@ -40,11 +40,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("x == NA_real_"),
             @"
-        warning: equals_na
+        error: equals_na
          --> <test>:1:1
           |
         1 | x == NA_real_
-          | ------------- Comparing to NA with `==` is problematic.
+          | ^^^^^^^^^^^^^ Comparing to NA with `==` is problematic.
           |
           = help: Use `is.na()` instead.
         Found 1 error.
@@ -53,11 +53,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("x == NA_logical_"),
             @"
-        warning: equals_na
+        error: equals_na
          --> <test>:1:1
           |
         1 | x == NA_logical_
-          | ---------------- Comparing to NA with `==` is problematic.
+          | ^^^^^^^^^^^^^^^^ Comparing to NA with `==` is problematic.
           |
           = help: Use `is.na()` instead.
         Found 1 error.
@@ -66,11 +66,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("x == NA_character_"),
             @"
-        warning: equals_na
+        error: equals_na
          --> <test>:1:1
           |
         1 | x == NA_character_
-          | ------------------ Comparing to NA with `==` is problematic.
+          | ^^^^^^^^^^^^^^^^^^ Comparing to NA with `==` is problematic.
           |
           = help: Use `is.na()` instead.
         Found 1 error.
@@ -79,11 +79,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("x == NA_complex_"),
             @"
-        warning: equals_na
+        error: equals_na
          --> <test>:1:1
           |
         1 | x == NA_complex_
-          | ---------------- Comparing to NA with `==` is problematic.
+          | ^^^^^^^^^^^^^^^^ Comparing to NA with `==` is problematic.
           |
           = help: Use `is.na()` instead.
         Found 1 error.
@@ -92,11 +92,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("x != NA"),
             @"
-        warning: equals_na
+        error: equals_na
          --> <test>:1:1
           |
         1 | x != NA
-          | ------- Comparing to NA with `!=` is problematic.
+          | ^^^^^^^ Comparing to NA with `!=` is problematic.
           |
           = help: Use `!is.na()` instead.
         Found 1 error.
@@ -105,11 +105,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("x %in% NA"),
             @"
-        warning: equals_na
+        error: equals_na
          --> <test>:1:1
           |
         1 | x %in% NA
-          | --------- Comparing to NA with `%in%` is problematic.
+          | ^^^^^^^^^ Comparing to NA with `%in%` is problematic.
           |
           = help: Use `is.na()` instead.
         Found 1 error.
@@ -118,11 +118,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("x %notin% NA"),
             @"
-        warning: equals_na
+        error: equals_na
          --> <test>:1:1
           |
         1 | x %notin% NA
-          | ------------ Comparing to NA with `%notin%` is problematic.
+          | ^^^^^^^^^^^^ Comparing to NA with `%notin%` is problematic.
           |
           = help: Use `!is.na()` instead.
         Found 1 error.
@@ -131,11 +131,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("foo(x(y)) == NA"),
             @"
-        warning: equals_na
+        error: equals_na
          --> <test>:1:1
           |
         1 | foo(x(y)) == NA
-          | --------------- Comparing to NA with `==` is problematic.
+          | ^^^^^^^^^^^^^^^ Comparing to NA with `==` is problematic.
           |
           = help: Use `is.na()` instead.
         Found 1 error.
@@ -144,11 +144,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("NA == x"),
             @"
-        warning: equals_na
+        error: equals_na
          --> <test>:1:1
           |
         1 | NA == x
-          | ------- Comparing to NA with `==` is problematic.
+          | ^^^^^^^ Comparing to NA with `==` is problematic.
           |
           = help: Use `is.na()` instead.
         Found 1 error.
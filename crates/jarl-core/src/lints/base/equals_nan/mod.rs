@@ -14,11 +14,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("x == NaN"),
             @"
-        warning: equals_nan
+        error: equals_nan
          --> <test>:1:1
           |
         1 | x == NaN
-          | -------- Comparing to NaN with `==`, `!=` or `%in%` is problematic.
+          | ^^^^^^^^ Comparing to NaN with `==`, `!=` or `%in%` is problematic.
           |
           = help: Use `is.nan()` instead.
         Found 1 error.
@@ -27,11 +27,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("x != NaN"),
             @"
-        warning: equals_nan
+        error: equals_nan
          --> <test>:1:1
           |
         1 | x != NaN
-          | -------- Comparing to NaN with `==`, `!=` or `%in%` is problematic.
+          | ^^^^^^^^ Comparing to NaN with `==`, `!=` or `%in%` is problematic.
           |
           = help: Use `is.nan()` instead.
         Found 1 error.
@@ -40,11 +40,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("x %in% NaN"),
             @"
-        warning: equals_nan
+        error: equals_nan
          --> <test>:1:1
           |
         1 | x %in% NaN
-          | ---------- Comparing to NaN with `==`, `!=` or `%in%` is problematic.
+          | ^^^^^^^^^^ Comparing to NaN with `==`, `!=` or `%in%` is problematic.
           |
           = help: Use `is.nan()` instead.
         Found 1 error.
@@ -53,11 +53,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("foo(x(y)) == NaN"),
             @"
-        warning: equals_nan
+        error: equals_nan
          --> <test>:1:1
           |
         1 | foo(x(y)) == NaN
-          | ---------------- Comparing to NaN with `==`, `!=` or `%in%` is problematic.
+          | ^^^^^^^^^^^^^^^^ Comparing to NaN with `==`, `!=` or `%in%` is problematic.
           |
           = help: Use `is.nan()` instead.
         Found 1 error.
@@ -66,11 +66,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("NaN == x"),
             @"
-        warning: equals_nan
+        error: equals_nan
          --> <test>:1:1
           |
         1 | NaN == x
-          | -------- Comparing to NaN with `==`, `!=` or `%in%` is problematic.
+          | ^^^^^^^^ Comparing to NaN with `==`, `!=` or `%in%` is problematic.
           |
           = help: Use `is.nan()` instead.
         Found 1 error.
@@ -14,11 +14,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("x == NULL"),
             @"
-        warning: equals_null
+        error: equals_null
          --> <test>:1:1
           |
         1 | x == NULL
-          | --------- Comparing to NULL with `==`, `!=` or `%in%` is problematic.
+          | ^^^^^^^^^ Comparing to NULL with `==`, `!=` or `%in%` is problematic.
           |
           = help: Use `is.null()` instead.
         Found 1 error.
@@ -27,11 +27,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("x != NULL"),
             @"
-        warning: equals_null
+        error: equals_null
          --> <test>:1:1
           |
         1 | x != NULL
-          | --------- Comparing to NULL with `==`, `!=` or `%in%` is problematic.
+          | ^^^^^^^^^ Comparing to NULL with `==`, `!=` or `%in%` is problematic.
           |
           = help: Use `is.null()` instead.
         Found 1 error.
@@ -40,11 +40,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("x %in% NULL"),
             @"
-        warning: equals_null
+        error: equals_null
          --> <test>:1:1
           |
         1 | x %in% NULL
-          | ----------- Comparing to NULL with `==`, `!=` or `%in%` is problematic.
+          | ^^^^^^^^^^^ Comparing to NULL with `==`, `!=` or `%in%` is problematic.
           |
           = help: Use `is.null()` instead.
         Found 1 error.
@@ -53,11 +53,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("foo(x(y)) == NULL"),
             @"
-        warning: equals_null
+        error: equals_null
          --> <test>:1:1
           |
         1 | foo(x(y)) == NULL
-          | ----------------- Comparing to NULL with `==`, `!=` or `%in%` is problematic.
+          | ^^^^^^^^^^^^^^^^^ Comparing to NULL with `==`, `!=` or `%in%` is problematic.
           |
           = help: Use `is.null()` instead.
         Found 1 error.
@@ -66,11 +66,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("NULL == x"),
             @"
-        warning: equals_null
+        error: equals_null
          --> <test>:1:1
           |
         1 | NULL == x
-          | --------- Comparing to NULL with `==`, `!=` or `%in%` is problematic.
+          | ^^^^^^^^^ Comparing to NULL with `==`, `!=` or `%in%` is problematic.
           |
           = help: Use `is.null()` instead.
         Found 1 error.
@@ -58,11 +58,11 @@ mod tests {
                     x
                 }
             }"), @"
-        warning: for_loop_dup_index
+        error: for_loop_dup_index
          --> <test>:2:22
           |
         2 |                 for (x in 1:4) {
-          |                      -------- This index variable is already used in a parent `for` loop.
+          |                      ^^^^^^^^ This index variable is already used in a parent `for` loop.
           |
           = help: Rename this index variable to avoid unexpected results.
         Found 1 error.
@@ -75,11 +75,11 @@ mod tests {
                     x
                 }
             }"), @"
-        warning: for_loop_dup_index
+        error: for_loop_dup_index
          --> <test>:2:24
           |
         2 |                 for (  x    in 1:4) {
-          |                        ----------- This index variable is already used in a parent `for` loop.
+          |                        ^^^^^^^^^^^ This index variable is already used in a parent `for` loop.
           |
           = help: Rename this index variable to avoid unexpected results.
         Found 1 error.
@@ -94,11 +94,11 @@ mod tests {
                     i
                 }
             }"), @"
-        warning: for_loop_dup_index
+        error: for_loop_dup_index
          --> <test>:4:22
           |
         4 |                 for (i in 1:4) {
-          |                      -------- This index variable is already used in a parent `for` loop.
+          |                      ^^^^^^^^ This index variable is already used in a parent `for` loop.
           |
           = help: Rename this index variable to avoid unexpected results.
         Found 1 error.
@@ -113,11 +113,11 @@ mod tests {
                     }
                 }
             }"), @"
-        warning: for_loop_dup_index
+        error: for_loop_dup_index
          --> <test>:3:26
           |
         3 |                     for (i in 1:5) {
-          |                          -------- This index variable is already used in a parent `for` loop.
+          |                          ^^^^^^^^ This index variable is already used in a parent `for` loop.
           |
           = help: Rename this index variable to avoid unexpected results.
         Found 1 error.
@@ -132,11 +132,11 @@ mod tests {
                     }
                 }
             }"), @"
-        warning: for_loop_dup_index
+        error: for_loop_dup_index
          --> <test>:2:22
           |
         2 |                 for (i in 1:4) {
-          |                      -------- This index variable is already used in a parent `for` loop.
+          |                      ^^^^^^^^ This index variable is already used in a parent `for` loop.
           |
           = help: Rename this index variable to avoid unexpected results.
         Found 1 error.
@@ -14,11 +14,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("length(x != 0)"),
             @"
-        warning: length_test
+        error: length_test
          --> <test>:1:1
           |
         1 | length(x != 0)
-          | -------------- Checking the length of a logical vector is likely a mistake
+          | ^^^^^^^^^^^^^^ Checking the length of a logical vector is likely a mistake
           |
         Found 1 error.
         "
@@ -26,11 +26,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("length(x >= 0)"),
             @"
-        warning: length_test
+        error: length_test
          --> <test>:1:1
           |
         1 | length(x >= 0)
-          | -------------- Checking the length of a logical vector is likely a mistake
+          | ^^^^^^^^^^^^^^ Checking the length of a logical vector is likely a mistake
           |
         Found 1 error.
         "
@@ -38,11 +38,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("length(x <= 0)"),
             @"
-        warning: length_test
+        error: length_test
          --> <test>:1:1
           |
         1 | length(x <= 0)
-          | -------------- Checking the length of a logical vector is likely a mistake
+          | ^^^^^^^^^^^^^^ Checking the length of a logical vector is likely a mistake
           |
         Found 1 error.
         "
@@ -50,11 +50,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("length(x > 0)"),
             @"
-        warning: length_test
+        error: length_test
          --> <test>:1:1
           |
         1 | length(x > 0)
-          | ------------- Checking the length of a logical vector is likely a mistake
+          | ^^^^^^^^^^^^^ Checking the length of a logical vector is likely a mistake
           |
         Found 1 error.
         "
@@ -62,11 +62,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("length(x < 0)"),
             @"
-        warning: length_test
+        error: length_test
          --> <test>:1:1
           |
         1 | length(x < 0)
-          | ------------- Checking the length of a logical vector is likely a mistake
+          | ^^^^^^^^^^^^^ Checking the length of a logical vector is likely a mistake
           |
         Found 1 error.
         "
@@ -74,11 +74,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("length(x < 0)"),
             @"
-        warning: length_test
+        error: length_test
          --> <test>:1:1
           |
         1 | length(x < 0)
-          | ------------- Checking the length of a logical vector is likely a mistake
+          | ^^^^^^^^^^^^^ Checking the length of a logical vector is likely a mistake
           |
         Found 1 error.
         "
@@ -86,11 +86,11 @@ mod tests {
         assert_snapshot!(
             snapshot_lint("length(x + y == 2)"),
             @"
-        warning: length_test
+        error: length_test
          --> <test>:1:1
           |
         1 | length(x + y == 2)
-          | ------------------ Checking the length of a logical vector is likely a mistake
+          | ^^^^^^^^^^^^^^^^^^ Checking the length of a logical vector is likely a mistake
           |
         Found 1 error.
         "
@@ -587,6 +587,7 @@ mod tests {
             allow_dirty: false,
             allow_no_vcs: true,
             assignment: None,
+            preview: false,
         };
 
         let config = build_config(&args, None, paths).unwrap();
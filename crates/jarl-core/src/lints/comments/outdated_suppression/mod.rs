@@ -116,11 +116,11 @@ y <- 2", "outdated_suppression,any_is_na"), @"
         insta::assert_snapshot!(snapshot_lint("
 # jarl-ignore any_is_na: <reason>
 x == NA", "outdated_suppression,any_is_na,equals_na"), @"
-        warning: equals_na
+        error: equals_na
          --> <test>:3:1
           |
         3 | x == NA
-          | ------- Comparing to NA with `==` is problematic.
+          | ^^^^^^^ Comparing to NA with `==` is problematic.
           |
           = help: Use `is.na()` instead.
         warning: outdated_suppression
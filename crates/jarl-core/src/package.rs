@@ -223,9 +223,8 @@ pub fn make_package_analysis(
     config: &Config,
     namespace_contents: &HashMap<PathBuf, String>,
 ) -> PackageAnalysis {
-    let rules = &config.rules_to_apply;
-    let check_duplicates = rules.contains(&Rule::DuplicatedFunctionDefinition);
-    let check_unused = rules.contains(&Rule::UnusedFunction);
+    let check_duplicates = config.applies_rule(&Rule::DuplicatedFunctionDefinition);
+    let check_unused = config.applies_rule(&Rule::UnusedFunction);
 
     if !check_duplicates && !check_unused {
         return PackageAnalysis::default();
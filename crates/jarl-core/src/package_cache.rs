@@ -6,14 +6,35 @@
 //!
 //! Caches are keyed by R project root so that files in an renv project get
 //! exports from that project's library, not the system library.
+//!
+//! When R is not available, the same metadata is read from the `NAMESPACE`
+//! and `DESCRIPTION` files of the installed packages. In renv projects, the
+//! versions recorded in `renv.lock` are used for packages that are not
+//! installed.
+//!
+//! The metadata is also stored in the user cache directory so that the next
+//! runs (or a restart of the language server) only call Rscript for packages
+//! that were installed or updated in the meantime.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::{Arc, RwLock};
 use std::time::SystemTime;
 
+use etcetera::BaseStrategy;
+use serde::{Deserialize, Serialize};
+
 use crate::checker::PackageOrigin;
+use crate::description::Description;
+use crate::hash::fnv1a_hash;
+use crate::library_paths::find_library_paths;
+use crate::namespace::parse_namespace_exports;
+use crate::renv::{LOCKFILE_NAME, read_lockfile_versions};
+
+/// Version of the format of the stored package metadata. Bump it when the
+/// format changes so that older files are ignored.
+const STORED_PACKAGES_VERSION: u32 = 1;
 
 /// Information about an installed R package.
 #[derive(Debug, Clone)]
@@ -40,6 +61,11 @@ pub struct PackageCache {
     /// The project root this cache was created for, used as the working
     /// directory when re-running Rscript for staleness refreshes.
     project_root: Option<PathBuf>,
+    /// File in which the metadata is stored between runs. `None` if it is not
+    /// persisted.
+    store_path: Option<PathBuf>,
+    /// Package versions recorded in the `renv.lock` of the project root.
+    locked_versions: HashMap<String, (u32, u32, u32)>,
 }
 
 impl PackageCache {
@@ -47,22 +73,77 @@ impl PackageCache {
     ///
     /// Spawns a single `Rscript` process with `project_root` as its working
     /// directory (so renv auto-activates via `.Rprofile`). Packages that are
-    /// not installed are silently skipped.
+    /// not installed are silently skipped. If Rscript can't be run, the
+    /// files of the installed packages are read instead.
+    ///
+    /// Packages whose metadata was stored by a previous run are reused if their
+    /// DESCRIPTION didn't change, so that Rscript only runs for the others.
     pub fn from_rscript(packages: &[&str], project_root: Option<&Path>) -> Option<Self> {
+        Self::from_rscript_with_store(packages, project_root, stored_packages_path(project_root))
+    }
+
+    fn from_rscript_with_store(
+        packages: &[&str],
+        project_root: Option<&Path>,
+        store_path: Option<PathBuf>,
+    ) -> Option<Self> {
         if packages.is_empty() {
             return None;
         }
 
-        let result = run_rscript_for_pkg_info(packages, project_root)?;
-        if result.cache.is_empty() {
+        let stored = store_path
+            .as_deref()
+            .map(StoredPackages::read)
+            .unwrap_or_default();
+
+        let libraries = find_library_paths(project_root);
+        let mut cache = HashMap::new();
+        let mut mtimes = HashMap::new();
+        let mut to_query = Vec::new();
+        for &pkg in packages {
+            match stored.get_fresh(pkg, &libraries) {
+                Some((info, mtime)) => {
+                    cache.insert(pkg.to_string(), Some(info));
+                    mtimes.insert(pkg.to_string(), Some(mtime));
+                }
+                None => to_query.push(pkg),
+            }
+        }
+
+        // Packages that are not installed are not stored, so they are looked
+        // up again in case they were installed since the last run.
+        if !to_query.is_empty()
+            && let Some(result) = query_pkg_info(&to_query, project_root)
+        {
+            cache.extend(result.cache);
+            mtimes.extend(result.mtimes);
+        }
+
+        let locked_versions: HashMap<String, (u32, u32, u32)> = project_root
+            .and_then(read_lockfile_versions)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(name, version)| Some((name, parse_package_version(&version)?)))
+            .collect();
+
+        // Even if no package is installed, the versions in `renv.lock` are
+        // enough for rules that depend on package versions.
+        if cache.is_empty() && locked_versions.is_empty() {
             return None;
         }
 
-        Some(Self {
-            cache: RwLock::new(result.cache),
-            mtimes: RwLock::new(result.mtimes),
+        let package_cache = Self {
+            cache: RwLock::new(cache),
+            mtimes: RwLock::new(mtimes),
             project_root: project_root.map(Path::to_path_buf),
-        })
+            store_path,
+            locked_versions,
+        };
+        if !to_query.is_empty() {
+            package_cache.persist();
+        }
+
+        Some(package_cache)
     }
 
     /// Build an in-memory cache from a list of (package_name, exports) pairs.
@@ -82,6 +163,8 @@ impl PackageCache {
             cache: RwLock::new(cache),
             mtimes: RwLock::new(HashMap::new()),
             project_root: None,
+            store_path: None,
+            locked_versions: HashMap::new(),
         }
     }
 
@@ -91,6 +174,50 @@ impl PackageCache {
         cache.get(name).and_then(|v| v.clone())
     }
 
+    /// Get the version of a package. This is the installed version if the
+    /// package is installed, and otherwise the version recorded in `renv.lock`.
+    pub fn package_version(&self, name: &str) -> Option<(u32, u32, u32)> {
+        self.get(name)
+            .and_then(|info| info.version)
+            .or_else(|| self.locked_versions.get(name).copied())
+    }
+
+    /// Packages whose installed version differs from the one recorded in
+    /// `renv.lock`, with their installed and locked versions, sorted by name.
+    pub fn lockfile_mismatches(&self) -> Vec<(String, (u32, u32, u32), (u32, u32, u32))> {
+        let cache = self.cache.read().unwrap();
+        let mut mismatches: Vec<_> = cache
+            .iter()
+            .filter_map(|(name, info)| {
+                let installed = info.as_ref()?.version?;
+                let locked = *self.locked_versions.get(name)?;
+                (installed != locked).then(|| (name.clone(), installed, locked))
+            })
+            .collect();
+        mismatches.sort();
+        mismatches
+    }
+
+    /// Exports and versions of all packages, in a stable order. They are part
+    /// of the cache key of lint results since package-specific rules depend
+    /// on them.
+    pub(crate) fn fingerprint(&self) -> String {
+        let cache = self.cache.read().unwrap();
+        let packages: BTreeMap<&String, Option<(BTreeSet<&String>, Option<(u32, u32, u32)>)>> =
+            cache
+                .iter()
+                .map(|(name, info)| {
+                    let info = info
+                        .as_ref()
+                        .map(|info| (info.exports.iter().collect(), info.version));
+                    (name, info)
+                })
+                .collect();
+        let locked_versions: BTreeMap<&String, &(u32, u32, u32)> =
+            self.locked_versions.iter().collect();
+        format!("{packages:?}\n{locked_versions:?}")
+    }
+
     /// Check if any packages were loaded into the cache.
     pub fn is_available(&self) -> bool {
         let cache = self.cache.read().unwrap();
@@ -123,7 +250,7 @@ impl PackageCache {
 
         // Re-fetch stale packages via Rscript
         let stale_refs: Vec<&str> = stale.iter().map(|s| s.as_str()).collect();
-        if let Some(result) = run_rscript_for_pkg_info(&stale_refs, self.project_root.as_deref()) {
+        if let Some(result) = query_pkg_info(&stale_refs, self.project_root.as_deref()) {
             let mut cache = self.cache.write().unwrap();
             let mut mtimes = self.mtimes.write().unwrap();
             for pkg in &stale {
@@ -148,9 +275,40 @@ impl PackageCache {
             }
         }
 
+        self.persist();
         stale
     }
 
+    /// Store the metadata of the cached packages so that the next runs don't
+    /// need to query them again. Packages stored by other runs are kept.
+    fn persist(&self) {
+        let Some(store_path) = &self.store_path else {
+            return;
+        };
+
+        let mut stored = StoredPackages::read(store_path);
+        let cache = self.cache.read().unwrap();
+        let mtimes = self.mtimes.read().unwrap();
+        for (name, info) in cache.iter() {
+            let entry = match (info, mtimes.get(name)) {
+                (Some(info), Some(Some(mtime))) => StoredPackage::new(info, *mtime),
+                _ => None,
+            };
+            if let Some(entry) = entry {
+                stored.packages.insert(name.clone(), entry);
+            } else {
+                stored.packages.remove(name);
+            }
+        }
+
+        if let Err(err) = stored.write(store_path) {
+            tracing::debug!(
+                "Failed to store package metadata in {}: {err}",
+                store_path.display()
+            );
+        }
+    }
+
     /// Get the mtime of a package's DESCRIPTION file at its recorded install path.
     fn description_mtime(&self, name: &str) -> Option<SystemTime> {
         let cache = self.cache.read().unwrap();
@@ -161,6 +319,130 @@ impl PackageCache {
     }
 }
 
+/// Package metadata stored on disk between runs, for a single R environment.
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredPackages {
+    version: u32,
+    packages: BTreeMap<String, StoredPackage>,
+}
+
+impl Default for StoredPackages {
+    fn default() -> Self {
+        Self {
+            version: STORED_PACKAGES_VERSION,
+            packages: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredPackage {
+    exports: BTreeSet<String>,
+    version: Option<(u32, u32, u32)>,
+    install_path: PathBuf,
+    /// Modification time of the DESCRIPTION file when the package was
+    /// queried. The stored metadata is only reused if it didn't change.
+    description_mtime: SystemTime,
+}
+
+impl StoredPackage {
+    fn new(info: &PackageInfo, description_mtime: SystemTime) -> Option<Self> {
+        Some(Self {
+            exports: info.exports.iter().cloned().collect(),
+            version: info.version,
+            install_path: info.install_path.clone()?,
+            description_mtime,
+        })
+    }
+}
+
+impl StoredPackages {
+    /// Read the stored metadata. A missing, invalid, or outdated file is
+    /// treated as empty.
+    fn read(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str::<Self>(&contents).ok())
+            .filter(|stored| stored.version == STORED_PACKAGES_VERSION)
+            .unwrap_or_default()
+    }
+
+    fn write(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_string(self)?;
+
+        // Write to a temporary file first since several processes may write
+        // the same file at once.
+        let tmp = path.with_extension(format!("{}.tmp", std::process::id()));
+        std::fs::write(&tmp, contents)?;
+        std::fs::rename(&tmp, path)
+    }
+
+    /// Get the stored metadata of `name` if the package didn't change since
+    /// it was stored.
+    ///
+    /// `libraries` are the library paths in the order R searches them. The
+    /// stored metadata is not used if one of the libraries before the one
+    /// where the package was found now contains this package too, e.g. after
+    /// a newer version was installed in the user library.
+    fn get_fresh(&self, name: &str, libraries: &[PathBuf]) -> Option<(PackageInfo, SystemTime)> {
+        let stored = self.packages.get(name)?;
+        if let Some(position) = libraries
+            .iter()
+            .position(|library| *library == stored.install_path)
+            && libraries[..position]
+                .iter()
+                .any(|library| library.join(name).join("DESCRIPTION").is_file())
+        {
+            return None;
+        }
+        let current = stored
+            .install_path
+            .join(name)
+            .join("DESCRIPTION")
+            .metadata()
+            .ok()?
+            .modified()
+            .ok()?;
+        if current != stored.description_mtime {
+            return None;
+        }
+
+        let info = PackageInfo {
+            exports: stored.exports.iter().cloned().collect(),
+            version: stored.version,
+            install_path: Some(stored.install_path.clone()),
+        };
+        Some((info, current))
+    }
+}
+
+/// Path of the file storing the package metadata of an R environment.
+///
+/// The environment is identified by its project root and by the environment
+/// variables that change the library paths.
+fn stored_packages_path(project_root: Option<&Path>) -> Option<PathBuf> {
+    let strategy = etcetera::base_strategy::choose_base_strategy().ok()?;
+
+    let root = project_root
+        .map(|root| root.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let r_libs = std::env::var("R_LIBS").unwrap_or_default();
+    let r_libs_user = std::env::var("R_LIBS_USER").unwrap_or_default();
+    let r_libs_site = std::env::var("R_LIBS_SITE").unwrap_or_default();
+    let key = fnv1a_hash(&[&root, &r_libs, &r_libs_user, &r_libs_site]);
+
+    Some(
+        strategy
+            .cache_dir()
+            .join("jarl")
+            .join("packages")
+            .join(format!("{key:016x}.json")),
+    )
+}
+
 /// Map of R project roots to their per-environment package caches.
 ///
 /// Different projects may have different R library paths (e.g. renv vs system).
@@ -224,6 +506,11 @@ impl PackageCacheMap {
 
         // Slow path: create a new cache
         let cache = PackageCache::from_rscript(packages, root.as_deref())?;
+        for (name, installed, locked) in cache.lockfile_mismatches() {
+            tracing::warn!(
+                "Installed version of {name} ({installed:?}) doesn't match renv.lock ({locked:?})"
+            );
+        }
         let cache = Arc::new(cache);
 
         let mut caches = self.caches.write().unwrap();
@@ -257,7 +544,7 @@ pub fn find_r_project_root(file_path: &Path) -> Option<PathBuf> {
 
     let mut dir = start;
     loop {
-        if dir.join("renv.lock").exists() {
+        if dir.join(LOCKFILE_NAME).exists() {
             return Some(dir.to_path_buf());
         }
         match dir.parent() {
@@ -360,7 +647,7 @@ impl<'a> FilePackageContext<'a> {
 
     /// Look up version info for a specific package.
     pub fn package_version(&self, pkg_name: &str) -> Option<(u32, u32, u32)> {
-        self.cache.get(pkg_name).and_then(|info| info.version)
+        self.cache.package_version(pkg_name)
     }
 }
 
@@ -373,6 +660,77 @@ struct PackageBatchResult {
     mtimes: HashMap<String, Option<SystemTime>>,
 }
 
+/// Get the exports, version, and install path of each requested package.
+///
+/// This runs Rscript if possible, and otherwise reads the files of the
+/// installed packages so that package-specific rules also work when R is not
+/// available.
+fn query_pkg_info(packages: &[&str], project_root: Option<&Path>) -> Option<PackageBatchResult> {
+    run_rscript_for_pkg_info(packages, project_root)
+        .or_else(|| read_pkg_info_from_libraries(packages, project_root))
+}
+
+/// Read the exports, version, and install path of each requested package from
+/// the `NAMESPACE` and `DESCRIPTION` files of installed packages, without R.
+///
+/// Libraries are searched in the same order as R would. `exportPattern()`
+/// directives can't be expanded since the list of objects of an installed
+/// package is stored in R's binary format, so only explicit exports are found
+/// for packages that use them and these packages are not stored between runs.
+///
+/// Returns `None` if no R library was found.
+fn read_pkg_info_from_libraries(
+    packages: &[&str],
+    project_root: Option<&Path>,
+) -> Option<PackageBatchResult> {
+    let libraries = find_library_paths(project_root);
+    if libraries.is_empty() {
+        return None;
+    }
+
+    let mut cache_map: HashMap<String, Option<PackageInfo>> = HashMap::new();
+    let mut mtime_map: HashMap<String, Option<SystemTime>> = HashMap::new();
+
+    for &name in packages {
+        let Some(library) = libraries
+            .iter()
+            .find(|library| library.join(name).join("DESCRIPTION").is_file())
+        else {
+            continue;
+        };
+
+        let pkg_dir = library.join(name);
+        let desc_path = pkg_dir.join("DESCRIPTION");
+        let Ok(description) = std::fs::read_to_string(&desc_path) else {
+            continue;
+        };
+        let namespace = std::fs::read_to_string(pkg_dir.join("NAMESPACE")).unwrap_or_default();
+
+        let version = Description::get_version(&description)
+            .and_then(|version| parse_package_version(&version));
+        let exports = parse_namespace_exports(&namespace, &[]);
+
+        // Exports are incomplete if the package uses `exportPattern()`. No
+        // mtime is recorded so that the package isn't stored on disk and is
+        // queried again, with R if it became available.
+        let desc_mtime = if namespace.contains("exportPattern(") {
+            None
+        } else {
+            desc_path.metadata().ok().and_then(|m| m.modified().ok())
+        };
+        mtime_map.insert(name.to_string(), desc_mtime);
+
+        let info = PackageInfo {
+            exports,
+            version,
+            install_path: Some(library.clone()),
+        };
+        cache_map.insert(name.to_string(), Some(info));
+    }
+
+    Some(PackageBatchResult { cache: cache_map, mtimes: mtime_map })
+}
+
 /// Run a single Rscript process that returns exports, version, and install
 /// path for each requested package.
 ///
@@ -460,9 +818,10 @@ fn run_rscript_for_pkg_info(
     Some(PackageBatchResult { cache: cache_map, mtimes: mtime_map })
 }
 
-/// Parse a version string like "1.2.3" into a tuple.
+/// Parse a version string like "1.2.3" into a tuple. Versions like "1.0-2"
+/// are read as "1.0.2", as `packageVersion()` formats them.
 fn parse_package_version(version: &str) -> Option<(u32, u32, u32)> {
-    let parts: Vec<&str> = version.split('.').collect();
+    let parts: Vec<&str> = version.split(['.', '-']).collect();
     if parts.len() < 2 {
         return None;
     }
@@ -518,6 +877,7 @@ mod tests {
         assert_eq!(parse_package_version("1.2.3"), Some((1, 2, 3)));
         assert_eq!(parse_package_version("1.2"), Some((1, 2, 0)));
         assert_eq!(parse_package_version("0.10.1"), Some((0, 10, 1)));
+        assert_eq!(parse_package_version("7.3-60"), Some((7, 3, 60)));
         assert_eq!(parse_package_version("1"), None);
     }
 
@@ -618,6 +978,235 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_read_pkg_info_from_libraries() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let library = dir
+            .path()
+            .join("renv")
+            .join("library")
+            .join("R-4.5")
+            .join("x86_64-pc-linux-gnu");
+        let pkg_dir = library.join("dplyr");
+        std::fs::create_dir_all(&pkg_dir).unwrap();
+        std::fs::write(
+            pkg_dir.join("DESCRIPTION"),
+            "Package: dplyr\nVersion: 1.1-4\n",
+        )
+        .unwrap();
+        std::fs::write(
+            pkg_dir.join("NAMESPACE"),
+            "S3method(filter,data.frame)\nexport(filter)\nexport(mutate)\n",
+        )
+        .unwrap();
+
+        let pattern_dir = library.join("patternpkg");
+        std::fs::create_dir_all(&pattern_dir).unwrap();
+        std::fs::write(
+            pattern_dir.join("DESCRIPTION"),
+            "Package: patternpkg\nVersion: 0.4-11\n",
+        )
+        .unwrap();
+        std::fs::write(
+            pattern_dir.join("NAMESPACE"),
+            "exportPattern(\"^[^\\\\.]\")\nexport(foo)\n",
+        )
+        .unwrap();
+
+        let result = read_pkg_info_from_libraries(
+            &["dplyr", "patternpkg", "notinstalled"],
+            Some(dir.path()),
+        )
+        .unwrap();
+
+        let info = result.cache.get("dplyr").unwrap().as_ref().unwrap();
+        assert!(info.exports.contains("filter"));
+        assert!(info.exports.contains("mutate"));
+        assert_eq!(info.version, Some((1, 1, 4)));
+        assert_eq!(info.install_path, Some(library));
+        assert!(result.mtimes.get("dplyr").unwrap().is_some());
+
+        // Packages with `exportPattern()` have incomplete exports, so they
+        // have no mtime and are not stored.
+        let info = result.cache.get("patternpkg").unwrap().as_ref().unwrap();
+        assert!(info.exports.contains("foo"));
+        assert_eq!(info.version, Some((0, 4, 11)));
+        assert_eq!(result.mtimes.get("patternpkg"), Some(&None));
+
+        assert!(!result.cache.contains_key("notinstalled"));
+    }
+
+    #[test]
+    fn test_versions_from_renv_lock() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let library = dir.path().join("library");
+        let store_path = dir.path().join("packages.json");
+        std::fs::write(
+            dir.path().join("renv.lock"),
+            r#"{"Packages": {
+                "fakedplyr": {"Package": "fakedplyr", "Version": "1.2.0"},
+                "faketidyr": {"Package": "faketidyr", "Version": "1.3.1"},
+                "fakemass": {"Package": "fakemass", "Version": "7.3-60"}
+            }}"#,
+        )
+        .unwrap();
+
+        // Store the metadata of the installed package so that the test
+        // doesn't depend on whether R is available.
+        let mtime = install_fake_package(&library, "fakedplyr");
+        let info = PackageInfo {
+            exports: ["filter".to_string()].into(),
+            version: Some((1, 2, 3)),
+            install_path: Some(library),
+        };
+        let mut stored = StoredPackages::default();
+        stored.packages.insert(
+            "fakedplyr".to_string(),
+            StoredPackage::new(&info, mtime).unwrap(),
+        );
+        stored.write(&store_path).unwrap();
+
+        let cache = PackageCache::from_rscript_with_store(
+            &["fakedplyr", "faketidyr", "fakemass"],
+            Some(dir.path()),
+            Some(store_path),
+        )
+        .unwrap();
+
+        // The installed version is used if there is one, otherwise the version
+        // in renv.lock.
+        assert_eq!(cache.package_version("fakedplyr"), Some((1, 2, 3)));
+        assert_eq!(cache.package_version("faketidyr"), Some((1, 3, 1)));
+        assert_eq!(cache.package_version("fakemass"), Some((7, 3, 60)));
+        assert_eq!(cache.package_version("fakeggplot2"), None);
+
+        assert_eq!(
+            cache.lockfile_mismatches(),
+            vec![("fakedplyr".to_string(), (1, 2, 3), (1, 2, 0))]
+        );
+    }
+
+    // ── Stored package metadata tests ─────────────────────────────────
+
+    fn install_fake_package(library: &Path, name: &str) -> SystemTime {
+        let pkg_dir = library.join(name);
+        std::fs::create_dir_all(&pkg_dir).unwrap();
+        let desc = pkg_dir.join("DESCRIPTION");
+        std::fs::write(&desc, format!("Package: {name}\nVersion: 1.2.3\n")).unwrap();
+        desc.metadata().unwrap().modified().unwrap()
+    }
+
+    #[test]
+    fn test_stored_packages_are_reused() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let library = dir.path().join("library");
+        let store_path = dir.path().join("cache").join("packages.json");
+        let mtime = install_fake_package(&library, "dplyr");
+
+        let info = PackageInfo {
+            exports: ["filter".to_string(), "mutate".to_string()].into(),
+            version: Some((1, 2, 3)),
+            install_path: Some(library.clone()),
+        };
+        let mut stored = StoredPackages::default();
+        stored.packages.insert(
+            "dplyr".to_string(),
+            StoredPackage::new(&info, mtime).unwrap(),
+        );
+        stored.write(&store_path).unwrap();
+
+        // All packages are fresh, so Rscript is not called.
+        let cache =
+            PackageCache::from_rscript_with_store(&["dplyr"], None, Some(store_path)).unwrap();
+        let info = cache.get("dplyr").unwrap();
+        assert!(info.exports.contains("filter"));
+        assert!(info.exports.contains("mutate"));
+        assert_eq!(info.version, Some((1, 2, 3)));
+        assert_eq!(info.install_path, Some(library));
+    }
+
+    #[test]
+    fn test_stored_packages_stale_description() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let library = dir.path().join("library");
+        let store_path = dir.path().join("packages.json");
+        let mtime = install_fake_package(&library, "dplyr");
+
+        let info = PackageInfo {
+            exports: ["filter".to_string()].into(),
+            version: Some((1, 2, 3)),
+            install_path: Some(library.clone()),
+        };
+        let mut stored = StoredPackages::default();
+        stored.packages.insert(
+            "dplyr".to_string(),
+            StoredPackage::new(&info, mtime).unwrap(),
+        );
+        stored.write(&store_path).unwrap();
+
+        let stored = StoredPackages::read(&store_path);
+        assert!(stored.get_fresh("dplyr", &[]).is_some());
+        assert!(stored.get_fresh("tidyr", &[]).is_none());
+
+        // Reinstalling the package changes the mtime of its DESCRIPTION.
+        std::fs::File::options()
+            .write(true)
+            .open(library.join("dplyr").join("DESCRIPTION"))
+            .unwrap()
+            .set_modified(mtime + std::time::Duration::from_secs(10))
+            .unwrap();
+        assert!(stored.get_fresh("dplyr", &[]).is_none());
+    }
+
+    #[test]
+    fn test_stored_packages_shadowed_by_earlier_library() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let user_library = dir.path().join("user");
+        let site_library = dir.path().join("site");
+        let store_path = dir.path().join("packages.json");
+        let mtime = install_fake_package(&site_library, "dplyr");
+
+        let info = PackageInfo {
+            exports: ["filter".to_string()].into(),
+            version: Some((1, 2, 3)),
+            install_path: Some(site_library.clone()),
+        };
+        let mut stored = StoredPackages::default();
+        stored.packages.insert(
+            "dplyr".to_string(),
+            StoredPackage::new(&info, mtime).unwrap(),
+        );
+        stored.write(&store_path).unwrap();
+
+        let libraries = vec![user_library.clone(), site_library.clone()];
+        let stored = StoredPackages::read(&store_path);
+        assert!(stored.get_fresh("dplyr", &libraries).is_some());
+
+        // A newer version installed in a library that comes first in the
+        // search order masks the stored one.
+        install_fake_package(&user_library, "dplyr");
+        assert!(stored.get_fresh("dplyr", &libraries).is_none());
+
+        // Libraries after the one of the stored package don't matter.
+        let libraries = vec![site_library, user_library];
+        assert!(stored.get_fresh("dplyr", &libraries).is_some());
+    }
+
+    #[test]
+    fn test_stored_packages_invalid_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let store_path = dir.path().join("packages.json");
+
+        std::fs::write(&store_path, "not json").unwrap();
+        assert!(StoredPackages::read(&store_path).packages.is_empty());
+
+        std::fs::write(&store_path, r#"{"version": 0, "packages": {}}"#).unwrap();
+        assert_eq!(
+            StoredPackages::read(&store_path).version,
+            STORED_PACKAGES_VERSION
+        );
+    }
+
     // ── find_r_project_root tests ──────────────────────────────────────
 
     #[test]
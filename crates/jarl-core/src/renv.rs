@@ -0,0 +1,93 @@
+//! Reading of renv lockfiles.
+//!
+//! `renv.lock` records the version of each package used in a project. It is
+//! used to know the versions of packages when the library of the project was
+//! not restored, e.g. in CI jobs that only run Jarl.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Deserialize;
+
+pub const LOCKFILE_NAME: &str = "renv.lock";
+
+#[derive(Deserialize)]
+struct Lockfile {
+    #[serde(rename = "Packages", default)]
+    packages: HashMap<String, LockedPackage>,
+}
+
+#[derive(Deserialize)]
+struct LockedPackage {
+    #[serde(rename = "Version")]
+    version: Option<String>,
+}
+
+/// Read the version of each package recorded in the `renv.lock` of
+/// `project_root`, e.g. `"dplyr" => "1.1.4"`.
+///
+/// Returns `None` if there is no lockfile or if it is invalid.
+pub fn read_lockfile_versions(project_root: &Path) -> Option<HashMap<String, String>> {
+    let contents = std::fs::read_to_string(project_root.join(LOCKFILE_NAME)).ok()?;
+    let lockfile: Lockfile = serde_json::from_str(&contents).ok()?;
+
+    Some(
+        lockfile
+            .packages
+            .into_iter()
+            .filter_map(|(name, package)| Some((name, package.version?)))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_lockfile_versions() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join(LOCKFILE_NAME),
+            r#"{
+  "R": {
+    "Version": "4.5.1",
+    "Repositories": []
+  },
+  "Packages": {
+    "dplyr": {
+      "Package": "dplyr",
+      "Version": "1.1.4",
+      "Source": "Repository"
+    },
+    "rlang": {
+      "Package": "rlang",
+      "Version": "1.1.6",
+      "Source": "Repository"
+    },
+    "broken": {
+      "Package": "broken"
+    }
+  }
+}"#,
+        )
+        .unwrap();
+
+        let versions = read_lockfile_versions(dir.path()).unwrap();
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions["dplyr"], "1.1.4");
+        assert_eq!(versions["rlang"], "1.1.6");
+    }
+
+    #[test]
+    fn test_read_lockfile_versions_missing_or_invalid() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert!(read_lockfile_versions(dir.path()).is_none());
+
+        std::fs::write(dir.path().join(LOCKFILE_NAME), "{}").unwrap();
+        assert!(read_lockfile_versions(dir.path()).unwrap().is_empty());
+
+        std::fs::write(dir.path().join(LOCKFILE_NAME), "not json").unwrap();
+        assert!(read_lockfile_versions(dir.path()).is_none());
+    }
+}
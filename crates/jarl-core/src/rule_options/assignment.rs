@@ -12,6 +12,14 @@ pub struct AssignmentOptions {
     pub operator: Option<String>,
 }
 
+impl AssignmentOptions {
+    /// Merge these options with those of the file they extend, keeping the
+    /// values of `self` for keys present in both.
+    pub(crate) fn combine(self, base: Self) -> Self {
+        Self { operator: self.operator.or(base.operator) }
+    }
+}
+
 /// Accepts either the legacy top-level string (`assignment = "<-"`) or the new
 /// table form (`[lint.assignment]` with an `operator` field).
 ///
@@ -27,6 +35,17 @@ pub enum AssignmentConfig {
     Options(AssignmentOptions),
 }
 
+impl AssignmentConfig {
+    /// Merge with the option of the file that is extended. The legacy form
+    /// can't be merged, so it replaces or is replaced by the table form.
+    pub(crate) fn combine(self, base: Self) -> Self {
+        match (self, base) {
+            (Self::Options(options), Self::Options(base)) => Self::Options(options.combine(base)),
+            (config, _) => config,
+        }
+    }
+}
+
 impl<'de> serde::Deserialize<'de> for AssignmentConfig {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -86,4 +105,18 @@ impl ResolvedAssignmentOptions {
 
         Ok(Self { operator })
     }
+
+    /// The operator as written in `jarl.toml`.
+    pub fn operator_as_str(&self) -> &'static str {
+        match self.operator {
+            RSyntaxKind::EQUAL => "=",
+            _ => "<-",
+        }
+    }
+}
+
+impl serde::Serialize for ResolvedAssignmentOptions {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.operator_as_str())
+    }
 }
@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use super::combine_with_extend;
 use super::resolve_with_extend;
 
 /// Default functions that are allowed to have duplicated arguments.
@@ -19,10 +20,23 @@ pub struct DuplicatedArgumentsOptions {
     pub extend_skipped_functions: Option<Vec<String>>,
 }
 
+impl DuplicatedArgumentsOptions {
+    /// Merge these options with those of the file they extend, keeping the
+    /// values of `self` for keys present in both.
+    pub(crate) fn combine(self, base: Self) -> Self {
+        let (skipped_functions, extend_skipped_functions) = combine_with_extend(
+            (self.skipped_functions, self.extend_skipped_functions),
+            (base.skipped_functions, base.extend_skipped_functions),
+        );
+        Self { skipped_functions, extend_skipped_functions }
+    }
+}
+
 /// Resolved options for the `duplicated_arguments` rule, ready for use during
 /// linting.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize)]
 pub struct ResolvedDuplicatedArgumentsOptions {
+    #[serde(serialize_with = "crate::cache::sorted_set")]
     pub skipped_functions: HashSet<String>,
 }
 
@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use super::combine_with_extend;
 use super::resolve_with_extend;
 
 /// Default functions where implicit assignments are allowed.
@@ -27,10 +28,23 @@ pub struct ImplicitAssignmentOptions {
     pub extend_skipped_functions: Option<Vec<String>>,
 }
 
+impl ImplicitAssignmentOptions {
+    /// Merge these options with those of the file they extend, keeping the
+    /// values of `self` for keys present in both.
+    pub(crate) fn combine(self, base: Self) -> Self {
+        let (skipped_functions, extend_skipped_functions) = combine_with_extend(
+            (self.skipped_functions, self.extend_skipped_functions),
+            (base.skipped_functions, base.extend_skipped_functions),
+        );
+        Self { skipped_functions, extend_skipped_functions }
+    }
+}
+
 /// Resolved options for the `implicit_assignment` rule, ready for use during
 /// linting.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize)]
 pub struct ResolvedImplicitAssignmentOptions {
+    #[serde(serialize_with = "crate::cache::sorted_set")]
     pub skipped_functions: HashSet<String>,
 }
 
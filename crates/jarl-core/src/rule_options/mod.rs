@@ -62,6 +62,33 @@ pub fn resolve_with_extend(
     }
 }
 
+/// Merge a pair of `field` / `extend-field` options with those of the file they
+/// extend, see [resolve_with_extend()].
+///
+/// - If `field` is set, it replaces the list of `base` and its additions.
+/// - Otherwise, `extend-field` is added to the `field` of `base` if it is set,
+///   or to its `extend-field`.
+pub(crate) fn combine_with_extend(
+    (list, extend): (Option<Vec<String>>, Option<Vec<String>>),
+    (base_list, base_extend): (Option<Vec<String>>, Option<Vec<String>>),
+) -> (Option<Vec<String>>, Option<Vec<String>>) {
+    if list.is_some() {
+        return (list, extend);
+    }
+
+    match (base_list, base_extend, extend) {
+        (Some(mut base_list), None, Some(extend)) => {
+            base_list.extend(extend);
+            (Some(base_list), None)
+        }
+        (base_list, Some(mut base_extend), Some(extend)) => {
+            base_extend.extend(extend);
+            (base_list, Some(base_extend))
+        }
+        (base_list, base_extend, extend) => (base_list, extend.or(base_extend)),
+    }
+}
+
 /// Resolved per-rule options, ready for use during linting.
 ///
 /// To add options for a new rule:
@@ -69,7 +96,7 @@ pub fn resolve_with_extend(
 /// 2. Add a field to `ResolvedRuleOptions` and a resolve line in `resolve()`.
 /// 3. Add the TOML field to `LinterTomlOptions` in `toml.rs` and pass it to
 ///    `resolve()` in `into_settings()`.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize)]
 pub struct ResolvedRuleOptions {
     pub assignment: ResolvedAssignmentOptions,
     pub duplicated_arguments: ResolvedDuplicatedArgumentsOptions,
@@ -116,3 +143,81 @@ impl Default for ResolvedRuleOptions {
             .expect("default rule options should always resolve")
     }
 }
+
+/// Per-rule options set in an entry of `[[lint.overrides]]`.
+///
+/// Rules whose options are not set in the override keep the options resolved
+/// from `[lint]`. Options of `unused_function` can't be overridden since this
+/// rule analyzes all files of a package at once.
+#[derive(Clone, Debug, Default)]
+pub struct RuleOptionsOverride {
+    pub assignment: Option<ResolvedAssignmentOptions>,
+    pub duplicated_arguments: Option<ResolvedDuplicatedArgumentsOptions>,
+    pub implicit_assignment: Option<ResolvedImplicitAssignmentOptions>,
+    pub pipe_consistency: Option<ResolvedPipeConsistencyOptions>,
+    pub quotes: Option<ResolvedQuotesOptions>,
+    pub undesirable_function: Option<ResolvedUndesirableFunctionOptions>,
+    pub unreachable_code: Option<ResolvedUnreachableCodeOptions>,
+}
+
+impl RuleOptionsOverride {
+    #[allow(clippy::too_many_arguments)]
+    pub fn resolve(
+        assignment: Option<&AssignmentOptions>,
+        duplicated_arguments: Option<&DuplicatedArgumentsOptions>,
+        implicit_assignment: Option<&ImplicitAssignmentOptions>,
+        pipe_consistency: Option<&PipeConsistencyOptions>,
+        quotes: Option<&QuotesOptions>,
+        undesirable_function: Option<&UndesirableFunctionOptions>,
+        unreachable_code: Option<&UnreachableCodeOptions>,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            assignment: assignment
+                .map(|o| ResolvedAssignmentOptions::resolve(Some(o)))
+                .transpose()?,
+            duplicated_arguments: duplicated_arguments
+                .map(|o| ResolvedDuplicatedArgumentsOptions::resolve(Some(o)))
+                .transpose()?,
+            implicit_assignment: implicit_assignment
+                .map(|o| ResolvedImplicitAssignmentOptions::resolve(Some(o)))
+                .transpose()?,
+            pipe_consistency: pipe_consistency
+                .map(|o| ResolvedPipeConsistencyOptions::resolve(Some(o)))
+                .transpose()?,
+            quotes: quotes
+                .map(|o| ResolvedQuotesOptions::resolve(Some(o)))
+                .transpose()?,
+            undesirable_function: undesirable_function
+                .map(|o| ResolvedUndesirableFunctionOptions::resolve(Some(o)))
+                .transpose()?,
+            unreachable_code: unreachable_code
+                .map(|o| ResolvedUnreachableCodeOptions::resolve(Some(o)))
+                .transpose()?,
+        })
+    }
+
+    /// Replace the options of `options` by those set in this override.
+    pub fn apply(&self, options: &mut ResolvedRuleOptions) {
+        if let Some(assignment) = &self.assignment {
+            options.assignment = assignment.clone();
+        }
+        if let Some(duplicated_arguments) = &self.duplicated_arguments {
+            options.duplicated_arguments = duplicated_arguments.clone();
+        }
+        if let Some(implicit_assignment) = &self.implicit_assignment {
+            options.implicit_assignment = implicit_assignment.clone();
+        }
+        if let Some(pipe_consistency) = &self.pipe_consistency {
+            options.pipe_consistency = pipe_consistency.clone();
+        }
+        if let Some(quotes) = &self.quotes {
+            options.quotes = quotes.clone();
+        }
+        if let Some(undesirable_function) = &self.undesirable_function {
+            options.undesirable_function = undesirable_function.clone();
+        }
+        if let Some(unreachable_code) = &self.unreachable_code {
+            options.unreachable_code = unreachable_code.clone();
+        }
+    }
+}
@@ -1,6 +1,6 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
 pub enum PreferredPipe {
     Base,
     Magrittr,
@@ -17,8 +17,16 @@ pub struct PipeConsistencyOptions {
     pub pipe: Option<String>,
 }
 
+impl PipeConsistencyOptions {
+    /// Merge these options with those of the file they extend, keeping the
+    /// values of `self` for keys present in both.
+    pub(crate) fn combine(self, base: Self) -> Self {
+        Self { pipe: self.pipe.or(base.pipe) }
+    }
+}
+
 /// Resolved options for the `pipe_consistency` rule.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ResolvedPipeConsistencyOptions {
     pub pipe: PreferredPipe,
 }
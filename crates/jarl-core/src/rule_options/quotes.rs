@@ -1,6 +1,6 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
 pub enum PreferredQuote {
     Double,
     Single,
@@ -26,8 +26,16 @@ pub struct QuotesOptions {
     pub quote: Option<String>,
 }
 
+impl QuotesOptions {
+    /// Merge these options with those of the file they extend, keeping the
+    /// values of `self` for keys present in both.
+    pub(crate) fn combine(self, base: Self) -> Self {
+        Self { quote: self.quote.or(base.quote) }
+    }
+}
+
 /// Resolved options for the `quotes` rule, ready for use during linting.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ResolvedQuotesOptions {
     pub preferred_delimiter: PreferredQuote,
 }
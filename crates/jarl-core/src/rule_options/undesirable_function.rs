@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use super::combine_with_extend;
 use super::resolve_with_extend;
 
 /// Default functions that are considered undesirable.
@@ -18,10 +19,23 @@ pub struct UndesirableFunctionOptions {
     pub extend_functions: Option<Vec<String>>,
 }
 
+impl UndesirableFunctionOptions {
+    /// Merge these options with those of the file they extend, keeping the
+    /// values of `self` for keys present in both.
+    pub(crate) fn combine(self, base: Self) -> Self {
+        let (functions, extend_functions) = combine_with_extend(
+            (self.functions, self.extend_functions),
+            (base.functions, base.extend_functions),
+        );
+        Self { functions, extend_functions }
+    }
+}
+
 /// Resolved options for the `undesirable_function` rule, ready for use during
 /// linting.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize)]
 pub struct ResolvedUndesirableFunctionOptions {
+    #[serde(serialize_with = "crate::cache::sorted_set")]
     pub functions: HashSet<String>,
 }
 
@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use super::combine_with_extend;
 use super::resolve_with_extend;
 
 /// Default functions that stop execution (never return).
@@ -20,10 +21,23 @@ pub struct UnreachableCodeOptions {
     pub extend_stopping_functions: Option<Vec<String>>,
 }
 
+impl UnreachableCodeOptions {
+    /// Merge these options with those of the file they extend, keeping the
+    /// values of `self` for keys present in both.
+    pub(crate) fn combine(self, base: Self) -> Self {
+        let (stopping_functions, extend_stopping_functions) = combine_with_extend(
+            (self.stopping_functions, self.extend_stopping_functions),
+            (base.stopping_functions, base.extend_stopping_functions),
+        );
+        Self { stopping_functions, extend_stopping_functions }
+    }
+}
+
 /// Resolved options for the `unreachable_code` rule, ready for use during
 /// linting.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize)]
 pub struct ResolvedUnreachableCodeOptions {
+    #[serde(serialize_with = "crate::cache::sorted_set")]
     pub stopping_functions: HashSet<String>,
 }
 
@@ -19,10 +19,22 @@ pub struct UnusedFunctionOptions {
     pub skipped_functions: Option<Vec<String>>,
 }
 
+impl UnusedFunctionOptions {
+    /// Merge these options with those of the file they extend, keeping the
+    /// values of `self` for keys present in both.
+    pub(crate) fn combine(self, base: Self) -> Self {
+        Self {
+            threshold_ignore: self.threshold_ignore.or(base.threshold_ignore),
+            skipped_functions: self.skipped_functions.or(base.skipped_functions),
+        }
+    }
+}
+
 /// Resolved options for the `unused_function` rule.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize)]
 pub struct ResolvedUnusedFunctionOptions {
     pub threshold_ignore: usize,
+    #[serde(serialize_with = "crate::cache::regex_patterns")]
     pub skipped_functions: Vec<Regex>,
 }
 
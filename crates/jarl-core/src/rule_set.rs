@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
 
@@ -97,6 +98,50 @@ pub enum FixStatus {
     Unsafe,
 }
 
+/// Severity of a rule violation.
+///
+/// Ordered from least to most severe so that `--fail-on` can compare levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    #[default]
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "info" => Ok(Self::Info),
+            "warning" => Ok(Self::Warning),
+            "error" => Ok(Self::Error),
+            _ => Err(format!(
+                "Unknown severity: \"{s}\". Expected \"error\", \"warning\", or \"info\"."
+            )),
+        }
+    }
+}
+
 /// Information about a deprecated rule.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DeprecationInfo {
@@ -113,6 +158,14 @@ macro_rules! declare_rules {
     (@deprecation) => {
         None
     };
+    // Internal helper: preview flag when present
+    (@preview $preview:literal) => {
+        $preview
+    };
+    // Internal helper: rules are stable unless stated otherwise
+    (@preview) => {
+        false
+    };
 
     (
         $(
@@ -122,12 +175,14 @@ macro_rules! declare_rules {
                 categories: [$($category:ident),+ $(,)?],
                 default: $default:ident,
                 fix: $fix:ident,
+                severity: $severity:ident,
                 min_r_version: $min_version:expr,
+                $(preview: $preview:literal,)?
             }
         ),* $(,)?
     ) => {
         /// Enum representing all available linting rules
-        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
         pub enum Rule {
             $($variant),*
         }
@@ -161,6 +216,13 @@ macro_rules! declare_rules {
                 }
             }
 
+            /// Get the rule's default severity
+            pub const fn default_severity(self) -> Severity {
+                match self {
+                    $(Self::$variant => Severity::$severity),*
+                }
+            }
+
             /// Get the minimum R version required for this rule
             pub const fn minimum_r_version(self) -> Option<(u32, u32, u32)> {
                 match self {
@@ -180,6 +242,14 @@ macro_rules! declare_rules {
                 self.deprecation().is_some()
             }
 
+            /// Check if this rule is in preview, meaning that it is only
+            /// enabled with `--preview` or `preview = true` in `jarl.toml`
+            pub const fn is_preview(self) -> bool {
+                match self {
+                    $(Self::$variant => declare_rules!(@preview $($preview)?)),*
+                }
+            }
+
             /// Check if the rule has a safe fix
             pub const fn has_safe_fix(self) -> bool {
                 matches!(self.fix_status(), FixStatus::Safe)
@@ -248,6 +318,7 @@ declare_rules! {
         categories: [Susp],
         default: Enabled,
         fix: Unsafe,
+        severity: Warning,
         min_r_version: None,
     },
     AnyDuplicated => {
@@ -255,6 +326,7 @@ declare_rules! {
         categories: [Perf],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     AnyIsNa => {
@@ -262,6 +334,7 @@ declare_rules! {
         categories: [Perf],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     Assignment => {
@@ -269,6 +342,7 @@ declare_rules! {
         categories: [Read],
         default: Disabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     #[deprecated(version = "0.5.0", replacement = "undesirable_function")]
@@ -277,6 +351,7 @@ declare_rules! {
         categories: [Corr],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     ClassEquals => {
@@ -284,6 +359,7 @@ declare_rules! {
         categories: [Susp],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     ComparisonNegation => {
@@ -291,6 +367,7 @@ declare_rules! {
         categories: [Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     Coalesce => {
@@ -298,6 +375,7 @@ declare_rules! {
         categories: [Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: Some((4, 4, 0)),
     },
     DownloadFile => {
@@ -305,6 +383,7 @@ declare_rules! {
         categories: [Susp],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     DuplicatedArguments => {
@@ -312,6 +391,7 @@ declare_rules! {
         categories: [Susp],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     DuplicatedFunctionDefinition => {
@@ -319,6 +399,7 @@ declare_rules! {
         categories: [Corr],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     EmptyAssignment => {
@@ -326,6 +407,7 @@ declare_rules! {
         categories: [Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     EmptyFile => {
@@ -333,6 +415,7 @@ declare_rules! {
         categories: [Susp],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     EqualsNa => {
@@ -340,6 +423,7 @@ declare_rules! {
         categories: [Corr],
         default: Enabled,
         fix: Safe,
+        severity: Error,
         min_r_version: None,
     },
     EqualsNaN => {
@@ -347,6 +431,7 @@ declare_rules! {
         categories: [Corr],
         default: Enabled,
         fix: Safe,
+        severity: Error,
         min_r_version: None,
     },
     EqualsNull => {
@@ -354,6 +439,7 @@ declare_rules! {
         categories: [Corr],
         default: Enabled,
         fix: Safe,
+        severity: Error,
         min_r_version: None,
     },
     FixedRegex => {
@@ -361,6 +447,7 @@ declare_rules! {
         categories: [Perf],
         default: Disabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     ForLoopDupIndex => {
@@ -368,6 +455,7 @@ declare_rules! {
         categories: [Corr, Susp],
         default: Enabled,
         fix: None,
+        severity: Error,
         min_r_version: None,
     },
     ForLoopIndex => {
@@ -375,6 +463,7 @@ declare_rules! {
         categories: [Read],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     Glue => {
@@ -382,6 +471,7 @@ declare_rules! {
         categories: [Corr, Susp],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     Grepv => {
@@ -389,6 +479,7 @@ declare_rules! {
         categories: [Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: Some((4, 5, 0)),
     },
     IfAlwaysTrue => {
@@ -396,6 +487,7 @@ declare_rules! {
         categories: [Read, Susp],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     ImplicitAssignment => {
@@ -403,6 +495,7 @@ declare_rules! {
         categories: [Read],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     InternalFunction => {
@@ -410,6 +503,7 @@ declare_rules! {
         categories: [Susp],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     IsNumeric => {
@@ -417,6 +511,7 @@ declare_rules! {
         categories: [Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     LengthLevels => {
@@ -424,6 +519,7 @@ declare_rules! {
         categories: [Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     LengthTest => {
@@ -431,6 +527,7 @@ declare_rules! {
         categories: [Corr],
         default: Enabled,
         fix: Safe,
+        severity: Error,
         min_r_version: None,
     },
     Lengths => {
@@ -438,6 +535,7 @@ declare_rules! {
         categories: [Perf, Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     List2df => {
@@ -445,6 +543,7 @@ declare_rules! {
         categories: [Perf, Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: Some((4, 0, 0)),
     },
     MatrixApply => {
@@ -452,6 +551,7 @@ declare_rules! {
         categories: [Perf],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     NotIn => {
@@ -459,6 +559,7 @@ declare_rules! {
         categories: [Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: Some((4, 6, 0)),
     },
     NumericLeadingZero => {
@@ -466,6 +567,7 @@ declare_rules! {
         categories: [Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     NzChar => {
@@ -473,6 +575,7 @@ declare_rules! {
         categories: [Perf],
         default: Disabled,
         fix: Unsafe,
+        severity: Warning,
         min_r_version: None,
     },
     PipeConsistency => {
@@ -480,6 +583,7 @@ declare_rules! {
         categories: [Read],
         default: Disabled,
         fix: Unsafe,
+        severity: Warning,
         min_r_version: Some((4, 2, 0)),
     },
     OuterNegation => {
@@ -487,6 +591,7 @@ declare_rules! {
         categories: [Perf, Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     Quotes => {
@@ -494,6 +599,7 @@ declare_rules! {
         categories: [Read],
         default: Disabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     RedundantEquals => {
@@ -501,6 +607,7 @@ declare_rules! {
         categories: [Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     RedundantIfelse => {
@@ -508,6 +615,7 @@ declare_rules! {
         categories: [Corr, Perf, Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     Repeat => {
@@ -515,6 +623,7 @@ declare_rules! {
         categories: [Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     SampleInt => {
@@ -522,6 +631,7 @@ declare_rules! {
         categories: [Read],
         default: Disabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     Seq => {
@@ -529,6 +639,7 @@ declare_rules! {
         categories: [Susp],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     Seq2 => {
@@ -536,6 +647,7 @@ declare_rules! {
         categories: [Susp],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     Sort => {
@@ -543,6 +655,7 @@ declare_rules! {
         categories: [Perf, Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     Sprintf => {
@@ -550,6 +663,7 @@ declare_rules! {
         categories: [Corr, Susp],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     StringBoundary => {
@@ -557,6 +671,7 @@ declare_rules! {
         categories: [Perf, Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     SystemFile => {
@@ -564,6 +679,7 @@ declare_rules! {
         categories: [Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     TrueFalseSymbol => {
@@ -571,6 +687,7 @@ declare_rules! {
         categories: [Read],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     UndesirableFunction => {
@@ -578,6 +695,7 @@ declare_rules! {
         categories: [Corr],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     UnnecessaryNesting => {
@@ -585,6 +703,7 @@ declare_rules! {
         categories: [Read],
         default: Disabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     UnreachableCode => {
@@ -592,6 +711,7 @@ declare_rules! {
         categories: [Read, Susp],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     UnusedFunction => {
@@ -599,6 +719,7 @@ declare_rules! {
         categories: [Corr],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     VectorLogic => {
@@ -606,6 +727,7 @@ declare_rules! {
         categories: [Perf],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     WhichGrepl => {
@@ -613,6 +735,7 @@ declare_rules! {
         categories: [Perf, Read],
         default: Enabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
 
@@ -624,6 +747,7 @@ declare_rules! {
         categories: [Comm],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     InvalidChunkSuppression => {
@@ -631,6 +755,7 @@ declare_rules! {
         categories: [Comm],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     MisplacedFileSuppression => {
@@ -638,6 +763,7 @@ declare_rules! {
         categories: [Comm],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     MisplacedSuppression => {
@@ -645,6 +771,7 @@ declare_rules! {
         categories: [Comm],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     MisnamedSuppression => {
@@ -652,6 +779,7 @@ declare_rules! {
         categories: [Comm],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     OutdatedSuppression => {
@@ -659,6 +787,7 @@ declare_rules! {
         categories: [Comm],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     UnexplainedSuppression => {
@@ -666,6 +795,7 @@ declare_rules! {
         categories: [Comm],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
     UnmatchedRangeSuppression => {
@@ -673,6 +803,7 @@ declare_rules! {
         categories: [Comm],
         default: Enabled,
         fix: None,
+        severity: Warning,
         min_r_version: None,
     },
 
@@ -684,6 +815,7 @@ declare_rules! {
         categories: [Dplyr],
         default: Disabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     DplyrGroupByUngroup => {
@@ -691,6 +823,7 @@ declare_rules! {
         categories: [Dplyr],
         default: Disabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
 
@@ -702,6 +835,7 @@ declare_rules! {
         categories: [Testthat],
         default: Disabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     TestthatExpectMatch => {
@@ -709,6 +843,7 @@ declare_rules! {
         categories: [Testthat],
         default: Disabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     TestthatExpectNamed => {
@@ -716,6 +851,7 @@ declare_rules! {
         categories: [Testthat],
         default: Disabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     TestthatExpectNoMatch => {
@@ -723,6 +859,7 @@ declare_rules! {
         categories: [Testthat],
         default: Disabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     TestthatExpectNot => {
@@ -730,6 +867,7 @@ declare_rules! {
         categories: [Testthat],
         default: Disabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     TestthatExpectNull => {
@@ -737,6 +875,7 @@ declare_rules! {
         categories: [Testthat],
         default: Disabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     TestthatExpectS3Class => {
@@ -744,6 +883,7 @@ declare_rules! {
         categories: [Testthat],
         default: Disabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     TestthatExpectTrueFalse => {
@@ -751,6 +891,7 @@ declare_rules! {
         categories: [Testthat],
         default: Disabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
     TestthatExpectType => {
@@ -758,13 +899,14 @@ declare_rules! {
         categories: [Testthat],
         default: Disabled,
         fix: Safe,
+        severity: Warning,
         min_r_version: None,
     },
 
 }
 
 /// A collection of rules
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct RuleSet {
     rules: Vec<Rule>,
 }
@@ -906,4 +1048,25 @@ impl Rule {
             .copied()
             .filter(|r| r.is_disabled_by_default())
     }
+
+    /// Get the rule's documentation ("What it does", "Why is this bad?",
+    /// "Example", etc.), extracted from the doc comments of its lint.
+    pub fn documentation(self) -> Option<&'static str> {
+        RULE_DOCS
+            .iter()
+            .find(|(name, _, _)| *name == self.name())
+            .map(|(_, _, doc)| *doc)
+    }
+
+    /// Get the version of Jarl in which the rule was added
+    pub fn version_added(self) -> Option<&'static str> {
+        RULE_DOCS
+            .iter()
+            .find(|(name, _, _)| *name == self.name())
+            .map(|(_, version, _)| *version)
+            .filter(|version| !version.is_empty())
+    }
 }
+
+// Generated by `build.rs` from the doc comments in `src/lints`.
+include!(concat!(env!("OUT_DIR"), "/rule_docs.rs"));
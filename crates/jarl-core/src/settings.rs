@@ -4,7 +4,12 @@
 //
 // MIT License - Posit PBC
 
-use crate::rule_options::ResolvedRuleOptions;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use ignore::overrides::{Override, OverrideBuilder};
+
+use crate::rule_options::{ResolvedRuleOptions, RuleOptionsOverride};
 
 /// Resolved configuration settings used within jarl
 #[derive(Clone, Debug, Default)]
@@ -22,12 +27,24 @@ pub struct LinterSettings {
     pub default_exclude: Option<bool>,
     pub check_roxygen: Option<bool>,
     pub fix_roxygen: Option<bool>,
+    pub lintr_compat_suppressions: Option<bool>,
+    pub preview: Option<bool>,
     pub fixable: Option<Vec<String>>,
     pub unfixable: Option<Vec<String>>,
+    /// Severity overrides from `[lint.severity]`, keyed by rule or category
+    /// name. Values are validated when building the config.
+    pub severity: Option<BTreeMap<String, String>>,
+    /// Path to the baseline file listing violations that are not reported,
+    /// resolved relative to the directory of `jarl.toml`.
+    pub baseline: Option<PathBuf>,
     /// Whether the deprecated `assignment = "<-"` top-level string form was
     /// used in `[lint]`. When `true`, a deprecation warning should be emitted.
     pub deprecated_assignment_syntax: bool,
     pub rule_options: ResolvedRuleOptions,
+    /// Rule selection and options that only apply to some files, from
+    /// `[lint.per-file-ignores]` followed by `[[lint.overrides]]`. They are
+    /// applied in this order, so later entries take precedence.
+    pub overrides: Vec<OverrideSettings>,
 }
 
 impl Default for LinterSettings {
@@ -44,10 +61,77 @@ impl Default for LinterSettings {
             default_exclude: None,
             check_roxygen: None,
             fix_roxygen: None,
+            lintr_compat_suppressions: None,
+            preview: None,
             fixable: None,
             unfixable: None,
+            severity: None,
+            baseline: None,
             deprecated_assignment_syntax: false,
             rule_options: ResolvedRuleOptions::default(),
+            overrides: Vec::new(),
         }
     }
 }
+
+/// An entry of `[lint.per-file-ignores]` or `[[lint.overrides]]`.
+#[derive(Clone, Debug)]
+pub struct OverrideSettings {
+    /// Patterns of the files to which the override applies, as written in
+    /// `jarl.toml`.
+    pub files: Vec<String>,
+    /// Matcher of `files`, resolved relative to the directory of `jarl.toml`.
+    matcher: Override,
+    /// Directory of `jarl.toml`.
+    root: PathBuf,
+    pub select: Option<Vec<String>>,
+    pub extend_select: Option<Vec<String>>,
+    pub ignore: Option<Vec<String>>,
+    pub rule_options: RuleOptionsOverride,
+}
+
+impl OverrideSettings {
+    pub fn new(
+        files: Vec<String>,
+        root: &Path,
+        select: Option<Vec<String>>,
+        extend_select: Option<Vec<String>>,
+        ignore: Option<Vec<String>>,
+        rule_options: RuleOptionsOverride,
+    ) -> anyhow::Result<Self> {
+        // Patterns follow the format of `include`: a pattern ending with `/`
+        // matches all files in this directory.
+        let mut builder = OverrideBuilder::new(root);
+        for pattern in &files {
+            let file_pattern = if pattern.ends_with('/') {
+                format!("{pattern}**")
+            } else {
+                pattern.clone()
+            };
+            builder
+                .add(&file_pattern)
+                .map_err(|e| anyhow::anyhow!("Invalid file pattern `{pattern}`: {e}"))?;
+        }
+        let matcher = builder.build()?;
+
+        Ok(Self {
+            files,
+            matcher,
+            root: root.to_path_buf(),
+            select,
+            extend_select,
+            ignore,
+            rule_options,
+        })
+    }
+
+    /// Whether the override applies to `path`.
+    pub fn matches(&self, path: &Path) -> bool {
+        let path = crate::fs::normalize_path(path);
+        let relative = path.strip_prefix(&self.root).unwrap_or(&path);
+        matches!(
+            self.matcher.matched(relative, false),
+            ignore::Match::Whitelist(_)
+        )
+    }
+}
@@ -7,14 +7,15 @@ use air_r_syntax::{RLanguage, RSyntaxKind, RSyntaxNode};
 use biome_formatter::comments::{
     CommentKind, CommentPlacement, CommentStyle, Comments, DecoratedComment,
 };
-use biome_rowan::{SyntaxTriviaPieceComments, TextRange};
+use biome_rowan::{Direction, SyntaxTriviaPieceComments, TextRange, TextSize};
 use std::collections::{HashMap, HashSet};
 
 use crate::diagnostic::Diagnostic;
 use crate::directive::{
-    DirectiveParseResult, LintDirective, is_quarto_chunk_array_header, parse_comment_directive,
-    parse_quarto_chunk_array_item,
+    DirectiveParseResult, LintDirective, NolintDirective, is_quarto_chunk_array_header,
+    parse_comment_directive, parse_nolint_directive, parse_quarto_chunk_array_item,
 };
+use crate::lintr::nolint_rules;
 use crate::rule_set::Rule;
 
 /// Comment style for R that identifies suppression directives
@@ -124,6 +125,16 @@ pub struct ChunkSuppression {
     pub comment_range: TextRange,
 }
 
+/// Represents a lintr `# nolint` suppression, only collected when
+/// `lintr-compat-suppressions` is enabled
+#[derive(Debug, Clone)]
+pub struct NolintSuppression {
+    /// The lines covered by this suppression
+    pub range: TextRange,
+    /// The rules being suppressed, `None` for all rules
+    pub rules: Option<Vec<Rule>>,
+}
+
 /// Intermediate state used during single-pass comment collection
 struct CommentCollector {
     /// Track start positions per (rule, nesting_level) for building skip regions
@@ -190,6 +201,8 @@ pub struct SuppressionManager {
     pub chunk_suppressions: Vec<ChunkSuppression>,
     /// Node-level suppressions (# jarl-ignore rule: explanation)
     pub node_suppressions: Vec<NodeSuppression>,
+    /// Line and range suppressions from lintr's `# nolint` comments
+    pub nolint_suppressions: Vec<NolintSuppression>,
     /// Fast path: true if there are any suppressions anywhere in the file
     pub has_any_suppressions: bool,
     /// Locations of blanket suppression comments (e.g., `# jarl-ignore` without a rule)
@@ -219,7 +232,14 @@ impl SuppressionManager {
     /// # Arguments
     /// * `root` - The root syntax node
     /// * `source` - The source code text (used for fast path optimization)
-    pub fn from_node(root: &RSyntaxNode, source: &str) -> Self {
+    /// * `lintr_compat` - Whether lintr's `# nolint` comments are recognized
+    pub fn from_node(root: &RSyntaxNode, source: &str, lintr_compat: bool) -> Self {
+        let nolint_suppressions = if lintr_compat && source.contains("nolint") {
+            Self::collect_nolint_suppressions(root, source)
+        } else {
+            Vec::new()
+        };
+
         // Fast path: if there's no "jarl-ignore" text anywhere in the source,
         // skip all expensive comment processing
         if !source.contains("jarl-ignore") {
@@ -229,7 +249,8 @@ impl SuppressionManager {
                 file_suppressions: Vec::new(),
                 chunk_suppressions: Vec::new(),
                 node_suppressions: Vec::new(),
-                has_any_suppressions: false,
+                has_any_suppressions: !nolint_suppressions.is_empty(),
+                nolint_suppressions,
                 blanket_suppressions: Vec::new(),
                 invalid_chunk_suppressions: Vec::new(),
                 unmatched_start_suppressions: Vec::new(),
@@ -257,6 +278,7 @@ impl SuppressionManager {
         let has_any_suppressions = !collector.skip_regions.is_empty()
             || !collector.file_suppressions.is_empty()
             || !collector.chunk_suppressions.is_empty()
+            || !nolint_suppressions.is_empty()
             || collector.has_any_valid_directive;
 
         Self {
@@ -265,6 +287,7 @@ impl SuppressionManager {
             file_suppressions: collector.file_suppressions,
             chunk_suppressions: collector.chunk_suppressions,
             node_suppressions: collector.node_suppressions,
+            nolint_suppressions,
             has_any_suppressions,
             blanket_suppressions: collector.blanket_suppressions,
             invalid_chunk_suppressions: collector.invalid_chunk_suppressions,
@@ -278,6 +301,57 @@ impl SuppressionManager {
         }
     }
 
+    /// Collect the suppressions of lintr's `# nolint` comments.
+    ///
+    /// As in lintr, `# nolint` skips the line it is on, and `# nolint start`
+    /// skips all lines until the next `# nolint end` (or the end of the file).
+    fn collect_nolint_suppressions(root: &RSyntaxNode, source: &str) -> Vec<NolintSuppression> {
+        let mut suppressions = Vec::new();
+        let mut open_range: Option<(TextSize, Option<Vec<Rule>>)> = None;
+
+        for token in root.descendants_tokens(Direction::Next) {
+            let pieces = token
+                .leading_trivia()
+                .pieces()
+                .chain(token.trailing_trivia().pieces());
+
+            for piece in pieces.filter(|piece| piece.is_comments()) {
+                let Some(directive) = parse_nolint_directive(piece.text()) else {
+                    continue;
+                };
+                let line = line_range(source, piece.text_range());
+
+                match directive {
+                    NolintDirective::Line(linters) => suppressions.push(NolintSuppression {
+                        range: line,
+                        rules: linters.as_deref().map(rules_of_linters),
+                    }),
+                    NolintDirective::Start(linters) => {
+                        open_range = Some((line.start(), linters.as_deref().map(rules_of_linters)));
+                    }
+                    NolintDirective::End => {
+                        if let Some((start, rules)) = open_range.take() {
+                            suppressions.push(NolintSuppression {
+                                range: TextRange::new(start, line.end()),
+                                rules,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        // An unterminated `# nolint start` skips the rest of the file
+        if let Some((start, rules)) = open_range {
+            suppressions.push(NolintSuppression {
+                range: TextRange::new(start, TextSize::of(source)),
+                rules,
+            });
+        }
+
+        suppressions
+    }
+
     /// Single-pass collection of all directive information from comments
     fn collect_all_directives(
         node: &RSyntaxNode,
@@ -670,7 +744,12 @@ impl SuppressionManager {
             }
         }
 
-        false
+        // Check lintr `# nolint` suppressions, which apply to the line where
+        // the diagnostic starts
+        self.nolint_suppressions.iter().any(|sup| {
+            sup.range.contains_inclusive(diag.range.start())
+                && sup.rules.as_ref().is_none_or(|rules| rules.contains(&rule))
+        })
     }
 
     /// Get all suppression comment ranges that were never used.
@@ -709,3 +788,28 @@ impl SuppressionManager {
         unused
     }
 }
+
+/// Get the Jarl rules covered by a list of lintr linters. Linters without an
+/// equivalent in Jarl are ignored.
+fn rules_of_linters(linters: &[String]) -> Vec<Rule> {
+    linters
+        .iter()
+        .filter_map(|linter| nolint_rules(linter))
+        .flatten()
+        .collect()
+}
+
+/// Get the range of the lines that contain `range`, without the final newline.
+fn line_range(source: &str, range: TextRange) -> TextRange {
+    let start = usize::from(range.start());
+    let end = usize::from(range.end());
+    let line_start = source[..start].rfind('\n').map_or(0, |pos| pos + 1);
+    let line_end = source[end..]
+        .find('\n')
+        .map_or(source.len(), |pos| end + pos);
+
+    TextRange::new(
+        TextSize::from(line_start as u32),
+        TextSize::from(line_end as u32),
+    )
+}
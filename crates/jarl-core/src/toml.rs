@@ -6,6 +6,7 @@
 //
 // MIT License - Posit PBC
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
@@ -14,7 +15,9 @@ use std::io;
 use std::path::Path;
 use std::path::PathBuf;
 
+use crate::description::Description;
 use crate::rule_options::ResolvedRuleOptions;
+use crate::rule_options::RuleOptionsOverride;
 use crate::rule_options::assignment::AssignmentConfig;
 use crate::rule_options::assignment::AssignmentOptions;
 use crate::rule_options::duplicated_arguments::DuplicatedArgumentsOptions;
@@ -24,13 +27,22 @@ use crate::rule_options::quotes::QuotesOptions;
 use crate::rule_options::undesirable_function::UndesirableFunctionOptions;
 use crate::rule_options::unreachable_code::UnreachableCodeOptions;
 use crate::rule_options::unused_function::UnusedFunctionOptions;
+use crate::rule_set::Severity;
 use crate::settings::LinterSettings;
+use crate::settings::OverrideSettings;
 use crate::settings::Settings;
+use crate::toml_validation::LINT_FIELDS;
+use crate::toml_validation::TomlDiagnostic;
+use crate::toml_validation::validate_description_config;
+use crate::toml_validation::validate_jarl_toml;
 
 #[derive(Debug)]
 pub enum ParseTomlError {
     Read(PathBuf, io::Error),
     Deserialize(PathBuf, toml::de::Error),
+    /// Errors found by [validate_jarl_toml()], with the content of the file
+    /// to locate them.
+    Invalid(PathBuf, String, Vec<TomlDiagnostic>),
 }
 
 impl std::error::Error for ParseTomlError {}
@@ -46,15 +58,165 @@ impl Display for ParseTomlError {
             Self::Deserialize(path, err) => {
                 write!(f, "Failed to parse {path}:\n{err}", path = path.display())
             }
+            Self::Invalid(path, contents, diagnostics) => {
+                write!(f, "Invalid configuration in {path}:", path = path.display())?;
+                for diagnostic in diagnostics {
+                    let (line, column) = diagnostic.line_column(contents);
+                    write!(
+                        f,
+                        "\n{path}:{line}:{column}: {message}",
+                        path = path.display(),
+                        message = diagnostic.message
+                    )?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
 pub fn parse_jarl_toml(path: &Path) -> Result<TomlOptions, ParseTomlError> {
-    let toml = fs::read_to_string(path).unwrap();
+    let toml =
+        fs::read_to_string(path).map_err(|err| ParseTomlError::Read(path.to_path_buf(), err))?;
+
+    // Report all unknown fields and rules at once, with their location.
+    // Warnings are reported by the callers, e.g. for deprecated rules.
+    let errors: Vec<TomlDiagnostic> = validate_jarl_toml(&toml)
+        .into_iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .collect();
+    if !errors.is_empty() {
+        return Err(ParseTomlError::Invalid(path.to_path_buf(), toml, errors));
+    }
+
     toml::from_str(&toml).map_err(|err| ParseTomlError::Deserialize(path.to_path_buf(), err))
 }
 
+/// Parse the configuration file at `path` and merge it with the files it
+/// extends (recursively) with the `extend` field.
+pub fn load_jarl_toml(path: &Path) -> anyhow::Result<TomlOptions> {
+    load_jarl_toml_extended(path, &mut Vec::new())
+}
+
+/// `chain` contains the files that are currently being loaded, to detect
+/// cycles. The last one extends `path`.
+fn load_jarl_toml_extended(path: &Path, chain: &mut Vec<PathBuf>) -> anyhow::Result<TomlOptions> {
+    let path = crate::fs::normalize_path(path);
+    let canonical = fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
+
+    if let Some(start) = chain
+        .iter()
+        .position(|file| fs::canonicalize(file).is_ok_and(|file| file == canonical))
+    {
+        let cycle: Vec<String> = chain[start..]
+            .iter()
+            .chain(std::iter::once(&path))
+            .map(|file| file.display().to_string())
+            .collect();
+        return Err(anyhow::anyhow!(
+            "Circular `extend` in configuration files:\n  {}",
+            cycle.join("\n  extends ")
+        ));
+    }
+
+    // The required version is checked before the file is validated, so that
+    // a file written for a newer version of Jarl, with fields that this one
+    // doesn't know, reports the version mismatch.
+    check_required_version(&path)?;
+    let mut options = parse_jarl_toml(&path)?;
+
+    let Some(extend) = options.global.extend.take() else {
+        return Ok(options);
+    };
+
+    let directory = path.parent().unwrap_or(Path::new("."));
+    let base_path = crate::fs::normalize_path(directory.join(&extend));
+    if !base_path.is_file() {
+        return Err(anyhow::anyhow!(
+            "Failed to find {}, extended by {}.",
+            base_path.display(),
+            path.display()
+        ));
+    }
+
+    chain.push(path.clone());
+    let base = load_jarl_toml_extended(&base_path, chain)?;
+    chain.pop();
+
+    // Validate the base file on its own so that errors point to the file in
+    // which the invalid setting is written. Paths and patterns of the base
+    // file are used as if they were written in the file that extends it.
+    validate_toml_options(&base, directory).map_err(|err| {
+        anyhow::anyhow!(
+            "Invalid configuration in {} (extended by {}):\n{err}",
+            base_path.display(),
+            path.display()
+        )
+    })?;
+
+    Ok(options.combine(base))
+}
+
+/// Check that the running version of Jarl satisfies the `required-version`
+/// of the configuration file at `path`.
+///
+/// The field is read from the raw content of the file. Files that can't be
+/// parsed and invalid requirements are reported by [parse_jarl_toml()].
+fn check_required_version(path: &Path) -> anyhow::Result<()> {
+    let Some(table) = fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| contents.parse::<toml::Table>().ok())
+    else {
+        return Ok(());
+    };
+    let Some(required_version) = table.get("required-version").and_then(toml::Value::as_str) else {
+        return Ok(());
+    };
+    let Ok(requirement) = semver::VersionReq::parse(required_version) else {
+        return Ok(());
+    };
+
+    let version = semver::Version::parse(env!("CARGO_PKG_VERSION"))?;
+    if !requirement.matches(&version) {
+        return Err(anyhow::anyhow!(
+            "{} requires Jarl {required_version} but the installed version is {version}.\n\
+             Install a version of Jarl that matches `required-version`, or update it.",
+            path.display()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Parse an option passed inline with `--config`, e.g.
+/// `lint.quotes.quote = "single"`.
+pub fn parse_inline_toml(value: &str) -> anyhow::Result<TomlOptions> {
+    let options: TomlOptions = toml::from_str(value)
+        .map_err(|err| anyhow::anyhow!("Invalid `--config` option `{value}`:\n{err}"))?;
+
+    if options.global.extend.is_some() {
+        return Err(anyhow::anyhow!(
+            "`extend` can't be set with `--config`. Pass the path to the configuration file instead."
+        ));
+    }
+
+    // Validate the option on its own so that errors point to it rather than
+    // to the configuration file it is merged with.
+    let root = crate::fs::normalize_path(".");
+    validate_toml_options(&options, &root)
+        .map_err(|err| anyhow::anyhow!("Invalid `--config` option `{value}`:\n{err}"))?;
+
+    Ok(options)
+}
+
+fn validate_toml_options(options: &TomlOptions, root: &Path) -> anyhow::Result<()> {
+    let settings = options.clone().into_settings(root)?;
+    crate::config::parse_rules_toml(Some(&settings))?;
+    crate::config::parse_fixable_toml(Some(&settings))?;
+    crate::config::parse_severity_toml(Some(&settings))?;
+    Ok(())
+}
+
 #[derive(Clone, Debug, Default, serde::Deserialize)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
@@ -67,7 +229,27 @@ pub struct TomlOptions {
 #[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
-pub struct GlobalTomlOptions {}
+pub struct GlobalTomlOptions {
+    /// # Configuration file to extend
+    ///
+    /// Path to another configuration file, either absolute or relative to the
+    /// directory of this file. Its settings are used as defaults and are
+    /// overridden by the settings of this file. This is useful to share a
+    /// configuration across several projects.
+    ///
+    /// Paths and patterns of the extended file, such as `exclude` or
+    /// `baseline`, are used as if they were written in the configuration file
+    /// that Jarl found, and are resolved relative to its directory.
+    pub extend: Option<String>,
+
+    /// # Required version of Jarl
+    ///
+    /// A version requirement, such as `">=0.6, <0.8"` or `"==0.6.1"`, that the
+    /// version of Jarl must satisfy. Jarl fails with an error if it doesn't,
+    /// so that all developers and CI use compatible versions.
+    #[serde(rename = "required-version")]
+    pub required_version: Option<String>,
+}
 
 #[derive(Clone, Debug, Default, serde::Deserialize)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -217,6 +399,65 @@ pub struct LinterTomlOptions {
     ///
     /// Defaults to `false`.
     pub fix_roxygen: Option<bool>,
+
+    /// # Whether to recognize lintr's `# nolint` comments
+    ///
+    /// When enabled, `# nolint`, `# nolint: <linters>.`, and
+    /// `# nolint start` / `# nolint end` comments suppress the violations of
+    /// the equivalent Jarl rules, as in lintr. This helps adopting Jarl in
+    /// projects that have many `# nolint` comments.
+    ///
+    /// Defaults to `false`.
+    pub lintr_compat_suppressions: Option<bool>,
+
+    /// # Whether to enable preview rules and behaviors
+    ///
+    /// Preview rules are new rules that are not enabled by default, even if
+    /// they are selected, so that upgrading Jarl doesn't report new
+    /// violations. Setting this to `true` is equivalent to passing `--preview`
+    /// in the CLI.
+    ///
+    /// Defaults to `false`.
+    pub preview: Option<bool>,
+
+    /// # Severity of rule violations
+    ///
+    /// A table mapping rule names or names of groups of rules (such as
+    /// `"PERF"`) to a severity level: `"error"`, `"warning"`, or `"info"`.
+    /// Rules that are not listed keep their default severity (`"warning"`).
+    ///
+    /// Rule names take precedence over group names. If a rule belongs to
+    /// several listed groups, the most severe level is used.
+    pub severity: Option<BTreeMap<String, String>>,
+
+    /// # Baseline of known violations
+    ///
+    /// Path to a baseline file generated with
+    /// `jarl check --generate-baseline <path>`, resolved relative to the
+    /// directory of `jarl.toml`. Violations recorded in this file are not
+    /// reported, only new ones are. This is useful to adopt Jarl on an existing
+    /// codebase without having to fix or suppress all violations at once.
+    pub baseline: Option<String>,
+
+    /// # Rules to ignore in some files
+    ///
+    /// A table mapping file patterns to a list of rules (or groups of rules)
+    /// that are ignored in the matching files, e.g.
+    /// `"tests/**" = ["undesirable_function"]`. Patterns follow the same
+    /// format as `include` and are resolved relative to the directory of
+    /// `jarl.toml`.
+    pub per_file_ignores: Option<BTreeMap<String, Vec<String>>>,
+
+    /// # Settings that only apply to some files
+    ///
+    /// Each entry of `[[lint.overrides]]` has a list of file patterns in
+    /// `files` and any of `select`, `extend-select`, `ignore`, and rule
+    /// options (e.g. `[lint.overrides.assignment]`). Those are applied on top
+    /// of the settings of `[lint]` for the files that match one of the
+    /// patterns. When several entries match a file, they are applied in
+    /// order.
+    pub overrides: Option<Vec<OverrideTomlOptions>>,
+
     /// # Assignment operator to use
     ///
     /// Accepts either the legacy form `assignment = "<-"` (deprecated) or the
@@ -290,6 +531,155 @@ pub struct LinterTomlOptions {
     pub(crate) unknown_fields: HashMap<String, toml::Value>,
 }
 
+impl LinterTomlOptions {
+    /// Merge these options with those of the file they extend:
+    ///
+    /// - `extend-select` is appended to the one of `base`, unless `select` is
+    ///   set in `self`, in which case it replaces the whole selection;
+    /// - tables of `severity` and `per-file-ignores` are merged, keeping the
+    ///   values of `self` for keys present in both;
+    /// - entries of `overrides` are appended after those of `base`;
+    /// - tables of rule options are merged key by key, keeping the values of
+    ///   `self`, and their `extend-*` lists are added to the lists of `base`;
+    /// - all other options are replaced.
+    fn combine(self, base: Self) -> Self {
+        let extend_select = if self.select.is_some() {
+            self.extend_select
+        } else {
+            combine_lists(base.extend_select, self.extend_select)
+        };
+
+        Self {
+            select: self.select.or(base.select),
+            extend_select,
+            ignore: self.ignore.or(base.ignore),
+            fixable: self.fixable.or(base.fixable),
+            unfixable: self.unfixable.or(base.unfixable),
+            include: self.include.or(base.include),
+            exclude: self.exclude.or(base.exclude),
+            default_exclude: self.default_exclude.or(base.default_exclude),
+            check_roxygen: self.check_roxygen.or(base.check_roxygen),
+            fix_roxygen: self.fix_roxygen.or(base.fix_roxygen),
+            lintr_compat_suppressions: self
+                .lintr_compat_suppressions
+                .or(base.lintr_compat_suppressions),
+            preview: self.preview.or(base.preview),
+            severity: combine_tables(base.severity, self.severity),
+            baseline: self.baseline.or(base.baseline),
+            per_file_ignores: combine_tables(base.per_file_ignores, self.per_file_ignores),
+            overrides: combine_lists(base.overrides, self.overrides),
+            assignment: combine_options(
+                base.assignment,
+                self.assignment,
+                AssignmentConfig::combine,
+            ),
+            duplicated_arguments: combine_options(
+                base.duplicated_arguments,
+                self.duplicated_arguments,
+                DuplicatedArgumentsOptions::combine,
+            ),
+            implicit_assignment: combine_options(
+                base.implicit_assignment,
+                self.implicit_assignment,
+                ImplicitAssignmentOptions::combine,
+            ),
+            pipe_consistency: combine_options(
+                base.pipe_consistency,
+                self.pipe_consistency,
+                PipeConsistencyOptions::combine,
+            ),
+            quotes: combine_options(base.quotes, self.quotes, QuotesOptions::combine),
+            undesirable_function: combine_options(
+                base.undesirable_function,
+                self.undesirable_function,
+                UndesirableFunctionOptions::combine,
+            ),
+            unreachable_code: combine_options(
+                base.unreachable_code,
+                self.unreachable_code,
+                UnreachableCodeOptions::combine,
+            ),
+            unused_function: combine_options(
+                base.unused_function,
+                self.unused_function,
+                UnusedFunctionOptions::combine,
+            ),
+            unknown_fields: self.unknown_fields,
+        }
+    }
+}
+
+fn combine_lists<T>(base: Option<Vec<T>>, values: Option<Vec<T>>) -> Option<Vec<T>> {
+    match (base, values) {
+        (Some(mut base), Some(values)) => {
+            base.extend(values);
+            Some(base)
+        }
+        (base, values) => values.or(base),
+    }
+}
+
+fn combine_options<T>(
+    base: Option<T>,
+    values: Option<T>,
+    combine: impl FnOnce(T, T) -> T,
+) -> Option<T> {
+    match (base, values) {
+        (Some(base), Some(values)) => Some(combine(values, base)),
+        (base, values) => values.or(base),
+    }
+}
+
+fn combine_tables<T>(
+    base: Option<BTreeMap<String, T>>,
+    values: Option<BTreeMap<String, T>>,
+) -> Option<BTreeMap<String, T>> {
+    match (base, values) {
+        (Some(mut base), Some(values)) => {
+            base.extend(values);
+            Some(base)
+        }
+        (base, values) => values.or(base),
+    }
+}
+
+/// An entry of `[[lint.overrides]]`.
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct OverrideTomlOptions {
+    /// # Files to which the override applies
+    ///
+    /// Patterns follow the same format as `include` and are resolved relative
+    /// to the directory of `jarl.toml`, e.g. `"tests/**"` or `"data-raw/"`.
+    pub files: Vec<String>,
+
+    /// # Rules to select in these files
+    ///
+    /// This replaces the selection of rules of `[lint]`.
+    pub select: Option<Vec<String>>,
+
+    /// # Additional rules to select in these files
+    pub extend_select: Option<Vec<String>>,
+
+    /// # Rules to ignore in these files
+    pub ignore: Option<Vec<String>>,
+
+    pub assignment: Option<AssignmentOptions>,
+    #[serde(rename = "duplicated_arguments")]
+    pub duplicated_arguments: Option<DuplicatedArgumentsOptions>,
+    #[serde(rename = "implicit_assignment")]
+    pub implicit_assignment: Option<ImplicitAssignmentOptions>,
+    #[serde(rename = "pipe_consistency")]
+    pub pipe_consistency: Option<PipeConsistencyOptions>,
+    #[serde(rename = "quotes")]
+    pub quotes: Option<QuotesOptions>,
+    #[serde(rename = "undesirable_function")]
+    pub undesirable_function: Option<UndesirableFunctionOptions>,
+    #[serde(rename = "unreachable_code")]
+    pub unreachable_code: Option<UnreachableCodeOptions>,
+}
+
 /// Return the path to the `jarl.toml` or `.jarl.toml` file in a given directory.
 pub fn find_jarl_toml_in_directory<P: AsRef<Path>>(path: P) -> Option<PathBuf> {
     // Check for `jarl.toml` first, as we prioritize the "visible" one.
@@ -308,6 +698,74 @@ pub fn find_jarl_toml_in_directory<P: AsRef<Path>>(path: P) -> Option<PathBuf> {
     None
 }
 
+/// Fields of `DESCRIPTION` that can contain settings, without the
+/// `Config/jarl/` prefix.
+pub const DESCRIPTION_FIELDS: &[&str] = &["select", "ignore", "exclude"];
+
+/// Return the path to the `DESCRIPTION` file in a given directory if it
+/// contains settings in `Config/jarl/*` fields.
+pub fn find_description_config_in_directory<P: AsRef<Path>>(path: P) -> Option<PathBuf> {
+    let description = path.as_ref().join("DESCRIPTION");
+    let contents = fs::read_to_string(&description).ok()?;
+    if Description::get_jarl_config(&contents).is_empty() {
+        None
+    } else {
+        Some(description)
+    }
+}
+
+/// Whether `path` is a `DESCRIPTION` file rather than a `jarl.toml`.
+pub fn is_description_file(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == "DESCRIPTION")
+}
+
+/// Parse the settings of the `Config/jarl/*` fields of a `DESCRIPTION` file.
+/// Values are separated by commas, e.g. `Config/jarl/ignore: assignment, quotes`.
+pub fn load_description_config(path: &Path) -> anyhow::Result<TomlOptions> {
+    let contents = fs::read_to_string(path)
+        .map_err(|err| anyhow::anyhow!("Failed to read {}: {err}", path.display()))?;
+
+    let mut lint = LinterTomlOptions::default();
+    for (field, value) in Description::get_jarl_config(&contents) {
+        let values: Vec<String> = value
+            .split(',')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(String::from)
+            .collect();
+        match field.as_str() {
+            "select" => lint.select = Some(values),
+            "ignore" => lint.ignore = Some(values),
+            "exclude" => lint.exclude = Some(values),
+            _ => {
+                let expected: Vec<String> = DESCRIPTION_FIELDS
+                    .iter()
+                    .map(|field| format!("`Config/jarl/{field}`"))
+                    .collect();
+                return Err(anyhow::anyhow!(
+                    "Unknown field `Config/jarl/{field}` in {}. Expected one of: {}.",
+                    path.display(),
+                    expected.join(", ")
+                ));
+            }
+        }
+    }
+
+    // Report unknown rules with their location, like in `jarl.toml`.
+    let errors: Vec<TomlDiagnostic> = validate_description_config(&contents)
+        .into_iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .collect();
+    if !errors.is_empty() {
+        return Err(ParseTomlError::Invalid(path.to_path_buf(), contents, errors).into());
+    }
+
+    Ok(TomlOptions {
+        global: GlobalTomlOptions::default(),
+        lint: Some(lint),
+    })
+}
+
 /// Find the path to the closest `jarl.toml` or `.jarl.toml` if one exists, walking up the filesystem
 pub fn find_jarl_toml<P: AsRef<Path>>(path: P) -> Option<PathBuf> {
     for directory in path.as_ref().ancestors() {
@@ -319,19 +777,70 @@ pub fn find_jarl_toml<P: AsRef<Path>>(path: P) -> Option<PathBuf> {
 }
 
 impl TomlOptions {
-    pub fn into_settings(self, _root: &Path) -> anyhow::Result<Settings> {
+    /// Merge these options with those of the file they extend. Options set in
+    /// `self` take precedence over those of `base`.
+    pub(crate) fn combine(self, base: Self) -> Self {
+        let lint = match (self.lint, base.lint) {
+            (Some(lint), Some(base)) => Some(lint.combine(base)),
+            (lint, base) => lint.or(base),
+        };
+        Self { global: self.global, lint }
+    }
+
+    pub fn into_settings(self, root: &Path) -> anyhow::Result<Settings> {
         let linter = self.lint.unwrap_or_default();
 
         // Reject unknown fields in `[lint]` with a clean error message that
         // only lists the primary options (not every rule sub-table name).
         if let Some(field) = linter.unknown_fields.keys().next() {
+            let expected: Vec<String> = LINT_FIELDS
+                .iter()
+                .map(|field| format!("`{field}`"))
+                .collect();
             return Err(anyhow::anyhow!(
-                "Unknown field `{field}` in `[lint]`. Expected one of: \
-                 `select`, `extend-select`, `ignore`, `fixable`, `unfixable`, \
-                 `exclude`, `default-exclude`, `include`, `check-roxygen`, `fix-roxygen`."
+                "Unknown field `{field}` in `[lint]`. Expected one of: {}.",
+                expected.join(", ")
             ));
         }
 
+        // `per-file-ignores` is a shorthand for overrides that only ignore
+        // rules, so both are stored the same way.
+        let mut overrides = Vec::new();
+        for (pattern, rules) in linter.per_file_ignores.unwrap_or_default() {
+            overrides.push(OverrideSettings::new(
+                vec![pattern],
+                root,
+                None,
+                None,
+                Some(rules),
+                RuleOptionsOverride::default(),
+            )?);
+        }
+        for entry in linter.overrides.unwrap_or_default() {
+            if entry.files.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "Field `files` in `[[lint.overrides]]` must contain at least one pattern."
+                ));
+            }
+            let rule_options = RuleOptionsOverride::resolve(
+                entry.assignment.as_ref(),
+                entry.duplicated_arguments.as_ref(),
+                entry.implicit_assignment.as_ref(),
+                entry.pipe_consistency.as_ref(),
+                entry.quotes.as_ref(),
+                entry.undesirable_function.as_ref(),
+                entry.unreachable_code.as_ref(),
+            )?;
+            overrides.push(OverrideSettings::new(
+                entry.files,
+                root,
+                entry.select,
+                entry.extend_select,
+                entry.ignore,
+                rule_options,
+            )?);
+        }
+
         // Resolve the assignment config: extract the AssignmentOptions and
         // track whether the deprecated top-level string form was used.
         let (assignment_options, deprecated_assignment_syntax) = match &linter.assignment {
@@ -352,8 +861,12 @@ impl TomlOptions {
             default_exclude: linter.default_exclude,
             check_roxygen: linter.check_roxygen,
             fix_roxygen: linter.fix_roxygen,
+            lintr_compat_suppressions: linter.lintr_compat_suppressions,
+            preview: linter.preview,
             fixable: linter.fixable,
             unfixable: linter.unfixable,
+            severity: linter.severity,
+            baseline: linter.baseline.map(|path| root.join(path)),
             deprecated_assignment_syntax,
             rule_options: ResolvedRuleOptions::resolve(
                 assignment_options.as_ref(),
@@ -365,6 +878,7 @@ impl TomlOptions {
                 linter.unreachable_code.as_ref(),
                 linter.unused_function.as_ref(),
             )?,
+            overrides,
         };
 
         Ok(Settings { linter })
@@ -0,0 +1,620 @@
+//! Validation of `jarl.toml` files.
+//!
+//! Deserializing the configuration stops at the first error, and unknown rule
+//! names are only detected when building the configuration, without their
+//! location. This checks the whole file at once and reports every problem
+//! with its position in the file, suggesting the closest valid name for
+//! misspelled rules and fields. It is used when loading configuration files,
+//! and by the language server when a `jarl.toml` is open.
+
+use std::ops::Range;
+
+use toml_edit::{ImDocument, Item, TableLike};
+
+use crate::rule_set::{Category, Rule, Severity};
+
+/// Fields of the top level of `jarl.toml`.
+const GLOBAL_FIELDS: &[&str] = &["extend", "required-version", "lint"];
+
+/// Fields of `[lint]` that are listed when a field is unknown. Tables of rule
+/// options, `severity`, and `assignment` are also valid.
+pub(crate) const LINT_FIELDS: &[&str] = &[
+    "select",
+    "extend-select",
+    "ignore",
+    "fixable",
+    "unfixable",
+    "exclude",
+    "default-exclude",
+    "include",
+    "check-roxygen",
+    "fix-roxygen",
+    "lintr-compat-suppressions",
+    "preview",
+    "baseline",
+    "per-file-ignores",
+    "overrides",
+];
+
+/// Fields of `[lint]` that contain a list of rules.
+const RULE_LIST_FIELDS: &[&str] = &["select", "extend-select", "ignore", "fixable", "unfixable"];
+
+/// Fields of `[[lint.overrides]]`, in addition to tables of rule options.
+const OVERRIDE_FIELDS: &[&str] = &["files", "select", "extend-select", "ignore"];
+
+/// Tables of rule options and their fields.
+const RULE_OPTIONS: &[(&str, &[&str])] = &[
+    ("assignment", &["operator"]),
+    (
+        "duplicated_arguments",
+        &["skipped-functions", "extend-skipped-functions"],
+    ),
+    (
+        "implicit_assignment",
+        &["skipped-functions", "extend-skipped-functions"],
+    ),
+    ("pipe_consistency", &["pipe"]),
+    ("quotes", &["quote"]),
+    ("undesirable_function", &["functions", "extend-functions"]),
+    (
+        "unreachable_code",
+        &["stopping-functions", "extend-stopping-functions"],
+    ),
+    (
+        "unused_function",
+        &["threshold-ignore", "skipped-functions"],
+    ),
+];
+
+/// A problem found in a configuration file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TomlDiagnostic {
+    pub severity: Severity,
+    pub message: String,
+    /// Byte range of the key or value that causes the problem
+    pub range: Range<usize>,
+}
+
+impl TomlDiagnostic {
+    fn error(message: String, range: Range<usize>) -> Self {
+        Self { severity: Severity::Error, message, range }
+    }
+
+    fn warning(message: String, range: Range<usize>) -> Self {
+        Self { severity: Severity::Warning, message, range }
+    }
+
+    /// Get the 1-based line and column of the start of the diagnostic in
+    /// `contents`.
+    pub fn line_column(&self, contents: &str) -> (usize, usize) {
+        let start = self.range.start.min(contents.len());
+        let before = &contents[..start];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |pos| pos + 1);
+        let column = before[line_start..].chars().count() + 1;
+        (line, column)
+    }
+}
+
+/// Check the content of a configuration file.
+///
+/// Syntax errors are reported alone since the rest of the file can't be
+/// checked. Otherwise, all unknown fields and rule names are reported, and
+/// deprecated rules in `select` and `extend-select` are reported as warnings.
+/// Invalid types and values of rule options are not checked here, they are
+/// reported when deserializing the file.
+pub fn validate_jarl_toml(contents: &str) -> Vec<TomlDiagnostic> {
+    let document = match ImDocument::parse(contents) {
+        Ok(document) => document,
+        Err(err) => {
+            return vec![TomlDiagnostic::error(
+                err.message().trim_end().to_string(),
+                err.span().unwrap_or_default(),
+            )];
+        }
+    };
+
+    let mut diagnostics = Vec::new();
+    check_global(document.as_table(), &mut diagnostics);
+    diagnostics
+}
+
+/// Check the rule names of the `Config/jarl/select` and `Config/jarl/ignore`
+/// fields of a `DESCRIPTION` file. Their values are separated by commas and
+/// may span several lines.
+pub fn validate_description_config(contents: &str) -> Vec<TomlDiagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut field: Option<(String, bool)> = None;
+    let mut offset = 0;
+
+    for line in contents.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+
+        // Continuation lines start with whitespace.
+        let value_start = if line.starts_with([' ', '\t']) {
+            line_start
+        } else {
+            field = line.split_once(':').and_then(|(key, _)| {
+                match key.strip_prefix("Config/jarl/")? {
+                    "select" => Some(("`Config/jarl/select`".to_string(), true)),
+                    "ignore" => Some(("`Config/jarl/ignore`".to_string(), false)),
+                    _ => None,
+                }
+            });
+            line_start + line.find(':').map_or(0, |pos| pos + 1)
+        };
+        let Some((field, warn_deprecated)) = &field else {
+            continue;
+        };
+
+        let mut start = value_start;
+        for value in contents[value_start..offset].split(',') {
+            let name = value.trim();
+            if !name.is_empty() {
+                let name_start = start + value.len() - value.trim_start().len();
+                let range = name_start..name_start + name.len();
+                check_rule(name, range, field, *warn_deprecated, &mut diagnostics);
+            }
+            start += value.len() + 1;
+        }
+    }
+
+    diagnostics
+}
+
+fn check_global(table: &dyn TableLike, diagnostics: &mut Vec<TomlDiagnostic>) {
+    for (key, item) in table.iter() {
+        match key {
+            "extend" => {}
+            "required-version" => {
+                if let Some(requirement) = item.as_str()
+                    && let Err(err) = semver::VersionReq::parse(requirement)
+                {
+                    diagnostics.push(TomlDiagnostic::error(
+                        format!("Invalid `required-version`: \"{requirement}\" ({err})."),
+                        item.span().unwrap_or_default(),
+                    ));
+                }
+            }
+            "lint" => {
+                if let Some(lint) = item.as_table_like() {
+                    check_lint(lint, diagnostics);
+                }
+            }
+            _ => diagnostics.push(unknown_field(
+                table,
+                key,
+                None,
+                GLOBAL_FIELDS,
+                GLOBAL_FIELDS,
+            )),
+        }
+    }
+}
+
+fn check_lint(table: &dyn TableLike, diagnostics: &mut Vec<TomlDiagnostic>) {
+    for (key, item) in table.iter() {
+        if RULE_LIST_FIELDS.contains(&key) {
+            let warn_deprecated = key == "select" || key == "extend-select";
+            check_rules(item, &format!("`{key}`"), warn_deprecated, diagnostics);
+        } else if key == "severity" {
+            check_severity(item, diagnostics);
+        } else if key == "per-file-ignores" {
+            if let Some(patterns) = item.as_table_like() {
+                for (_, rules) in patterns.iter() {
+                    check_rules(rules, "`[lint.per-file-ignores]`", false, diagnostics);
+                }
+            }
+        } else if key == "overrides" {
+            check_overrides(item, diagnostics);
+        } else if let Some((_, fields)) = RULE_OPTIONS.iter().find(|(rule, _)| *rule == key) {
+            // `assignment` can also be a string, which is deprecated.
+            if let Some(options) = item.as_table_like() {
+                check_rule_options(options, &format!("[lint.{key}]"), fields, diagnostics);
+            }
+        } else if !LINT_FIELDS.contains(&key) {
+            let candidates: Vec<&str> = LINT_FIELDS
+                .iter()
+                .copied()
+                .chain(["severity"])
+                .chain(RULE_OPTIONS.iter().map(|(rule, _)| *rule))
+                .collect();
+            diagnostics.push(unknown_field(
+                table,
+                key,
+                Some("[lint]"),
+                &candidates,
+                LINT_FIELDS,
+            ));
+        }
+    }
+}
+
+fn check_overrides(item: &Item, diagnostics: &mut Vec<TomlDiagnostic>) {
+    // Overrides are usually written as `[[lint.overrides]]`, but can also be
+    // an array of inline tables.
+    let entries: Vec<&dyn TableLike> = if let Some(tables) = item.as_array_of_tables() {
+        tables.iter().map(|table| table as &dyn TableLike).collect()
+    } else if let Some(array) = item.as_array() {
+        array
+            .iter()
+            .filter_map(|value| value.as_inline_table())
+            .map(|table| table as &dyn TableLike)
+            .collect()
+    } else {
+        return;
+    };
+
+    let rule_tables = RULE_OPTIONS
+        .iter()
+        .filter(|(rule, _)| *rule != "unused_function");
+
+    for entry in entries {
+        for (key, item) in entry.iter() {
+            if key == "files" {
+                continue;
+            } else if OVERRIDE_FIELDS.contains(&key) {
+                let warn_deprecated = key == "select" || key == "extend-select";
+                let field = format!("`{key}` of `[[lint.overrides]]`");
+                check_rules(item, &field, warn_deprecated, diagnostics);
+            } else if let Some((_, fields)) = rule_tables.clone().find(|(rule, _)| *rule == key) {
+                if let Some(options) = item.as_table_like() {
+                    let section = format!("[lint.overrides.{key}]");
+                    check_rule_options(options, &section, fields, diagnostics);
+                }
+            } else {
+                let candidates: Vec<&str> = OVERRIDE_FIELDS
+                    .iter()
+                    .copied()
+                    .chain(rule_tables.clone().map(|(rule, _)| *rule))
+                    .collect();
+                diagnostics.push(unknown_field(
+                    entry,
+                    key,
+                    Some("[[lint.overrides]]"),
+                    &candidates,
+                    &candidates,
+                ));
+            }
+        }
+    }
+}
+
+fn check_rule_options(
+    table: &dyn TableLike,
+    section: &str,
+    fields: &[&str],
+    diagnostics: &mut Vec<TomlDiagnostic>,
+) {
+    for (key, _) in table.iter() {
+        if !fields.contains(&key) {
+            diagnostics.push(unknown_field(table, key, Some(section), fields, fields));
+        }
+    }
+}
+
+/// Check a list of rule names, e.g. `select = ["any_is_na", "PERF"]`. Values
+/// that are not strings are reported when deserializing the file.
+fn check_rules(
+    item: &Item,
+    field: &str,
+    warn_deprecated: bool,
+    diagnostics: &mut Vec<TomlDiagnostic>,
+) {
+    let Some(array) = item.as_array() else {
+        return;
+    };
+
+    for value in array.iter() {
+        let Some(name) = value.as_str() else {
+            continue;
+        };
+        let range = value.span().unwrap_or_default();
+        check_rule(name.trim(), range, field, warn_deprecated, diagnostics);
+    }
+}
+
+fn check_rule(
+    name: &str,
+    range: Range<usize>,
+    field: &str,
+    warn_deprecated: bool,
+    diagnostics: &mut Vec<TomlDiagnostic>,
+) {
+    if name.is_empty() {
+        diagnostics.push(TomlDiagnostic::error(
+            format!("Rule names in {field} can't be empty."),
+            range,
+        ));
+        return;
+    }
+    if is_rule_group(name) {
+        return;
+    }
+
+    match Rule::from_name(name) {
+        Some(rule) => {
+            if warn_deprecated && let Some(deprecation) = rule.deprecation() {
+                diagnostics.push(TomlDiagnostic::warning(
+                    format!(
+                        "Rule `{name}` is deprecated since v{}. Use `{}` instead.",
+                        deprecation.version, deprecation.replacement
+                    ),
+                    range,
+                ));
+            }
+        }
+        None => diagnostics.push(TomlDiagnostic::error(
+            format!("Unknown rule `{name}` in {field}.{}", suggest_rule(name)),
+            range,
+        )),
+    }
+}
+
+fn check_severity(item: &Item, diagnostics: &mut Vec<TomlDiagnostic>) {
+    let Some(table) = item.as_table_like() else {
+        return;
+    };
+
+    for (name, value) in table.iter() {
+        let trimmed = name.trim();
+        if !is_rule_group(trimmed) && Rule::from_name(trimmed).is_none() {
+            diagnostics.push(TomlDiagnostic::error(
+                format!(
+                    "Unknown rule `{name}` in `[lint.severity]`.{}",
+                    suggest_rule(trimmed)
+                ),
+                key_range(table, name),
+            ));
+        }
+
+        if let Some(level) = value.as_str()
+            && level.parse::<Severity>().is_err()
+        {
+            diagnostics.push(TomlDiagnostic::error(
+                format!(
+                    "Invalid value for `{name}` in `[lint.severity]`: \"{level}\". \
+                     Expected \"error\", \"warning\", or \"info\"."
+                ),
+                value.span().unwrap_or_default(),
+            ));
+        }
+    }
+}
+
+fn is_rule_group(name: &str) -> bool {
+    name == "ALL"
+        || Category::ALL
+            .iter()
+            .any(|category| category.as_str() == name)
+}
+
+fn suggest_rule(name: &str) -> String {
+    let candidates = Rule::all()
+        .iter()
+        .map(|rule| rule.name())
+        .chain(Category::ALL.iter().map(|category| category.as_str()))
+        .chain(["ALL"]);
+
+    match did_you_mean(name, candidates) {
+        Some(suggestion) => format!(" Did you mean `{suggestion}`?"),
+        None => String::new(),
+    }
+}
+
+/// Report an unknown field of `table`. The message suggests the closest
+/// field among `candidates`, or lists the `expected` fields if none is close.
+fn unknown_field(
+    table: &dyn TableLike,
+    key: &str,
+    section: Option<&str>,
+    candidates: &[&str],
+    expected: &[&str],
+) -> TomlDiagnostic {
+    let location = section
+        .map(|section| format!(" in `{section}`"))
+        .unwrap_or_default();
+    let hint = match did_you_mean(key, candidates.iter().copied()) {
+        Some(suggestion) => format!("Did you mean `{suggestion}`?"),
+        None => {
+            let expected: Vec<String> = expected.iter().map(|field| format!("`{field}`")).collect();
+            format!("Expected one of: {}.", expected.join(", "))
+        }
+    };
+
+    TomlDiagnostic::error(
+        format!("Unknown field `{key}`{location}. {hint}"),
+        key_range(table, key),
+    )
+}
+
+fn key_range(table: &dyn TableLike, key: &str) -> Range<usize> {
+    table
+        .get_key_value(key)
+        .and_then(|(key, _)| key.span())
+        .unwrap_or_default()
+}
+
+/// Get the candidate closest to `name`, if it is close enough to be a
+/// plausible typo.
+fn did_you_mean<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| (levenshtein(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Number of single-character edits needed to turn `a` into `b`.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(contents: &str) -> Vec<(usize, usize, Severity, String)> {
+        validate_jarl_toml(contents)
+            .into_iter()
+            .map(|diagnostic| {
+                let (line, column) = diagnostic.line_column(contents);
+                (line, column, diagnostic.severity, diagnostic.message)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_file() {
+        let contents = r#"
+required-version = ">=0.5, <1.0"
+
+[lint]
+select = ["any_is_na", "PERF", "ALL"]
+ignore = ["any_duplicated"]
+
+[lint.severity]
+PERF = "error"
+
+[lint.assignment]
+operator = "="
+
+[[lint.overrides]]
+files = ["tests/**"]
+ignore = ["any_is_na"]
+"#;
+        assert_eq!(messages(contents), vec![]);
+    }
+
+    #[test]
+    fn test_all_problems_are_reported() {
+        let contents = r#"
+[lint]
+select = ["any_is_naa", "foo", ""]
+extend-select = ["PERF"]
+ignore = ["any_duplicate"]
+fixbale = ["any_is_na"]
+
+[lint.quotes]
+quotes = "single"
+"#;
+        assert_eq!(
+            messages(contents),
+            vec![
+                (
+                    3,
+                    11,
+                    Severity::Error,
+                    "Unknown rule `any_is_naa` in `select`. Did you mean `any_is_na`?".to_string()
+                ),
+                (
+                    3,
+                    25,
+                    Severity::Error,
+                    "Unknown rule `foo` in `select`.".to_string()
+                ),
+                (
+                    3,
+                    32,
+                    Severity::Error,
+                    "Rule names in `select` can't be empty.".to_string()
+                ),
+                (
+                    5,
+                    11,
+                    Severity::Error,
+                    "Unknown rule `any_duplicate` in `ignore`. Did you mean `any_duplicated`?"
+                        .to_string()
+                ),
+                (
+                    6,
+                    1,
+                    Severity::Error,
+                    "Unknown field `fixbale` in `[lint]`. Did you mean `fixable`?".to_string()
+                ),
+                (
+                    9,
+                    1,
+                    Severity::Error,
+                    "Unknown field `quotes` in `[lint.quotes]`. Did you mean `quote`?".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_description_config() {
+        let contents = "Package: foo\n\
+                        Config/jarl/select: PERF, any_is_naa,\n    foo\n\
+                        Config/jarl/ignore: any_duplicated\n\
+                        Config/jarl/exclude: any_is_naa\n";
+        let messages: Vec<_> = validate_description_config(contents)
+            .into_iter()
+            .map(|diagnostic| {
+                let (line, column) = diagnostic.line_column(contents);
+                (line, column, diagnostic.message)
+            })
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    2,
+                    27,
+                    "Unknown rule `any_is_naa` in `Config/jarl/select`. Did you mean `any_is_na`?"
+                        .to_string()
+                ),
+                (
+                    3,
+                    5,
+                    "Unknown rule `foo` in `Config/jarl/select`.".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deprecated_rule_is_warning() {
+        let contents = "[lint]\nselect = [\"browser\"]\n";
+        assert_eq!(
+            messages(contents),
+            vec![(
+                2,
+                11,
+                Severity::Warning,
+                "Rule `browser` is deprecated since v0.5.0. Use `undesirable_function` instead."
+                    .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_syntax_error() {
+        let diagnostics = validate_jarl_toml("[lint\nselect = 1\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("any_is_na", "any_is_na"), 0);
+        assert_eq!(levenshtein("any_is_naa", "any_is_na"), 1);
+        assert_eq!(levenshtein("fixbale", "fixable"), 2);
+        assert_eq!(levenshtein("", "abc"), 3);
+    }
+}
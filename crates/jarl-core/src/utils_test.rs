@@ -86,6 +86,7 @@ fn run_check(
         allow_dirty: false,
         allow_no_vcs: true,
         assignment: None,
+        preview: false,
     };
 
     let resolver = setup_resolver(temp_file.path(), settings);
@@ -142,6 +143,7 @@ fn apply_fixes(
         allow_dirty: false,
         allow_no_vcs: true,
         assignment: None,
+        preview: false,
     };
 
     let resolver = setup_resolver(temp_file.path(), settings);
@@ -1,7 +1,8 @@
 use crate::config::Config;
 use anyhow::{Result, bail};
 use std::collections::HashMap;
-use std::path::Path;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 /// Try to find the git repository root for a given file path.
@@ -122,3 +123,221 @@ pub fn check_version_control(paths: &[String], config: &Config) -> Result<()> {
 
     Ok(())
 }
+
+/// Lines of a file that were added or modified compared to a Git reference.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChangedLines {
+    /// The file is not tracked by Git, so all its lines are new.
+    All,
+    /// 1-based, inclusive ranges of lines.
+    Ranges(Vec<(usize, usize)>),
+}
+
+impl ChangedLines {
+    /// Does the range of lines `start..=end` (1-based) contain a changed line?
+    pub fn intersects(&self, start: usize, end: usize) -> bool {
+        match self {
+            Self::All => true,
+            Self::Ranges(ranges) => ranges
+                .iter()
+                .any(|(first, last)| start <= *last && end >= *first),
+        }
+    }
+}
+
+/// Files that changed compared to a Git reference. Files that are not listed
+/// didn't change.
+#[derive(Debug, Default)]
+pub struct ChangedFiles {
+    files: HashMap<PathBuf, ChangedLines>,
+}
+
+impl ChangedFiles {
+    pub fn get(&self, path: &Path) -> Option<&ChangedLines> {
+        self.files.get(&canonicalize(path))
+    }
+}
+
+/// Get the commit from which `HEAD` diverged from `base`.
+fn merge_base(root: &Path, base: &str) -> Result<String> {
+    let output = Command::new("git")
+        .args(["merge-base", base, "HEAD"])
+        .current_dir(root)
+        .output()?;
+
+    if !output.status.success() {
+        bail!(
+            "Failed to get the changes compared to `{base}`:\n{}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Git may report paths with symlinks resolved (e.g. `/private/tmp` instead of
+/// `/tmp` on macOS), so we compare canonical paths.
+fn canonicalize(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| crate::fs::normalize_path(path))
+}
+
+/// Get the lines that were added or modified in the working tree compared to
+/// the Git reference `base` (e.g. `origin/main`), for all repositories that
+/// contain `paths`. Untracked files are considered entirely new.
+///
+/// Changes are computed since the common ancestor of `base` and `HEAD`, so that
+/// changes made in `base` after the current branch was created are ignored.
+pub fn changed_lines(paths: &[String], base: &str) -> Result<ChangedFiles> {
+    let mut repos: Vec<String> = Vec::new();
+    for path in paths {
+        let Some(repo_root) = discover_repo(path) else {
+            bail!(
+                "`--diff-base` requires the files to be in a Git repository, but {path} \
+                 is not."
+            );
+        };
+        if !repos.contains(&repo_root) {
+            repos.push(repo_root);
+        }
+    }
+
+    let mut changed = ChangedFiles::default();
+
+    for repo_root in repos {
+        let root = Path::new(&repo_root);
+        let merge_base = merge_base(root, base)?;
+
+        let output = Command::new("git")
+            .args([
+                "diff",
+                "--no-color",
+                "--no-ext-diff",
+                "--unified=0",
+                "--src-prefix=a/",
+                "--dst-prefix=b/",
+                &merge_base,
+                "--",
+            ])
+            .current_dir(root)
+            .output()?;
+
+        if !output.status.success() {
+            bail!(
+                "Failed to get the changes compared to `{base}`:\n{}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        for (file, ranges) in parse_diff_hunks(&String::from_utf8_lossy(&output.stdout)) {
+            changed
+                .files
+                .insert(canonicalize(&root.join(file)), ChangedLines::Ranges(ranges));
+        }
+
+        let output = Command::new("git")
+            .args(["ls-files", "--others", "--exclude-standard"])
+            .current_dir(root)
+            .output()?;
+
+        for file in String::from_utf8_lossy(&output.stdout).lines() {
+            changed
+                .files
+                .insert(canonicalize(&root.join(file)), ChangedLines::All);
+        }
+    }
+
+    Ok(changed)
+}
+
+/// Extract the ranges of added lines of each file from the output of
+/// `git diff --unified=0`.
+fn parse_diff_hunks(diff: &str) -> Vec<(String, Vec<(usize, usize)>)> {
+    let mut files: Vec<(String, Vec<(usize, usize)>)> = Vec::new();
+
+    for line in diff.lines() {
+        if let Some(file) = line.strip_prefix("+++ ") {
+            // Deleted files have no new lines.
+            if let Some(file) = file.trim_matches('"').strip_prefix("b/") {
+                files.push((file.to_string(), Vec::new()));
+            }
+            continue;
+        }
+
+        // Hunk headers look like `@@ -12,3 +14,5 @@`. The length is omitted
+        // when it is 1, and is 0 when lines were only removed.
+        let Some(header) = line.strip_prefix("@@ ") else {
+            continue;
+        };
+        let Some(new) = header.split_whitespace().find_map(|s| s.strip_prefix('+')) else {
+            continue;
+        };
+        let (start, len) = match new.split_once(',') {
+            Some((start, len)) => (start.parse::<usize>(), len.parse::<usize>()),
+            None => (new.parse::<usize>(), Ok(1)),
+        };
+        if let (Ok(start), Ok(len)) = (start, len)
+            && len > 0
+            && let Some((_, ranges)) = files.last_mut()
+        {
+            ranges.push((start, start + len - 1));
+        }
+    }
+
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_diff_hunks() {
+        let diff = r#"diff --git a/R/foo.R b/R/foo.R
+index 1234567..89abcde 100644
+--- a/R/foo.R
++++ b/R/foo.R
+@@ -1,0 +2,2 @@ f <- function() {
++x <- 1
++y <- 2
+@@ -10 +12 @@
+-a
++b
+@@ -20,3 +21,0 @@
+-c
+-d
+-e
+diff --git a/R/deleted.R b/R/deleted.R
+deleted file mode 100644
+--- a/R/deleted.R
++++ /dev/null
+@@ -1 +0,0 @@
+-z
+diff --git a/R/new.R b/R/new.R
+new file mode 100644
+--- /dev/null
++++ b/R/new.R
+@@ -0,0 +1,3 @@
++a
++b
++c
+"#;
+        assert_eq!(
+            parse_diff_hunks(diff),
+            vec![
+                ("R/foo.R".to_string(), vec![(2, 3), (12, 12)]),
+                ("R/new.R".to_string(), vec![(1, 3)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_changed_lines_intersects() {
+        let lines = ChangedLines::Ranges(vec![(2, 3), (12, 12)]);
+        assert!(lines.intersects(1, 2));
+        assert!(lines.intersects(3, 3));
+        assert!(lines.intersects(10, 15));
+        assert!(!lines.intersects(1, 1));
+        assert!(!lines.intersects(4, 11));
+        assert!(ChangedLines::All.intersects(100, 100));
+    }
+}
@@ -21,7 +21,9 @@ use jarl_core::diagnostic::Diagnostic as JarlDiagnostic;
 use jarl_core::discovery::{DiscoveredSettings, discover_settings};
 use jarl_core::fs::{has_r_extension, relativize_path};
 use jarl_core::package::{is_in_r_package, make_package_analysis, summarize_package_info};
+use jarl_core::rule_set::Severity as JarlSeverity;
 use jarl_core::settings::Settings;
+use jarl_core::toml_validation::validate_jarl_toml;
 
 /// Fix information that can be attached to a diagnostic for code actions
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -64,6 +66,14 @@ pub fn lint_document(snapshot: &DocumentSnapshot) -> Result<LintOutput> {
     let file_path = snapshot.file_path();
     let encoding = snapshot.position_encoding();
 
+    if file_path.as_deref().is_some_and(is_config_file) {
+        return Ok(LintOutput {
+            diagnostics: lint_config_file(content, encoding)?,
+            unused_fn_hidden_count: 0,
+            refreshed_packages: Vec::new(),
+        });
+    }
+
     // Run the actual linting
     let LintInternalOutput {
         diagnostics: jarl_diagnostics,
@@ -85,6 +95,34 @@ pub fn lint_document(snapshot: &DocumentSnapshot) -> Result<LintOutput> {
     })
 }
 
+/// Whether `path` is a Jarl configuration file, i.e. `jarl.toml` or
+/// `.jarl.toml`.
+pub fn is_config_file(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name == "jarl.toml" || name == ".jarl.toml")
+}
+
+/// Report unknown fields and rules, and deprecated rules, in the content of a
+/// configuration file.
+fn lint_config_file(content: &str, encoding: PositionEncoding) -> Result<Vec<Diagnostic>> {
+    validate_jarl_toml(content)
+        .into_iter()
+        .map(|toml_diagnostic| {
+            let start =
+                byte_offset_to_lsp_position(toml_diagnostic.range.start, content, encoding)?;
+            let end = byte_offset_to_lsp_position(toml_diagnostic.range.end, content, encoding)?;
+
+            Ok(Diagnostic {
+                range: Range::new(start, end),
+                severity: Some(convert_severity(toml_diagnostic.severity)),
+                source: Some(DIAGNOSTIC_SOURCE.to_string()),
+                message: toml_diagnostic.message,
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
 struct LintInternalOutput {
     diagnostics: Vec<JarlDiagnostic>,
     unused_fn_hidden_count: usize,
@@ -143,14 +181,16 @@ fn run_jarl_linting(
         allow_dirty: false,
         allow_no_vcs: false,
         assignment: None,
+        preview: false,
     };
 
     let toml_settings = resolver.items().first().map(|item| item.value());
     let mut config = build_config(&check_config, toml_settings, vec![file_path.to_path_buf()])?;
 
     let mut refreshed_packages = Vec::new();
-    if config.rules_to_apply.has_package_specific_rules() {
-        let pkgs = config.rules_to_apply.pkg_names_from_category();
+    let all_rules = config.all_rules_to_apply();
+    if all_rules.has_package_specific_rules() {
+        let pkgs = all_rules.pkg_names_from_category();
         // Get or create a per-project-root cache (spawns Rscript once per root).
         let package_cache = snapshot.get_or_create_package_cache(&pkgs);
         // Check if any tracked packages have changed on disk (cheap stat()).
@@ -266,9 +306,7 @@ fn convert_to_lsp_diagnostic(
 
     let range = Range::new(start_pos, end_pos);
 
-    // TODO-etienne: don't have that
-    // let severity = convert_severity(jarl_diag.severity);
-    let severity = DiagnosticSeverity::WARNING;
+    let severity = convert_severity(jarl_diag.severity);
 
     // Extract fix information if available
     // Always include fix_data even if there's no actual fix, so we can access the rule_name
@@ -377,15 +415,14 @@ pub fn byte_offset_to_lsp_position(
     Ok(Position::new(line as u32, lsp_character))
 }
 
-// /// Convert Jarl severity to LSP diagnostic severity
-// fn convert_severity(severity: JarlSeverity) -> DiagnosticSeverity {
-//     match severity {
-//         JarlSeverity::Error => DiagnosticSeverity::ERROR,
-//         JarlSeverity::Warning => DiagnosticSeverity::WARNING,
-//         JarlSeverity::Info => DiagnosticSeverity::INFORMATION,
-//         JarlSeverity::Hint => DiagnosticSeverity::HINT,
-//     }
-// }
+/// Convert Jarl severity to LSP diagnostic severity
+fn convert_severity(severity: JarlSeverity) -> DiagnosticSeverity {
+    match severity {
+        JarlSeverity::Error => DiagnosticSeverity::ERROR,
+        JarlSeverity::Warning => DiagnosticSeverity::WARNING,
+        JarlSeverity::Info => DiagnosticSeverity::INFORMATION,
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -424,6 +461,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_config_file_diagnostics() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("jarl.toml");
+        let content = "[lint]\nselect = [\"any_is_naa\", \"browser\"]\n";
+        std::fs::write(&file_path, content).unwrap();
+
+        let snapshot = create_test_snapshot(&file_path, content);
+        let output = lint_document(&snapshot).unwrap();
+        assert_eq!(output.diagnostics.len(), 2);
+
+        let unknown = &output.diagnostics[0];
+        assert_eq!(
+            unknown.message,
+            "Unknown rule `any_is_naa` in `select`. Did you mean `any_is_na`?"
+        );
+        assert_eq!(unknown.severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(unknown.range.start, Position::new(1, 10));
+        assert_eq!(unknown.range.end, Position::new(1, 22));
+
+        let deprecated = &output.diagnostics[1];
+        assert_eq!(deprecated.severity, Some(DiagnosticSeverity::WARNING));
+        assert_eq!(deprecated.range.start, Position::new(1, 24));
+    }
+
     #[test]
     fn test_position_conversion() {
         let content = "hello\nworld\ntest";
@@ -290,9 +290,20 @@ impl Server {
                 // Check and notify about config file location (once per session, only if not in CWD)
                 if let Ok(file_path) = params.text_document.uri.to_file_path() {
                     session.check_and_notify_config(&file_path);
+
+                    // Configuration files are cheap to check, so report their
+                    // problems right away.
+                    if lint::is_config_file(&file_path)
+                        && let Some(snapshot) = session.take_snapshot(params.text_document.uri)
+                    {
+                        task_sender.send(Task::LintDocument {
+                            snapshot: Box::new(snapshot),
+                            client: session.client().clone(),
+                        })?;
+                    }
                 }
 
-                // Don't trigger linting on open, only on save
+                // Don't trigger linting of R files on open, only on save
                 Ok(())
             }
             types::notification::DidChangeTextDocument::METHOD => {
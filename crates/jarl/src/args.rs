@@ -3,6 +3,8 @@ use crate::output_format::OutputFormat;
 use clap::builder::Styles;
 use clap::builder::styling::{AnsiColor, Effects};
 use clap::{Parser, Subcommand};
+use jarl_core::rule_set::Severity;
+use std::path::PathBuf;
 
 // Configures Clap v3-style help menu colors
 const STYLES: Styles = Styles::styled()
@@ -32,6 +34,24 @@ pub(crate) enum Command {
     /// Check a set of files or directories
     Check(CheckCommand),
 
+    /// Explain a rule
+    Rule(RuleCommand),
+
+    /// List all rules
+    Rules(RulesCommand),
+
+    /// Inspect the configuration
+    Config(ConfigCommand),
+
+    /// Create a `jarl.toml` suited to the project
+    Init(InitCommand),
+
+    /// Migrate the configuration of another linter
+    Migrate(MigrateCommand),
+
+    /// Remove the cache of lint results
+    Clean(CleanCommand),
+
     /// Start a language server
     Server(ServerCommand),
 }
@@ -64,6 +84,15 @@ pub struct CheckCommand {
         help = "Apply fixes to resolve lint violations, but don't report on leftover violations. Implies `--fix`."
     )]
     pub fix_only: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        conflicts_with = "statistics",
+        conflicts_with = "add_jarl_ignore",
+        conflicts_with = "stdin_filename",
+        help = "Print a diff of the fixes instead of writing them to disk. Implies `--fix`."
+    )]
+    pub diff: bool,
     #[arg(
         long,
         default_value = "false",
@@ -117,6 +146,14 @@ pub struct CheckCommand {
         help="Output serialization format for violations."
     )]
     pub output_format: OutputFormat,
+    #[arg(
+        long,
+        value_enum,
+        value_name = "LEVEL",
+        default_value_t = Severity::Info,
+        help = "Minimum severity of violations that make jarl exit with a non-zero status."
+    )]
+    pub fail_on: Severity,
     #[arg(
         long,
         value_enum,
@@ -151,7 +188,218 @@ pub struct CheckCommand {
         help = "Automatically insert a `# jarl-ignore` comment to suppress all violations.\nThe default reason can be customized with `--add-jarl-ignore=\"my_reason\"`."
     )]
     pub add_jarl_ignore: Option<String>,
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with = "add_jarl_ignore",
+        help = "Lint source code read from stdin as if it was the content of this file. Pass `-` instead of files to check."
+    )]
+    pub stdin_filename: Option<String>,
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with = "fix",
+        conflicts_with = "unsafe_fixes",
+        conflicts_with = "fix_only",
+        conflicts_with = "diff",
+        conflicts_with = "statistics",
+        conflicts_with = "add_jarl_ignore",
+        conflicts_with = "stdin_filename",
+        help = "Record all current violations in a baseline file. Use `baseline` in jarl.toml to only report violations that are not in this file."
+    )]
+    pub generate_baseline: Option<PathBuf>,
+    #[arg(
+        long,
+        value_name = "REF",
+        conflicts_with = "fix",
+        conflicts_with = "unsafe_fixes",
+        conflicts_with = "fix_only",
+        conflicts_with = "diff",
+        conflicts_with = "generate_baseline",
+        conflicts_with = "stdin_filename",
+        help = "Only report violations on lines that were added or modified compared to this Git reference, for example `origin/main`."
+    )]
+    pub diff_base: Option<String>,
+    #[arg(
+        long,
+        default_value = "false",
+        help = "Don't read or write the cache of lint results."
+    )]
+    pub no_cache: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        help = "Enable preview rules and behaviors, which are not stable yet."
+    )]
+    pub preview: bool,
+    #[arg(
+        long,
+        value_name = "CONFIG_OPTION",
+        help = "Path to a configuration file to use instead of the discovered `jarl.toml`, or a TOML option overriding the configuration, for example `lint.quotes.quote = \"single\"`. Can be repeated."
+    )]
+    pub config: Vec<String>,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[command(arg_required_else_help(true))]
+pub struct RuleCommand {
+    #[arg(help = "Name of the rule to explain, for example `jarl rule any_is_na`.")]
+    pub name: String,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = InfoOutputFormat::default(),
+        help = "Output format for the rule information."
+    )]
+    pub output_format: InfoOutputFormat,
+}
+
+#[derive(Clone, Debug, Parser)]
+pub struct RulesCommand {
+    #[arg(
+        long,
+        value_name = "CATEGORY",
+        help = "Only list rules in this category, for example \"PERF\"."
+    )]
+    pub category: Option<String>,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = InfoOutputFormat::default(),
+        help = "Output format for the list of rules."
+    )]
+    pub output_format: InfoOutputFormat,
+}
+
+#[derive(Clone, Debug, Parser)]
+pub struct ConfigCommand {
+    #[command(subcommand)]
+    pub command: ConfigSubcommand,
+}
+
+#[derive(Clone, Debug, Subcommand)]
+pub enum ConfigSubcommand {
+    /// Print the resolved configuration for a file or directory
+    Show(ConfigShowCommand),
+}
+
+#[derive(Clone, Debug, Parser)]
+pub struct ConfigShowCommand {
+    #[arg(
+        default_value = ".",
+        help = "File or directory for which to show the configuration."
+    )]
+    pub path: String,
+    #[arg(
+        short,
+        long,
+        default_value = "",
+        help = "Names of rules to include, separated by a comma (no spaces). This also accepts names of groups of rules, such as \"PERF\"."
+    )]
+    pub select: String,
+    #[arg(
+        short,
+        long,
+        default_value = "",
+        help = "Like `--select` but adds additional rules in addition to those already specified."
+    )]
+    pub extend_select: String,
+    #[arg(
+        short,
+        long,
+        default_value = "",
+        help = "Names of rules to exclude, separated by a comma (no spaces). This also accepts names of groups of rules, such as \"PERF\"."
+    )]
+    pub ignore: String,
+    #[arg(
+        short,
+        long,
+        help = "The mimimum R version to be used by the linter. Some rules only work starting from a specific version."
+    )]
+    pub min_r_version: Option<String>,
+    #[arg(
+        long,
+        default_value = "false",
+        help = "Enable preview rules and behaviors, which are not stable yet."
+    )]
+    pub preview: bool,
+    #[arg(
+        long,
+        value_name = "CONFIG_OPTION",
+        help = "Path to a configuration file to use instead of the discovered `jarl.toml`, or a TOML option overriding the configuration, for example `lint.quotes.quote = \"single\"`. Can be repeated."
+    )]
+    pub config: Vec<String>,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = InfoOutputFormat::default(),
+        help = "Output format for the configuration."
+    )]
+    pub output_format: InfoOutputFormat,
+}
+
+#[derive(Clone, Debug, Parser)]
+pub struct InitCommand {
+    #[arg(
+        default_value = ".",
+        help = "Root directory of the project. The `jarl.toml` is written in this directory."
+    )]
+    pub path: String,
+    #[arg(long, help = "Overwrite the configuration file if it already exists.")]
+    pub force: bool,
 }
+
+#[derive(Clone, Debug, Parser)]
+pub struct MigrateCommand {
+    #[command(subcommand)]
+    pub command: MigrateSubcommand,
+}
+
+#[derive(Clone, Debug, Subcommand)]
+pub enum MigrateSubcommand {
+    /// Convert the `.lintr` file of lintr to a `jarl.toml`
+    Lintr(MigrateLintrCommand),
+    /// Convert lintr's `# nolint` comments to `# jarl-ignore` comments
+    Nolint(MigrateNolintCommand),
+}
+
+#[derive(Clone, Debug, Parser)]
+pub struct MigrateLintrCommand {
+    #[arg(
+        default_value = ".",
+        help = "Directory containing the `.lintr` file. The `jarl.toml` is written in the same directory."
+    )]
+    pub path: String,
+    #[arg(long, help = "Overwrite the configuration file if it already exists.")]
+    pub force: bool,
+}
+
+#[derive(Clone, Debug, Parser)]
+pub struct MigrateNolintCommand {
+    #[arg(
+        default_value = ".",
+        help = "List of files or directories in which `# nolint` comments are converted."
+    )]
+    pub files: Vec<String>,
+    #[arg(
+        long,
+        help = "Rewrite the files. Without this, only report the comments that would be converted."
+    )]
+    pub fix: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum InfoOutputFormat {
+    /// Print as human-readable text
+    #[default]
+    Text,
+    /// Print as JSON
+    Json,
+}
+
+#[derive(Clone, Debug, Parser)]
+pub(crate) struct CleanCommand {}
+
 #[derive(Clone, Debug, Parser)]
 pub(crate) struct ServerCommand {}
 
@@ -0,0 +1,214 @@
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use air_fs::relativize_path;
+use anyhow::{Context, Result, anyhow};
+use jarl_core::diagnostic::Diagnostic;
+use jarl_core::hash::fnv1a_hash;
+use serde::{Deserialize, Serialize};
+
+const BASELINE_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct BaselineFile {
+    version: u32,
+    violations: Vec<BaselineEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BaselineEntry {
+    file: String,
+    rule: String,
+    fingerprint: String,
+    count: usize,
+}
+
+/// A violation is identified by its file (relative to the baseline), its rule,
+/// and a fingerprint of the code that triggered it.
+type Key = (String, String, String);
+
+/// Violations that existed when the baseline was generated and that are not
+/// reported anymore.
+///
+/// Violations are not identified by their position so that adding or removing
+/// lines in a file doesn't make them reappear. Since the same code can trigger
+/// the same rule several times in a file, we store the number of occurrences
+/// of each violation.
+pub(crate) struct Baseline {
+    /// Directory of the baseline file. Files are stored relative to it so that
+    /// the baseline doesn't depend on the working directory.
+    directory: PathBuf,
+    counts: BTreeMap<Key, usize>,
+}
+
+impl Baseline {
+    fn new(path: &Path) -> Self {
+        let directory = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        Self {
+            directory: jarl_core::fs::normalize_path(directory),
+            counts: BTreeMap::new(),
+        }
+    }
+
+    /// Record all `diagnostics` in a baseline that will be written to `path`.
+    pub(crate) fn from_diagnostics(path: &Path, diagnostics: &[&Diagnostic]) -> Self {
+        let mut baseline = Self::new(path);
+        let mut sources: HashMap<&Path, String> = HashMap::new();
+
+        for diagnostic in diagnostics {
+            let source = sources
+                .entry(diagnostic.filename.as_path())
+                .or_insert_with(|| fs::read_to_string(&diagnostic.filename).unwrap_or_default());
+            let key = baseline.key(diagnostic, source);
+            *baseline.counts.entry(key).or_insert(0) += 1;
+        }
+
+        baseline
+    }
+
+    pub(crate) fn read(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path).map_err(|_| {
+            anyhow!(
+                "Failed to read the baseline file {path}.\n\
+                 Use `jarl check --generate-baseline {path}` to create it.",
+                path = relativize_path(path)
+            )
+        })?;
+        let file: BaselineFile = serde_json::from_str(&contents).with_context(|| {
+            format!(
+                "Failed to parse the baseline file {}.",
+                relativize_path(path)
+            )
+        })?;
+
+        if file.version != BASELINE_VERSION {
+            return Err(anyhow!(
+                "Unsupported version {} of the baseline file {}. Expected version {}.",
+                file.version,
+                relativize_path(path),
+                BASELINE_VERSION
+            ));
+        }
+
+        let mut baseline = Self::new(path);
+        for entry in file.violations {
+            *baseline
+                .counts
+                .entry((entry.file, entry.rule, entry.fingerprint))
+                .or_insert(0) += entry.count;
+        }
+        Ok(baseline)
+    }
+
+    pub(crate) fn write(&self, path: &Path) -> Result<()> {
+        let file = BaselineFile {
+            version: BASELINE_VERSION,
+            violations: self
+                .counts
+                .iter()
+                .map(|((file, rule, fingerprint), count)| BaselineEntry {
+                    file: file.clone(),
+                    rule: rule.clone(),
+                    fingerprint: fingerprint.clone(),
+                    count: *count,
+                })
+                .collect(),
+        };
+
+        let mut contents = serde_json::to_string_pretty(&file)?;
+        contents.push('\n');
+        fs::write(path, contents)
+            .with_context(|| format!("Failed to write the baseline file {}.", path.display()))
+    }
+
+    /// Total number of violations in the baseline.
+    pub(crate) fn n_violations(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Remove the `diagnostics` of a single file that are recorded in the
+    /// baseline. Returns the number of removed diagnostics.
+    fn filter(&mut self, diagnostics: &mut Vec<Diagnostic>) -> usize {
+        // When there are more occurrences of a violation than in the baseline,
+        // the last ones are considered new.
+        diagnostics.sort();
+
+        let Some(first) = diagnostics.first() else {
+            return 0;
+        };
+        let source = fs::read_to_string(&first.filename).unwrap_or_default();
+
+        let mut keys = diagnostics
+            .iter()
+            .map(|diagnostic| self.key(diagnostic, &source))
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        let before = diagnostics.len();
+        diagnostics.retain(|_| {
+            let key = keys.next().unwrap();
+            match self.counts.get_mut(&key) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    false
+                }
+                _ => true,
+            }
+        });
+        before - diagnostics.len()
+    }
+
+    fn key(&self, diagnostic: &Diagnostic, source: &str) -> Key {
+        let path = jarl_core::fs::normalize_path(&diagnostic.filename);
+        let file = path
+            .strip_prefix(&self.directory)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        // Whitespace is normalized so that reformatting the code doesn't
+        // change the fingerprint.
+        let start: usize = diagnostic.range.start().into();
+        let end: usize = diagnostic.range.end().into();
+        let snippet = source.get(start..end).unwrap_or_default();
+        let normalized = snippet.split_whitespace().collect::<Vec<_>>().join(" ");
+        let fingerprint = fnv1a_hash(&[&diagnostic.message.name, &normalized]);
+
+        (
+            file,
+            diagnostic.message.name.clone(),
+            format!("{:016x}", fingerprint),
+        )
+    }
+}
+
+/// Remove the diagnostics recorded in the baseline that applies to each file.
+/// `baseline_paths` maps a file to the baseline set in its `jarl.toml`.
+///
+/// Returns the number of removed diagnostics.
+pub(crate) fn apply_baselines(
+    all_diagnostics: &mut Vec<(String, Vec<Diagnostic>)>,
+    baseline_paths: &HashMap<String, PathBuf>,
+) -> Result<usize> {
+    let mut baselines: HashMap<&Path, Baseline> = HashMap::new();
+    let mut n_hidden = 0;
+
+    for (path, diagnostics) in all_diagnostics.iter_mut() {
+        let Some(baseline_path) = baseline_paths.get(path) else {
+            continue;
+        };
+        let baseline = match baselines.entry(baseline_path.as_path()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(Baseline::read(baseline_path)?),
+        };
+        n_hidden += baseline.filter(diagnostics);
+    }
+
+    all_diagnostics.retain(|(_path, diagnostics)| !diagnostics.is_empty());
+    Ok(n_hidden)
+}
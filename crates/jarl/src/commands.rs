@@ -1,2 +1,7 @@
 pub(crate) mod check;
+pub(crate) mod clean;
+pub(crate) mod config;
+pub(crate) mod init;
+pub(crate) mod migrate;
+pub(crate) mod rule;
 pub(crate) mod server;
@@ -1,10 +1,14 @@
+use air_fs::relativize_path;
 use air_workspace::resolve::PathResolver;
-use jarl_core::discovery::{discover_r_file_paths, discover_settings};
+use jarl_core::discovery::{ConfigArguments, discover_r_file_paths, discover_settings_with_args};
 use jarl_core::library_paths::is_r_available;
 use jarl_core::package_cache::{PackageCache, any_file_references_packages, find_r_project_root};
-use jarl_core::rule_set::Rule;
+use jarl_core::renv::LOCKFILE_NAME;
+use jarl_core::rule_set::{Rule, Severity};
 use jarl_core::{
+    cache::{CACHE_DIR_NAME, LintCache},
     config::ArgsConfig,
+    config::Config,
     config::build_config,
     diagnostic::Diagnostic,
     fs::has_rmd_extension,
@@ -12,6 +16,7 @@ use jarl_core::{
     suppression_edit::{
         create_suppression_edit, create_suppression_edit_in_rmd, format_suppression_comments,
     },
+    vcs::changed_lines,
 };
 
 use anyhow::Result;
@@ -20,16 +25,22 @@ use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::env;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Instant;
 
 use crate::args::CheckCommand;
+use crate::baseline::{Baseline, apply_baselines};
+use crate::diff::unified_diff;
 use crate::output_format::{self, GithubEmitter, print_notes, print_summary, print_warnings};
 use crate::statistics::print_statistics;
 use crate::status::ExitStatus;
 
-use output_format::{ConciseEmitter, Emitter, FullEmitter, JsonEmitter, OutputFormat};
+use output_format::{
+    ConciseEmitter, Emitter, FullEmitter, GitlabEmitter, JsonEmitter, JunitEmitter, OutputFormat,
+    RdjsonEmitter, SarifEmitter, StdinSource,
+};
 
 pub fn check(args: CheckCommand) -> Result<ExitStatus> {
     let start = if args.with_timing {
@@ -38,6 +49,17 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
         None
     };
 
+    let fail_on = args.fail_on;
+
+    if let Some(stdin_filename) = &args.stdin_filename {
+        return check_stdin(&args, stdin_filename, fail_on);
+    }
+    if args.files.iter().any(|file| file == "-") {
+        return Err(anyhow::anyhow!(
+            "Reading from stdin with `-` requires `--stdin-filename`."
+        ));
+    }
+
     let mut resolver = PathResolver::new(Settings::default());
 
     // Track if we're using a config from a parent directory
@@ -48,7 +70,7 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
     // override each discovered settings' `default_exclude` to `false` so the
     // default patterns from `DEFAULT_EXCLUDE_PATTERNS` are not applied during
     // discovery.
-    let discovered = discover_settings(&args.files)?;
+    let discovered = discover_settings_with_args(&args.files, &config_arguments(&args.config)?)?;
     let single_config = discovered.len() == 1;
 
     for mut ds in discovered {
@@ -82,19 +104,7 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
         return Ok(ExitStatus::Success);
     }
 
-    let check_config = ArgsConfig {
-        files: args.files.iter().map(|s| s.into()).collect(),
-        fix: args.fix,
-        unsafe_fixes: args.unsafe_fixes,
-        fix_only: args.fix_only,
-        select: args.select.clone(),
-        extend_select: args.extend_select.clone(),
-        ignore: args.ignore.clone(),
-        min_r_version: args.min_r_version.clone(),
-        allow_dirty: args.allow_dirty,
-        allow_no_vcs: args.allow_no_vcs,
-        assignment: args.assignment.clone(),
-    };
+    let check_config = args_config(&args);
 
     // Group paths by their closest resolved config directory, so each file is
     // checked with the settings from the nearest jarl.toml.
@@ -106,48 +116,54 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
         groups.entry(key).or_default().push(path);
     }
 
-    // Track whether we've already verified R is available (avoid repeated checks).
-    let mut r_available_checked = false;
+    // Whether R is available, only checked when needed (avoid repeated checks).
+    let mut r_available: Option<bool> = None;
     // Cache of project root - PackageCache to avoid duplicate Rscript calls.
     let mut root_caches: HashMap<Option<PathBuf>, Option<Arc<PackageCache>>> = HashMap::new();
 
-    let mut file_results = Vec::new();
+    let mut configs = Vec::new();
+    // Baseline file that applies to each file, if any.
+    let mut baseline_paths: HashMap<String, PathBuf> = HashMap::new();
+    // Preview rules that were already reported as ignored.
+    let mut ignored_preview_rules: BTreeSet<String> = BTreeSet::new();
     for (dir_key, group_paths) in groups {
         let settings = dir_key
             .as_deref()
             .and_then(|dir| resolver.items().iter().find(|item| item.path() == dir))
             .map(|item| item.value());
 
+        if let Some(baseline) = settings.and_then(|s| s.linter.baseline.as_ref()) {
+            for path in &group_paths {
+                baseline_paths.insert(relativize_path(path), baseline.clone());
+            }
+        }
+
         let config = build_config(&check_config, settings, group_paths.clone())?;
 
-        if !config.rules_to_apply.has_package_specific_rules() {
-            file_results.extend(jarl_core::check::check(config));
-            continue;
+        for rule in &config.ignored_preview_rules {
+            if ignored_preview_rules.insert(rule.clone()) {
+                eprintln!(
+                    "{}: Rule `{rule}` is in preview and is ignored. Use `--preview` or \
+                     `preview = true` to enable it.",
+                    "Warning".yellow().bold()
+                );
+            }
         }
 
-        // Package-specific rules are enabled — need per-project-root caches.
-        if !r_available_checked {
-            if !is_r_available() {
-                let pkg_categories: Vec<_> = config
-                    .rules_to_apply
-                    .package_specific_categories()
-                    .into_iter()
-                    .map(|c| c.as_str())
-                    .collect();
-                return Err(anyhow::anyhow!(
-                    "Package-specific rules are enabled ({}) but R is not available.\n\n\
-                     These rules require R and installed packages to resolve function origins.\n\n\
-                     If running in CI with `setup-jarl`, uncomment (or add yourself) the R setup steps in your workflow:\n\n\
-                     \x20 - uses: r-lib/actions/setup-r@v2\n\
-                     \x20 - uses: r-lib/actions/setup-r-dependencies@v2\n\n\
-                     You can also disable these rules instead.",
-                    pkg_categories.join(", "),
-                ));
-            }
-            r_available_checked = true;
+        // Rules enabled only in some files with overrides count too.
+        let all_rules = config.all_rules_to_apply();
+        if !all_rules.has_package_specific_rules() {
+            configs.push(config);
+            continue;
         }
 
-        let r_pkg_names = config.rules_to_apply.pkg_names_from_category();
+        // Package-specific rules are enabled — need per-project-root caches.
+        let pkg_categories: Vec<_> = all_rules
+            .package_specific_categories()
+            .into_iter()
+            .map(|c| c.as_str())
+            .collect();
+        let r_pkg_names = all_rules.pkg_names_from_category();
         drop(config);
 
         // Skip the expensive Rscript call if no file in this group actually
@@ -156,10 +172,8 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
         // without a PackageCache.
         if !any_file_references_packages(&group_paths, &r_pkg_names) {
             let mut config = build_config(&check_config, settings, group_paths)?;
-            config.rules_to_apply = config
-                .rules_to_apply
-                .filter(|r| !r.categories().iter().any(|c| c.is_package_specific()));
-            file_results.extend(jarl_core::check::check(config));
+            config.remove_package_specific_rules();
+            configs.push(config);
             continue;
         }
 
@@ -181,13 +195,55 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
                 })
                 .clone();
 
+            // Without R, the metadata is read from the files of the installed
+            // packages. If none of them were found, these rules can't work.
+            if cache.is_none() && !*r_available.get_or_insert_with(is_r_available) {
+                return Err(anyhow::anyhow!(
+                    "Package-specific rules are enabled ({}) but R is not available and the packages they \
+                     need are not installed in any R library.\n\n\
+                     These rules require installed packages to resolve function origins.\n\n\
+                     If running in CI with `setup-jarl`, uncomment (or add yourself) the R setup steps in your workflow:\n\n\
+                     \x20 - uses: r-lib/actions/setup-r@v2\n\
+                     \x20 - uses: r-lib/actions/setup-r-dependencies@v2\n\n\
+                     You can also disable these rules instead.",
+                    pkg_categories.join(", "),
+                ));
+            }
+
             config.package_cache = cache;
-            file_results.extend(jarl_core::check::check(config));
+            configs.push(config);
         }
     }
 
+    let lockfile_warnings = lockfile_warnings(&root_caches);
+
+    if args.diff {
+        return print_diffs(configs);
+    }
+
+    // Results of files that didn't change since the last run are reused.
+    let lint_cache = match &cwd {
+        Some(dir) if !args.no_cache => Some(Arc::new(LintCache::load(&dir.join(CACHE_DIR_NAME)))),
+        _ => None,
+    };
+    for config in &mut configs {
+        config.lint_cache = lint_cache.clone();
+    }
+
+    let file_results: Vec<_> = configs
+        .into_iter()
+        .flat_map(jarl_core::check::check)
+        .collect();
+
+    if let Some(cache) = &lint_cache
+        && let Err(err) = cache.save()
+    {
+        tracing::warn!("Failed to save the cache of lint results: {err:?}");
+    }
+
     let mut all_errors = Vec::new();
     let mut all_diagnostics = Vec::new();
+    let checked_files: Vec<String> = file_results.iter().map(|(path, _)| path.clone()).collect();
 
     for (path, result) in file_results {
         match result {
@@ -202,6 +258,18 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
         }
     }
 
+    // Handle --generate-baseline: record all violations, including those that
+    // are already in an existing baseline.
+    if let Some(baseline_path) = &args.generate_baseline {
+        return generate_baseline(baseline_path, &all_diagnostics, &all_errors);
+    }
+
+    let n_baselined = apply_baselines(&mut all_diagnostics, &baseline_paths)?;
+
+    if let Some(base) = &args.diff_base {
+        keep_changed_lines(&mut all_diagnostics, &checked_files, base)?;
+    }
+
     // Handle --add-jarl-ignore: insert suppression comments for all diagnostics
     if let Some(reason) = &args.add_jarl_ignore {
         return add_jarl_ignore_comments(&all_diagnostics, reason, parent_config_path);
@@ -219,28 +287,21 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
     all_diagnostics_flat.sort();
 
     if args.statistics {
-        return print_statistics(&all_diagnostics_flat, parent_config_path);
+        return print_statistics(&all_diagnostics_flat, parent_config_path, fail_on);
     }
 
     let mut stdout = std::io::stdout();
-
-    match args.output_format {
-        OutputFormat::Concise => {
-            ConciseEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
-        }
-        OutputFormat::Json => {
-            JsonEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
-        }
-        OutputFormat::Github => {
-            GithubEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
-        }
-        OutputFormat::Full => {
-            FullEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
-        }
-    }
+    emit_diagnostics(
+        &mut stdout,
+        args.output_format,
+        &all_diagnostics_flat,
+        &all_errors,
+        &checked_files,
+        None,
+    )?;
 
     // For human-readable formats, print sections (summary, warnings, notes).
-    // Skip for JSON/GitHub to avoid corrupting structured output.
+    // Skip for structured formats (JSON, GitHub, SARIF, etc.) to avoid corrupting structured output.
     let is_human_format = matches!(
         args.output_format,
         OutputFormat::Full | OutputFormat::Concise
@@ -312,6 +373,8 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
             }
         }
 
+        warnings.extend(lockfile_warnings);
+
         print_warnings(&warnings);
 
         // ── Notes ──
@@ -326,6 +389,13 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
             notes.push(format!("Used '{}'", config_path.display()));
         }
 
+        if n_baselined > 0 {
+            notes.push(format!(
+                "{n_baselined} violation{} hidden by the baseline.",
+                if n_baselined == 1 { " was" } else { "s were" }
+            ));
+        }
+
         print_notes(&notes);
     }
 
@@ -333,13 +403,290 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
         return Ok(ExitStatus::Error);
     }
 
-    if all_diagnostics.is_empty() {
+    // Only violations at or above `--fail-on` make the command fail, lower
+    // severities are reported but don't change the exit status.
+    if !all_diagnostics_flat.iter().any(|d| d.severity >= fail_on) {
         return Ok(ExitStatus::Success);
     }
 
     Ok(ExitStatus::Failure)
 }
 
+fn args_config(args: &CheckCommand) -> ArgsConfig {
+    ArgsConfig {
+        files: args.files.iter().map(|s| s.into()).collect(),
+        fix: args.fix || args.diff,
+        unsafe_fixes: args.unsafe_fixes,
+        fix_only: args.fix_only,
+        select: args.select.clone(),
+        extend_select: args.extend_select.clone(),
+        ignore: args.ignore.clone(),
+        min_r_version: args.min_r_version.clone(),
+        allow_dirty: args.allow_dirty,
+        allow_no_vcs: args.allow_no_vcs,
+        assignment: args.assignment.clone(),
+        preview: args.preview,
+    }
+}
+
+/// Split the values of `--config` into a configuration file and inline TOML
+/// options.
+pub(crate) fn config_arguments(values: &[String]) -> Result<ConfigArguments> {
+    let mut config_arguments = ConfigArguments::default();
+
+    for value in values {
+        let path = Path::new(value);
+        if path.is_file() {
+            if config_arguments.config_file.is_some() {
+                return Err(anyhow::anyhow!(
+                    "Only one configuration file can be passed with `--config`."
+                ));
+            }
+            config_arguments.config_file = Some(path.to_path_buf());
+        } else if path.extension().is_some_and(|ext| ext == "toml") {
+            return Err(anyhow::anyhow!(
+                "The configuration file {value} passed with `--config` doesn't exist."
+            ));
+        } else {
+            config_arguments
+                .overrides
+                .push(jarl_core::toml::parse_inline_toml(value)?);
+        }
+    }
+
+    Ok(config_arguments)
+}
+
+fn emit_diagnostics<W: Write>(
+    writer: &mut W,
+    output_format: OutputFormat,
+    diagnostics: &[&Diagnostic],
+    errors: &[(String, anyhow::Error)],
+    checked_files: &[String],
+    stdin_source: Option<StdinSource>,
+) -> Result<()> {
+    match output_format {
+        OutputFormat::Concise => ConciseEmitter.emit(writer, diagnostics, errors),
+        OutputFormat::Json => JsonEmitter.emit(writer, diagnostics, errors),
+        OutputFormat::Github => GithubEmitter.emit(writer, diagnostics, errors),
+        OutputFormat::Full => FullEmitter { stdin_source }.emit(writer, diagnostics, errors),
+        OutputFormat::Sarif => SarifEmitter.emit(writer, diagnostics, errors),
+        OutputFormat::Gitlab => GitlabEmitter { stdin_source }.emit(writer, diagnostics, errors),
+        OutputFormat::Rdjson => RdjsonEmitter { stdin_source }.emit(writer, diagnostics, errors),
+        OutputFormat::Junit => JunitEmitter { checked_files }.emit(writer, diagnostics, errors),
+    }
+}
+
+/// Print the diff of the fixes of all files without modifying them. Returns a
+/// failure if any file would be changed.
+fn print_diffs(configs: Vec<Config>) -> Result<ExitStatus> {
+    let mut results: Vec<(String, Result<(String, String)>)> = configs
+        .into_iter()
+        .flat_map(jarl_core::check::preview_fixes)
+        .collect();
+    results.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut stdout = std::io::stdout().lock();
+    let mut errors = Vec::new();
+    let mut n_changed = 0;
+
+    for (path, result) in results {
+        match result {
+            Ok((original, fixed)) => {
+                if original != fixed {
+                    n_changed += 1;
+                    write!(stdout, "{}", unified_diff(&path, &original, &fixed))?;
+                }
+            }
+            Err(e) => errors.push((path, e)),
+        }
+    }
+
+    output_format::print_errors_to_stderr(&errors);
+
+    if !errors.is_empty() {
+        return Ok(ExitStatus::Error);
+    }
+
+    if n_changed > 0 {
+        eprintln!(
+            "{} file{} would be fixed.",
+            n_changed,
+            if n_changed == 1 { "" } else { "s" }
+        );
+        return Ok(ExitStatus::Failure);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Lint source code read from stdin as if it was the content of
+/// `stdin_filename`. With `--fix`, the fixed code is printed to stdout and the
+/// remaining violations are reported on stderr.
+fn check_stdin(args: &CheckCommand, stdin_filename: &str, fail_on: Severity) -> Result<ExitStatus> {
+    if args.files != ["-"] {
+        return Err(anyhow::anyhow!(
+            "`--stdin-filename` requires `-` as the only file to check."
+        ));
+    }
+
+    let contents = std::io::read_to_string(std::io::stdin())?;
+    let path = PathBuf::from(stdin_filename);
+
+    // Use the closest `jarl.toml` above the path the code is linted as.
+    let normalized = jarl_core::fs::normalize_path(&path);
+    let discovered = discover_settings_with_args(&[&normalized], &config_arguments(&args.config)?)?
+        .into_iter()
+        .filter(|ds| normalized.starts_with(&ds.directory))
+        .max_by_key(|ds| ds.directory.components().count());
+    let settings = discovered.map(|ds| ds.settings);
+
+    let mut config = build_config(&args_config(args), settings.as_ref(), vec![path.clone()])?;
+
+    // Package-specific rules need a `PackageCache`, which requires R or the
+    // installed packages. Since this is typically used by editor integrations,
+    // we don't fail when neither is available but skip those rules instead.
+    let all_rules = config.all_rules_to_apply();
+    if all_rules.has_package_specific_rules() {
+        let r_pkg_names = all_rules.pkg_names_from_category();
+        let root = find_r_project_root(&normalized);
+        config.package_cache =
+            PackageCache::from_rscript(&r_pkg_names, root.as_deref()).map(Arc::new);
+
+        if config.package_cache.is_none() && !is_r_available() {
+            config.remove_package_specific_rules();
+        }
+    }
+
+    let apply_fixes = config.apply_fixes || config.apply_unsafe_fixes;
+    let result = jarl_core::check::check_source(config, contents.clone());
+    let (diagnostics, errors, source) = match result {
+        Ok((diagnostics, fixed)) => {
+            if apply_fixes {
+                print!("{fixed}");
+            }
+            (diagnostics, Vec::new(), fixed)
+        }
+        Err(e) => {
+            // Don't lose the code that was piped in.
+            if apply_fixes {
+                print!("{contents}");
+            }
+            (Vec::new(), vec![(stdin_filename.to_string(), e)], contents)
+        }
+    };
+
+    let mut diagnostics: Vec<&Diagnostic> = diagnostics.iter().collect();
+    diagnostics.sort();
+
+    // Emitters that display snippets can't read the code from disk.
+    let source_path = PathBuf::from(relativize_path(&path));
+    let stdin_source = Some(StdinSource { path: &source_path, contents: &source });
+
+    if !args.fix_only {
+        let checked_files = [stdin_filename.to_string()];
+        if apply_fixes {
+            let mut stderr = std::io::stderr();
+            emit_diagnostics(
+                &mut stderr,
+                args.output_format,
+                &diagnostics,
+                &errors,
+                &checked_files,
+                stdin_source,
+            )?;
+        } else {
+            let mut stdout = std::io::stdout();
+            emit_diagnostics(
+                &mut stdout,
+                args.output_format,
+                &diagnostics,
+                &errors,
+                &checked_files,
+                stdin_source,
+            )?;
+        }
+    }
+
+    if !errors.is_empty() {
+        return Ok(ExitStatus::Error);
+    }
+
+    if !diagnostics.iter().any(|d| d.severity >= fail_on) {
+        return Ok(ExitStatus::Success);
+    }
+
+    Ok(ExitStatus::Failure)
+}
+
+/// Only keep the diagnostics that are on lines added or modified compared to
+/// the Git reference `base`. Files are still checked entirely so that
+/// cross-file analyses see the whole package.
+fn keep_changed_lines(
+    all_diagnostics: &mut Vec<(String, Vec<Diagnostic>)>,
+    checked_files: &[String],
+    base: &str,
+) -> Result<()> {
+    let changed = changed_lines(checked_files, base)?;
+
+    for (path, diagnostics) in all_diagnostics.iter_mut() {
+        let Some(lines) = changed.get(Path::new(path)) else {
+            diagnostics.clear();
+            continue;
+        };
+        let source = std::fs::read_to_string(path).unwrap_or_default();
+
+        diagnostics.retain(|diagnostic| {
+            let Some(location) = diagnostic.location else {
+                return true;
+            };
+            // A violation can span several lines, it is kept if any of them
+            // changed.
+            let start: usize = diagnostic.range.start().into();
+            let end: usize = diagnostic.range.end().into();
+            let n_lines = source
+                .get(start..end)
+                .map_or(0, |snippet| snippet.matches('\n').count());
+            lines.intersects(location.row(), location.row() + n_lines)
+        });
+    }
+
+    all_diagnostics.retain(|(_path, diagnostics)| !diagnostics.is_empty());
+    Ok(())
+}
+
+/// Record all diagnostics in a baseline file.
+fn generate_baseline(
+    path: &Path,
+    all_diagnostics: &[(String, Vec<Diagnostic>)],
+    errors: &[(String, anyhow::Error)],
+) -> Result<ExitStatus> {
+    // A file that can't be parsed would have no entries in the baseline, so
+    // all its violations would be reported once it's fixed.
+    if !errors.is_empty() {
+        output_format::print_errors_to_stderr(errors);
+        return Ok(ExitStatus::Error);
+    }
+
+    let mut diagnostics: Vec<&Diagnostic> = all_diagnostics
+        .iter()
+        .flat_map(|(_path, diagnostics)| diagnostics.iter())
+        .collect();
+    diagnostics.sort();
+
+    let baseline = Baseline::from_diagnostics(path, &diagnostics);
+    baseline.write(path)?;
+
+    println!(
+        "{}: Recorded {} violation(s) in {}.",
+        "Info".cyan().bold(),
+        baseline.n_violations(),
+        path.display()
+    );
+
+    Ok(ExitStatus::Success)
+}
+
 /// Insert `# jarl-ignore` comments for all diagnostics in the given files.
 fn add_jarl_ignore_comments(
     all_diagnostics: &[(String, Vec<Diagnostic>)],
@@ -551,3 +898,41 @@ fn hide_unused_function_if_needed(
 
     (hidden, unused_fn_count)
 }
+
+/// Warn about packages whose installed version differs from the one recorded
+/// in `renv.lock`, since package-specific rules use the installed version.
+fn lockfile_warnings(
+    root_caches: &HashMap<Option<PathBuf>, Option<Arc<PackageCache>>>,
+) -> Vec<String> {
+    let format_version =
+        |(major, minor, patch): (u32, u32, u32)| format!("{major}.{minor}.{patch}");
+
+    let mut warnings: Vec<String> = root_caches
+        .iter()
+        .filter_map(|(root, cache)| {
+            let mismatches = cache.as_ref()?.lockfile_mismatches();
+            if mismatches.is_empty() {
+                return None;
+            }
+            let details = mismatches
+                .into_iter()
+                .map(|(name, installed, locked)| {
+                    format!(
+                        "  - {name}: {} is installed but renv.lock has {}.",
+                        format_version(installed),
+                        format_version(locked)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            Some(format!(
+                "Installed packages don't match {}:\n{details}\n\
+                 Run `renv::restore()` to install the versions of the lockfile.",
+                relativize_path(root.as_ref()?.join(LOCKFILE_NAME))
+            ))
+        })
+        .collect();
+
+    warnings.sort();
+    warnings
+}
@@ -0,0 +1,20 @@
+use std::env;
+
+use colored::Colorize;
+use jarl_core::cache::{CACHE_DIR_NAME, clean_cache};
+
+use crate::args::CleanCommand;
+use crate::status::ExitStatus;
+
+pub(crate) fn clean(_command: CleanCommand) -> anyhow::Result<ExitStatus> {
+    let cwd = env::current_dir()?;
+
+    let message = if clean_cache(&cwd)? {
+        format!("Removed {CACHE_DIR_NAME}.")
+    } else {
+        "No cache to remove.".to_string()
+    };
+    println!("{}: {}", "Info".cyan().bold(), message);
+
+    Ok(ExitStatus::Success)
+}
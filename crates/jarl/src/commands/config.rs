@@ -0,0 +1,288 @@
+use std::io::Write;
+use std::path::PathBuf;
+
+use air_fs::relativize_path;
+use jarl_core::config::{ArgsConfig, Config, build_config};
+use jarl_core::discovery::{
+    DiscoveredSettings, discover_settings_with_args, find_config_in_directory, get_user_config_dir,
+};
+use jarl_core::rule_options::pipe_consistency::PreferredPipe;
+use jarl_core::rule_options::quotes::PreferredQuote;
+use jarl_core::settings::Settings;
+
+use crate::args::{ConfigCommand, ConfigShowCommand, ConfigSubcommand, InfoOutputFormat};
+use crate::commands::check::config_arguments;
+use crate::status::ExitStatus;
+
+pub(crate) fn config(command: ConfigCommand) -> anyhow::Result<ExitStatus> {
+    match command.command {
+        ConfigSubcommand::Show(command) => show(command),
+    }
+}
+
+fn show(command: ConfigShowCommand) -> anyhow::Result<ExitStatus> {
+    let path = jarl_core::fs::normalize_path(&command.path);
+    if !path.exists() {
+        return Err(anyhow::anyhow!("Path \"{}\" does not exist.", command.path));
+    }
+
+    // `discover_settings()` also returns the configs of nested directories
+    // when `path` is a directory, so we only keep the closest config that
+    // applies to `path` itself.
+    let config_arguments = config_arguments(&command.config)?;
+    let discovered = discover_settings_with_args(&[&path], &config_arguments)?
+        .into_iter()
+        .filter(|ds| path.starts_with(&ds.directory))
+        .max_by_key(|ds| ds.directory.components().count());
+
+    let args_config = ArgsConfig {
+        files: vec![path.clone()],
+        fix: false,
+        unsafe_fixes: false,
+        fix_only: false,
+        select: command.select.clone(),
+        extend_select: command.extend_select.clone(),
+        ignore: command.ignore.clone(),
+        min_r_version: command.min_r_version.clone(),
+        allow_dirty: false,
+        allow_no_vcs: false,
+        assignment: None,
+        preview: command.preview,
+    };
+
+    let settings = discovered.as_ref().map(|ds| &ds.settings);
+    let config = build_config(&args_config, settings, vec![path])?;
+
+    let output = resolved_config_to_json(
+        discovered.as_ref(),
+        &config,
+        config_arguments.config_file.is_some(),
+    );
+
+    let mut stdout = std::io::stdout().lock();
+    match command.output_format {
+        InfoOutputFormat::Text => print_text(&mut stdout, &output)?,
+        InfoOutputFormat::Json => {
+            serde_json::to_writer_pretty(&mut stdout, &output)?;
+            writeln!(stdout)?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Where a `jarl.toml` was found, relative to the path being inspected.
+fn config_source(ds: &DiscoveredSettings, from_config_option: bool) -> &'static str {
+    if from_config_option {
+        return "passed with `--config`";
+    }
+    if get_user_config_dir().is_some_and(|dir| dir == ds.directory) {
+        return "user configuration directory";
+    }
+
+    // A config is "nested" if it takes precedence over another config in one
+    // of its parent directories.
+    let has_parent_config = ds
+        .directory
+        .ancestors()
+        .skip(1)
+        .any(|dir| find_config_in_directory(dir).is_some());
+
+    if has_parent_config {
+        "nested"
+    } else {
+        "project"
+    }
+}
+
+fn sorted<'a>(values: impl IntoIterator<Item = &'a String>) -> Vec<&'a str> {
+    let mut values: Vec<&str> = values.into_iter().map(|v| v.as_str()).collect();
+    values.sort_unstable();
+    values
+}
+
+fn resolved_config_to_json(
+    discovered: Option<&DiscoveredSettings>,
+    config: &Config,
+    from_config_option: bool,
+) -> serde_json::Value {
+    let linter = discovered
+        .map(|ds| &ds.settings)
+        .unwrap_or(&Settings::default())
+        .linter
+        .clone();
+
+    let config_file = discovered.and_then(|ds| {
+        ds.config_path.as_ref().map(|path| {
+            serde_json::json!({
+                "path": relativize_path(path),
+                "source": config_source(ds, from_config_option),
+            })
+        })
+    });
+
+    // Rules and options of `[[lint.overrides]]` are shown if they match the
+    // inspected path.
+    let file = config
+        .paths
+        .first()
+        .map(|path| PathBuf::from(relativize_path(path)))
+        .unwrap_or_default();
+
+    let mut rules: Vec<&str> = config.rules_for(&file).iter().map(|r| r.name()).collect();
+    rules.sort_unstable();
+
+    let mut severity: Vec<(&String, String)> = config
+        .severity
+        .iter()
+        .map(|(rule, severity)| (rule, severity.to_string()))
+        .collect();
+    severity.sort();
+
+    let options = config.rule_options_for(&file);
+    let rule_options = serde_json::json!({
+        "assignment": {
+            "operator": options.assignment.operator_as_str(),
+        },
+        "duplicated_arguments": {
+            "skipped-functions": sorted(&options.duplicated_arguments.skipped_functions),
+        },
+        "implicit_assignment": {
+            "skipped-functions": sorted(&options.implicit_assignment.skipped_functions),
+        },
+        "pipe_consistency": {
+            "pipe": match options.pipe_consistency.pipe {
+                PreferredPipe::Base => "|>",
+                PreferredPipe::Magrittr => "%>%",
+            },
+        },
+        "quotes": {
+            "quote": match options.quotes.preferred_delimiter {
+                PreferredQuote::Double => "double",
+                PreferredQuote::Single => "single",
+            },
+        },
+        "undesirable_function": {
+            "functions": sorted(&options.undesirable_function.functions),
+        },
+        "unreachable_code": {
+            "stopping-functions": sorted(&options.unreachable_code.stopping_functions),
+        },
+        "unused_function": {
+            "threshold-ignore": options.unused_function.threshold_ignore,
+            "skipped-functions": options
+                .unused_function
+                .skipped_functions
+                .iter()
+                .map(|re| re.as_str())
+                .collect::<Vec<_>>(),
+        },
+    });
+
+    serde_json::json!({
+        "config_file": config_file,
+        "min_r_version": config
+            .minimum_r_version
+            .map(|(major, minor, patch)| format!("{major}.{minor}.{patch}")),
+        "rules": rules,
+        "fixable": config.fixable.as_ref().map(sorted),
+        "unfixable": sorted(&config.unfixable),
+        "severity": severity
+            .into_iter()
+            .map(|(rule, level)| (rule.clone(), serde_json::Value::String(level)))
+            .collect::<serde_json::Map<_, _>>(),
+        "include": linter.include,
+        "exclude": linter.exclude,
+        "default_exclude": linter.default_exclude.unwrap_or(true),
+        "check_roxygen": config.check_roxygen,
+        "fix_roxygen": config.fix_roxygen,
+        "lintr_compat_suppressions": config.lintr_compat_suppressions,
+        "preview": config.preview,
+        "rule_options": rule_options,
+    })
+}
+
+/// Format a JSON value on a single line, e.g. a list of strings.
+fn inline(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "none".to_string(),
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(values) if values.is_empty() => "none".to_string(),
+        serde_json::Value::Array(values) => {
+            values.iter().map(inline).collect::<Vec<_>>().join(", ")
+        }
+        other => other.to_string(),
+    }
+}
+
+fn print_text<W: Write>(writer: &mut W, output: &serde_json::Value) -> anyhow::Result<()> {
+    match &output["config_file"] {
+        serde_json::Value::Null => writeln!(writer, "Configuration file: none (using defaults)")?,
+        file => writeln!(
+            writer,
+            "Configuration file: {} ({})",
+            inline(&file["path"]),
+            inline(&file["source"])
+        )?,
+    }
+    writeln!(
+        writer,
+        "Minimum R version: {}",
+        output["min_r_version"].as_str().unwrap_or("unknown")
+    )?;
+    writeln!(writer)?;
+
+    let rules = output["rules"].as_array().map(Vec::as_slice).unwrap_or(&[]);
+    writeln!(writer, "Rules ({}):", rules.len())?;
+    for rule in rules {
+        writeln!(writer, "  {}", inline(rule))?;
+    }
+    writeln!(writer)?;
+
+    let fixable = match &output["fixable"] {
+        serde_json::Value::Null => "all".to_string(),
+        fixable => inline(fixable),
+    };
+    writeln!(writer, "Fixable: {fixable}")?;
+    writeln!(writer, "Unfixable: {}", inline(&output["unfixable"]))?;
+
+    let severity = output["severity"].as_object().cloned().unwrap_or_default();
+    if severity.is_empty() {
+        writeln!(writer, "Severity overrides: none")?;
+    } else {
+        writeln!(writer, "Severity overrides:")?;
+        for (rule, level) in &severity {
+            writeln!(writer, "  {rule} = {}", inline(level))?;
+        }
+    }
+
+    writeln!(writer, "Include: {}", inline(&output["include"]))?;
+    writeln!(writer, "Exclude: {}", inline(&output["exclude"]))?;
+    writeln!(writer, "Default exclude: {}", output["default_exclude"])?;
+    writeln!(
+        writer,
+        "Check roxygen examples: {}",
+        output["check_roxygen"]
+    )?;
+    writeln!(writer, "Fix roxygen examples: {}", output["fix_roxygen"])?;
+    writeln!(
+        writer,
+        "Recognize `# nolint` comments: {}",
+        output["lintr_compat_suppressions"]
+    )?;
+    writeln!(writer, "Preview: {}", output["preview"])?;
+    writeln!(writer)?;
+
+    writeln!(writer, "Rule options:")?;
+    if let Some(rule_options) = output["rule_options"].as_object() {
+        for (rule, options) in rule_options {
+            if let Some(options) = options.as_object() {
+                for (option, value) in options {
+                    writeln!(writer, "  {rule}.{option} = {}", inline(value))?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
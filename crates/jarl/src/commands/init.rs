@@ -0,0 +1,57 @@
+use std::fs;
+
+use air_fs::relativize_path;
+use air_workspace::resolve::PathResolver;
+use anyhow::Context;
+use colored::Colorize;
+use jarl_core::discovery::discover_r_file_paths;
+use jarl_core::init::{detect_project, init_toml};
+use jarl_core::settings::Settings;
+use jarl_core::toml::find_jarl_toml_in_directory;
+
+use crate::args::InitCommand;
+use crate::status::ExitStatus;
+
+pub(crate) fn init(command: InitCommand) -> anyhow::Result<ExitStatus> {
+    let dir = jarl_core::fs::normalize_path(&command.path);
+    if !dir.is_dir() {
+        return Err(anyhow::anyhow!(
+            "{} is not a directory.",
+            relativize_path(&dir)
+        ));
+    }
+
+    // Overwrite the existing config, whether it is `jarl.toml` or
+    // `.jarl.toml`, so that it doesn't take precedence over the new one.
+    let toml_path = match find_jarl_toml_in_directory(&dir) {
+        Some(path) if !command.force => {
+            return Err(anyhow::anyhow!(
+                "{} already exists. Use `--force` to overwrite it.",
+                relativize_path(&path)
+            ));
+        }
+        Some(path) => path,
+        None => dir.join("jarl.toml"),
+    };
+
+    // The existing config is ignored since it is about to be replaced.
+    let resolver = PathResolver::new(Settings::default());
+    let paths: Vec<_> = discover_r_file_paths(&[&dir], &resolver, true, false)
+        .into_iter()
+        .filter_map(Result::ok)
+        .collect();
+
+    let info = detect_project(&dir, &paths);
+
+    fs::write(&toml_path, init_toml(&info))
+        .with_context(|| format!("Failed to write {}", toml_path.display()))?;
+
+    println!(
+        "{}: Wrote {} for {}.",
+        "Info".cyan().bold(),
+        relativize_path(&toml_path),
+        info.kind.description()
+    );
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,166 @@
+use std::ffi::OsStr;
+use std::fs;
+
+use air_fs::relativize_path;
+use air_workspace::resolve::PathResolver;
+use anyhow::Context;
+use colored::Colorize;
+use jarl_core::discovery::{discover_r_file_paths, discover_settings};
+use jarl_core::fs::is_r_extension;
+use jarl_core::lintr::{LINTR_FILE_NAME, migrate_lintr, migrate_nolint};
+use jarl_core::settings::Settings;
+use jarl_core::toml::find_jarl_toml_in_directory;
+
+use crate::args::{MigrateCommand, MigrateLintrCommand, MigrateNolintCommand, MigrateSubcommand};
+use crate::status::ExitStatus;
+
+pub(crate) fn migrate(command: MigrateCommand) -> anyhow::Result<ExitStatus> {
+    match command.command {
+        MigrateSubcommand::Lintr(command) => lintr(command),
+        MigrateSubcommand::Nolint(command) => nolint(command),
+    }
+}
+
+fn lintr(command: MigrateLintrCommand) -> anyhow::Result<ExitStatus> {
+    let dir = jarl_core::fs::normalize_path(&command.path);
+    let lintr_path = dir.join(LINTR_FILE_NAME);
+    if !lintr_path.is_file() {
+        return Err(anyhow::anyhow!(
+            "Could not find {}.",
+            relativize_path(&lintr_path)
+        ));
+    }
+
+    // Overwrite the existing config, whether it is `jarl.toml` or
+    // `.jarl.toml`, so that it doesn't take precedence over the new one.
+    let toml_path = match find_jarl_toml_in_directory(&dir) {
+        Some(path) if !command.force => {
+            return Err(anyhow::anyhow!(
+                "{} already exists. Use `--force` to overwrite it.",
+                relativize_path(&path)
+            ));
+        }
+        Some(path) => path,
+        None => dir.join("jarl.toml"),
+    };
+
+    let contents = fs::read_to_string(&lintr_path)
+        .with_context(|| format!("Failed to read {}", lintr_path.display()))?;
+    let migration = migrate_lintr(&contents, &dir)?;
+
+    fs::write(&toml_path, &migration.toml)
+        .with_context(|| format!("Failed to write {}", toml_path.display()))?;
+
+    println!(
+        "{}: Wrote {} from {}.",
+        "Info".cyan().bold(),
+        relativize_path(&toml_path),
+        relativize_path(&lintr_path)
+    );
+
+    if !migration.unmapped.is_empty() {
+        println!(
+            "{}: Some settings of {} couldn't be migrated:",
+            "Warning".yellow().bold(),
+            relativize_path(&lintr_path)
+        );
+        for unmapped in &migration.unmapped {
+            println!("  - {unmapped}");
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+fn nolint(command: MigrateNolintCommand) -> anyhow::Result<ExitStatus> {
+    let mut resolver = PathResolver::new(Settings::default());
+    for ds in discover_settings(&command.files)? {
+        resolver.add(&ds.directory, ds.settings);
+    }
+
+    // `# nolint` comments are only converted in R scripts, not in R Markdown
+    // or Quarto documents.
+    let paths: Vec<_> = discover_r_file_paths(&command.files, &resolver, true, false)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|path| {
+            path.extension()
+                .and_then(OsStr::to_str)
+                .is_some_and(is_r_extension)
+        })
+        .collect();
+
+    let mut converted = 0;
+    let mut files = 0;
+    let mut unconverted: Vec<String> = Vec::new();
+
+    for path in &paths {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        if !contents.contains("nolint") {
+            continue;
+        }
+
+        let migration = match migrate_nolint(&contents, "<reason>") {
+            Ok(migration) => migration,
+            Err(err) => {
+                unconverted.push(format!("{}: {err}", relativize_path(path)));
+                continue;
+            }
+        };
+
+        for comment in &migration.unconverted {
+            unconverted.push(format!(
+                "{}:{}: {}",
+                relativize_path(path),
+                comment.line,
+                comment.message
+            ));
+        }
+
+        if migration.converted > 0 {
+            converted += migration.converted;
+            files += 1;
+            if command.fix {
+                fs::write(path, &migration.source)
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+            }
+        }
+    }
+
+    let comments = if converted == 1 {
+        "comment"
+    } else {
+        "comments"
+    };
+    let in_files = if files == 1 { "file" } else { "files" };
+    if converted == 0 {
+        println!(
+            "{}: No `# nolint` comments to convert.",
+            "Info".cyan().bold()
+        );
+    } else if command.fix {
+        println!(
+            "{}: Converted {converted} `# nolint` {comments} in {files} {in_files}.",
+            "Info".cyan().bold()
+        );
+    } else {
+        println!(
+            "{}: Found {converted} `# nolint` {comments} to convert in {files} {in_files}. Use `--fix` to convert them.",
+            "Info".cyan().bold()
+        );
+    }
+
+    if !unconverted.is_empty() {
+        println!(
+            "{}: Some `# nolint` comments couldn't be converted:",
+            "Warning".yellow().bold()
+        );
+        for message in &unconverted {
+            println!("  - {message}");
+        }
+        println!("These comments are still recognized with `lintr-compat-suppressions = true`.");
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,172 @@
+use std::io::Write;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use jarl_core::rule_set::{Category, DefaultStatus, FixStatus, Rule};
+
+use crate::args::{InfoOutputFormat, RuleCommand, RulesCommand};
+use crate::status::ExitStatus;
+
+pub(crate) fn rule(command: RuleCommand) -> anyhow::Result<ExitStatus> {
+    let Some(rule) = Rule::from_name(&command.name) else {
+        return Err(anyhow!(
+            "Unknown rule: \"{}\". Use `jarl rules` to list all available rules.",
+            command.name
+        ));
+    };
+
+    let mut stdout = std::io::stdout().lock();
+
+    match command.output_format {
+        InfoOutputFormat::Text => {
+            writeln!(stdout, "# {}", rule.name())?;
+            writeln!(stdout)?;
+            for (field, value) in rule_fields(rule) {
+                writeln!(stdout, "{field}: {value}")?;
+            }
+            if let Some(doc) = rule.documentation() {
+                writeln!(stdout)?;
+                writeln!(stdout, "{doc}")?;
+            }
+        }
+        InfoOutputFormat::Json => {
+            let mut info = rule_to_json(rule);
+            info["documentation"] = serde_json::json!(rule.documentation());
+            serde_json::to_writer_pretty(&mut stdout, &info)?;
+            writeln!(stdout)?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+pub(crate) fn rules(command: RulesCommand) -> anyhow::Result<ExitStatus> {
+    let category = command
+        .category
+        .as_deref()
+        .map(|category| {
+            Category::from_str(category).map_err(|_| {
+                let valid = Category::ALL
+                    .iter()
+                    .map(|c| c.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                anyhow!("Unknown category: \"{category}\". Expected one of: {valid}.")
+            })
+        })
+        .transpose()?;
+
+    let mut rules: Vec<Rule> = Rule::all()
+        .iter()
+        .copied()
+        .filter(|rule| category.is_none_or(|c| rule.has_category(c)))
+        .collect();
+    rules.sort_by_key(|rule| rule.name());
+
+    let mut stdout = std::io::stdout().lock();
+
+    match command.output_format {
+        InfoOutputFormat::Text => {
+            let width = rules.iter().map(|r| r.name().len()).max().unwrap_or(0);
+            for rule in rules {
+                let mut notes = vec![format!("fix: {}", fix_status(rule.fix_status()))];
+                if rule.is_disabled_by_default() {
+                    notes.push("disabled by default".to_string());
+                }
+                if rule.is_deprecated() {
+                    notes.push("deprecated".to_string());
+                }
+                if rule.is_preview() {
+                    notes.push("preview".to_string());
+                }
+                writeln!(
+                    stdout,
+                    "{:<width$}  {:<16}  {}",
+                    rule.name(),
+                    categories(rule),
+                    notes.join(", ")
+                )?;
+            }
+        }
+        InfoOutputFormat::Json => {
+            let rules: Vec<serde_json::Value> = rules.into_iter().map(rule_to_json).collect();
+            serde_json::to_writer_pretty(&mut stdout, &rules)?;
+            writeln!(stdout)?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+fn categories(rule: Rule) -> String {
+    rule.categories()
+        .iter()
+        .map(|c| c.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn fix_status(status: FixStatus) -> &'static str {
+    match status {
+        FixStatus::Safe => "safe",
+        FixStatus::Unsafe => "unsafe",
+        FixStatus::None => "none",
+    }
+}
+
+fn min_r_version(rule: Rule) -> Option<String> {
+    rule.minimum_r_version()
+        .map(|(major, minor, patch)| format!("{major}.{minor}.{patch}"))
+}
+
+/// Metadata of a rule, as displayed by `jarl rule <name>`.
+fn rule_fields(rule: Rule) -> Vec<(&'static str, String)> {
+    let mut fields = vec![
+        ("Categories", categories(rule)),
+        (
+            "Enabled by default",
+            match rule.default_status() {
+                DefaultStatus::Enabled => "yes".to_string(),
+                DefaultStatus::Disabled => "no".to_string(),
+            },
+        ),
+        ("Fix", fix_status(rule.fix_status()).to_string()),
+        ("Default severity", rule.default_severity().to_string()),
+    ];
+    if rule.is_preview() {
+        fields.push(("Preview", "yes, only enabled with `--preview`".to_string()));
+    }
+    if let Some(version) = min_r_version(rule) {
+        fields.push(("Minimum R version", version));
+    }
+    if let Some(version) = rule.version_added() {
+        fields.push(("Added in", version.to_string()));
+    }
+    if let Some(deprecation) = rule.deprecation() {
+        fields.push((
+            "Deprecated",
+            format!(
+                "since {}, use `{}` instead",
+                deprecation.version, deprecation.replacement
+            ),
+        ));
+    }
+    fields
+}
+
+fn rule_to_json(rule: Rule) -> serde_json::Value {
+    serde_json::json!({
+        "name": rule.name(),
+        "categories": rule.categories().iter().map(|c| c.as_str()).collect::<Vec<_>>(),
+        "enabled_by_default": rule.is_enabled_by_default(),
+        "fix": fix_status(rule.fix_status()),
+        "default_severity": rule.default_severity(),
+        "min_r_version": min_r_version(rule),
+        "version_added": rule.version_added(),
+        "preview": rule.is_preview(),
+        "deprecated": rule.deprecation().map(|d| serde_json::json!({
+            "version": d.version,
+            "replacement": d.replacement,
+        })),
+    })
+}
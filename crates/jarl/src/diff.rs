@@ -0,0 +1,18 @@
+use similar::TextDiff;
+
+/// Number of unchanged lines displayed around each change.
+const CONTEXT_LINES: usize = 3;
+
+/// Build a unified diff between `old` and `new`, the original and fixed
+/// content of `path`. Returns an empty string if both are identical.
+pub fn unified_diff(path: &str, old: &str, new: &str) -> String {
+    if old == new {
+        return String::new();
+    }
+
+    TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(CONTEXT_LINES)
+        .header(&format!("a/{path}"), &format!("b/{path}"))
+        .to_string()
+}
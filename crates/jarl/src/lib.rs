@@ -3,7 +3,9 @@ use crate::args::Command;
 use crate::status::ExitStatus;
 
 pub mod args;
+pub mod baseline;
 pub mod commands;
+pub mod diff;
 pub mod logging;
 pub mod output_format;
 pub mod statistics;
@@ -20,6 +22,12 @@ pub fn run(args: Args) -> anyhow::Result<ExitStatus> {
 
     match args.command {
         Command::Check(command) => commands::check::check(command),
+        Command::Rule(command) => commands::rule::rule(command),
+        Command::Rules(command) => commands::rule::rules(command),
+        Command::Config(command) => commands::config::config(command),
+        Command::Init(command) => commands::init::init(command),
+        Command::Migrate(command) => commands::migrate::migrate(command),
+        Command::Clean(command) => commands::clean::clean(command),
         Command::Server(command) => commands::server::server(command),
     }
 }
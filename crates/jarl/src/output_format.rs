@@ -5,17 +5,39 @@ use colored::Colorize;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// Base URL of the documentation page of each rule.
+const RULES_DOCS_URL: &str = "https://jarl.etiennebacher.com/rules/";
+
+/// Source code read from stdin, and the path it was linted as. Emitters that
+/// display snippets use it instead of reading that path from disk.
+#[derive(Clone, Copy, Debug)]
+pub struct StdinSource<'a> {
+    pub path: &'a Path,
+    pub contents: &'a str,
+}
+
+/// Read the source code of a file that has diagnostics.
+fn read_source(path: &Path, stdin: Option<StdinSource>) -> std::io::Result<String> {
+    match stdin {
+        Some(stdin) if stdin.path == path => Ok(stdin.contents.to_string()),
+        _ => fs::read_to_string(path),
+    }
+}
 
 /// Creates a terminal hyperlink using OSC 8 escape sequences
 /// Format: \x1b]8;;<URL>\x1b\\<TEXT>\x1b]8;;\x1b\\
 fn make_hyperlink(text: &str) -> String {
     format!(
         "\x1b]8;;{}{}\x1b\\{}\x1b]8;;\x1b\\",
-        "https://jarl.etiennebacher.com/rules/", text, text
+        RULES_DOCS_URL, text, text
     )
 }
 
 use jarl_core::diagnostic::{Diagnostic, render_diagnostic};
+use jarl_core::hash::fnv1a_hash;
+use jarl_core::rule_set::{FixStatus, Rule, Severity};
 
 /// Prints a section header like `── Summary ──────────────────────────────────`
 /// padded to 57 characters total.
@@ -28,7 +50,7 @@ pub fn print_section_header(title: &str) {
     println!("{prefix}{padding}");
 }
 
-/// Prints the summary section with error counts and fix info.
+/// Prints the summary section with the number of issues and fix info.
 /// Only call for human-readable formats (Full, Concise).
 pub fn print_summary(diagnostics: &[&Diagnostic], has_errors: bool) {
     let total: i32 = diagnostics.len() as i32;
@@ -40,9 +62,9 @@ pub fn print_summary(diagnostics: &[&Diagnostic], has_errors: bool) {
         print_section_header("Summary");
 
         if total > 1 {
-            println!("Found {total} errors.");
+            println!("Found {total} issues.");
         } else {
-            println!("Found 1 error.");
+            println!("Found 1 issue.");
         }
 
         if n_safe_fixes > 0 {
@@ -74,7 +96,7 @@ pub fn print_summary(diagnostics: &[&Diagnostic], has_errors: bool) {
             .unwrap_or(15);
         if total > n_violations {
             println!(
-                "More than {n_violations} errors reported, use `--statistics` to get the count by rule."
+                "More than {n_violations} issues reported, use `--statistics` to get the count by rule."
             );
         }
     } else if !has_errors {
@@ -130,6 +152,14 @@ pub enum OutputFormat {
     Github,
     /// Print diagnostics as JSON
     Json,
+    /// Print diagnostics as SARIF 2.1.0
+    Sarif,
+    /// Print diagnostics as JUnit XML
+    Junit,
+    /// Print diagnostics as GitLab Code Quality report
+    Gitlab,
+    /// Print diagnostics as Reviewdog Diagnostic Format (rdjson)
+    Rdjson,
 }
 
 /// Takes the diagnostics and parsing errors in each file and then displays
@@ -196,12 +226,18 @@ impl Emitter for ConciseEmitter {
             } else {
                 &diagnostic.message.name
             };
+            let severity = match diagnostic.severity {
+                Severity::Error => diagnostic.severity.as_str().red(),
+                Severity::Warning => diagnostic.severity.as_str().yellow(),
+                Severity::Info => diagnostic.severity.as_str().cyan(),
+            };
             writeln!(
                 writer,
-                "{} [{}:{}] {} {}",
+                "{} [{}:{}] {} {} {}",
                 relative_path.white(),
                 row,
                 col,
+                severity,
                 rule_name.red(),
                 message
             )?;
@@ -258,6 +294,13 @@ impl Emitter for GithubEmitter {
                 }
             };
 
+            // GitHub only knows the `error`, `warning`, and `notice` levels.
+            let level = match diagnostic.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Info => "notice",
+            };
+
             // We want a message like this:
             // ::warning title=Jarl (any_is_na),file=demos/foo.R,line=4,col=5::demos/foo.R:4:5: any_is_na `any(is.na(...))` etc.
             //
@@ -269,7 +312,7 @@ impl Emitter for GithubEmitter {
             //   without the Github annotations.
             write!(
                 writer,
-                "::warning title=Jarl ({}),file={file},line={row},col={col}::{file}:{row}:{col} ",
+                "::{level} title=Jarl ({}),file={file},line={row},col={col}::{file}:{row}:{col} ",
                 diagnostic.message.name,
                 file = diagnostic.filename.to_string_lossy()
             )?;
@@ -287,9 +330,12 @@ impl Emitter for GithubEmitter {
     }
 }
 
-pub struct FullEmitter;
+pub struct FullEmitter<'a> {
+    /// Source code read from stdin, if any.
+    pub stdin_source: Option<StdinSource<'a>>,
+}
 
-impl Emitter for FullEmitter {
+impl Emitter for FullEmitter<'_> {
     fn emit<W: Write>(
         &self,
         writer: &mut W,
@@ -340,7 +386,7 @@ impl Emitter for FullEmitter {
         // Pre-load all files into cache
         for diagnostic in diagnostics {
             if !file_cache.contains_key(diagnostic.filename.as_path()) {
-                match fs::read_to_string(&diagnostic.filename) {
+                match read_source(&diagnostic.filename, self.stdin_source) {
                     Ok(content) => {
                         file_cache.insert(diagnostic.filename.as_path(), content);
                     }
@@ -390,3 +436,536 @@ impl Emitter for FullEmitter {
         Ok(())
     }
 }
+
+pub struct SarifEmitter;
+
+impl SarifEmitter {
+    /// Build the `tool.driver.rules` array. Results refer to these entries by
+    /// index, which is the position of the rule in `Rule::all()`.
+    fn rules() -> Vec<serde_json::Value> {
+        Rule::all()
+            .iter()
+            .map(|rule| {
+                let categories: Vec<&str> = rule.categories().iter().map(|c| c.as_str()).collect();
+                let fix = match rule.fix_status() {
+                    FixStatus::Safe => "safe",
+                    FixStatus::Unsafe => "unsafe",
+                    FixStatus::None => "none",
+                };
+                serde_json::json!({
+                    "id": rule.name(),
+                    "name": rule.name(),
+                    "helpUri": format!("{RULES_DOCS_URL}{}", rule.name()),
+                    "defaultConfiguration": {
+                        "level": sarif_level(rule.default_severity()),
+                        "enabled": rule.is_enabled_by_default(),
+                    },
+                    "properties": {
+                        "categories": categories,
+                        "fix": fix,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// SARIF has no `info` level, `note` is the closest equivalent.
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+/// SARIF expects URIs, which always use forward slashes.
+fn sarif_uri(path: &std::path::Path) -> String {
+    relativize_path(path).replace('\\', "/")
+}
+
+impl Emitter for SarifEmitter {
+    fn emit<W: Write>(
+        &self,
+        writer: &mut W,
+        diagnostics: &[&Diagnostic],
+        errors: &[(String, anyhow::Error)],
+    ) -> anyhow::Result<()> {
+        let mut writer = BufWriter::new(writer);
+
+        let results: Vec<serde_json::Value> = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let (row, col) = match diagnostic.location {
+                    Some(loc) => (loc.row(), loc.column() + 1), // Convert to 1-based for display
+                    None => {
+                        unreachable!("Row/col locations must have been parsed successfully before.")
+                    }
+                };
+
+                let uri = sarif_uri(&diagnostic.filename);
+                let start: usize = diagnostic.range.start().into();
+                let end: usize = diagnostic.range.end().into();
+
+                let message = if let Some(suggestion) = &diagnostic.message.suggestion {
+                    format!("{} {}", diagnostic.message.body, suggestion)
+                } else {
+                    diagnostic.message.body.clone()
+                };
+
+                let mut result = serde_json::json!({
+                    "ruleId": diagnostic.message.name,
+                    "level": sarif_level(diagnostic.severity),
+                    "message": { "text": message },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": uri },
+                            "region": {
+                                "startLine": row,
+                                "startColumn": col,
+                                "byteOffset": start,
+                                "byteLength": end - start,
+                            },
+                        },
+                    }],
+                });
+
+                if let Some(index) = Rule::all()
+                    .iter()
+                    .position(|r| r.name() == diagnostic.message.name)
+                {
+                    result["ruleIndex"] = serde_json::json!(index);
+                }
+
+                if diagnostic.has_safe_fix() || diagnostic.has_unsafe_fix() {
+                    let fix = &diagnostic.fix;
+                    let description = if diagnostic.has_safe_fix() {
+                        "Safe fix"
+                    } else {
+                        "Unsafe fix"
+                    };
+                    result["fixes"] = serde_json::json!([{
+                        "description": { "text": description },
+                        "artifactChanges": [{
+                            "artifactLocation": { "uri": uri },
+                            "replacements": [{
+                                "deletedRegion": {
+                                    "byteOffset": fix.start,
+                                    "byteLength": fix.end - fix.start,
+                                },
+                                "insertedContent": { "text": fix.content },
+                            }],
+                        }],
+                    }]);
+                }
+
+                result
+            })
+            .collect();
+
+        // Files that couldn't be checked (e.g. parsing errors) are reported as
+        // notifications of the tool execution rather than as results.
+        let notifications: Vec<serde_json::Value> = errors
+            .iter()
+            .map(|(path, err)| {
+                serde_json::json!({
+                    "level": "error",
+                    "message": { "text": format!("{:#}", err) },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": path.replace('\\', "/") },
+                        },
+                    }],
+                })
+            })
+            .collect();
+
+        let output = serde_json::json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "jarl",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": "https://jarl.etiennebacher.com",
+                        "rules": Self::rules(),
+                    },
+                },
+                "invocations": [{
+                    "executionSuccessful": errors.is_empty(),
+                    "toolExecutionNotifications": notifications,
+                }],
+                "results": results,
+            }],
+        });
+
+        serde_json::to_writer_pretty(&mut writer, &output)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Reports each checked file as a `<testsuite>` and each violation as a
+/// failing `<testcase>`. Files without violations get a single passing
+/// `<testcase>` so that they show up in test-report dashboards.
+pub struct JunitEmitter<'a> {
+    /// All files that were checked, including those without violations.
+    pub checked_files: &'a [String],
+}
+
+/// Escape the characters that are not allowed in XML text and attributes.
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl Emitter for JunitEmitter<'_> {
+    fn emit<W: Write>(
+        &self,
+        writer: &mut W,
+        diagnostics: &[&Diagnostic],
+        errors: &[(String, anyhow::Error)],
+    ) -> anyhow::Result<()> {
+        let mut writer = BufWriter::new(writer);
+
+        // Group diagnostics by file, keeping files in a deterministic order.
+        let mut by_file: std::collections::BTreeMap<String, Vec<&Diagnostic>> =
+            std::collections::BTreeMap::new();
+        for file in self.checked_files {
+            by_file.entry(file.clone()).or_default();
+        }
+        for diagnostic in diagnostics {
+            by_file
+                .entry(relativize_path(diagnostic.filename.clone()))
+                .or_default()
+                .push(diagnostic);
+        }
+
+        let errors_by_file: std::collections::HashMap<&str, &anyhow::Error> = errors
+            .iter()
+            .map(|(path, err)| (path.as_str(), err))
+            .collect();
+
+        let n_tests: usize = by_file
+            .iter()
+            .map(|(file, diagnostics)| {
+                if diagnostics.is_empty() || errors_by_file.contains_key(file.as_str()) {
+                    1
+                } else {
+                    diagnostics.len()
+                }
+            })
+            .sum();
+
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            writer,
+            r#"<testsuites name="jarl" tests="{}" failures="{}" errors="{}">"#,
+            n_tests,
+            diagnostics.len(),
+            errors.len()
+        )?;
+
+        for (file, diagnostics) in &by_file {
+            let name = xml_escape(&file.replace('\\', "/"));
+
+            // Files that couldn't be checked, e.g. because of parsing errors.
+            if let Some(err) = errors_by_file.get(file.as_str()) {
+                writeln!(
+                    writer,
+                    r#"  <testsuite name="{name}" tests="1" failures="0" errors="1">"#
+                )?;
+                writeln!(
+                    writer,
+                    r#"    <testcase name="jarl" classname="{name}" file="{name}">"#
+                )?;
+                writeln!(
+                    writer,
+                    r#"      <error message="{}"/>"#,
+                    xml_escape(&format!("{:#}", err))
+                )?;
+                writeln!(writer, "    </testcase>")?;
+                writeln!(writer, "  </testsuite>")?;
+                continue;
+            }
+
+            if diagnostics.is_empty() {
+                writeln!(
+                    writer,
+                    r#"  <testsuite name="{name}" tests="1" failures="0" errors="0">"#
+                )?;
+                writeln!(
+                    writer,
+                    r#"    <testcase name="jarl" classname="{name}" file="{name}"/>"#
+                )?;
+                writeln!(writer, "  </testsuite>")?;
+                continue;
+            }
+
+            writeln!(
+                writer,
+                r#"  <testsuite name="{name}" tests="{n}" failures="{n}" errors="0">"#,
+                n = diagnostics.len()
+            )?;
+
+            for diagnostic in diagnostics {
+                let (row, col) = match diagnostic.location {
+                    Some(loc) => (loc.row(), loc.column() + 1), // Convert to 1-based for display
+                    None => {
+                        unreachable!("Row/col locations must have been parsed successfully before.")
+                    }
+                };
+
+                let message = if let Some(suggestion) = &diagnostic.message.suggestion {
+                    format!("{} {}", diagnostic.message.body, suggestion)
+                } else {
+                    diagnostic.message.body.clone()
+                };
+
+                writeln!(
+                    writer,
+                    r#"    <testcase name="{rule} ({row}:{col})" classname="{name}" file="{name}" line="{row}">"#,
+                    rule = diagnostic.message.name,
+                )?;
+                writeln!(
+                    writer,
+                    r#"      <failure message="{}" type="{}">line {row}, column {col}: {}</failure>"#,
+                    xml_escape(&diagnostic.message.body),
+                    diagnostic.message.name,
+                    xml_escape(&message),
+                )?;
+                writeln!(writer, "    </testcase>")?;
+            }
+
+            writeln!(writer, "  </testsuite>")?;
+        }
+
+        writeln!(writer, "</testsuites>")?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Print errors (e.g. files that couldn't be parsed) to stderr, for output
+/// formats that have no place for them.
+pub(crate) fn print_errors_to_stderr(errors: &[(String, anyhow::Error)]) {
+    for (_path, err) in errors {
+        let root_cause = err.chain().last().unwrap();
+        if root_cause.is::<jarl_core::error::ParseError>() {
+            eprintln!("{}: {}", "Error".red().bold(), root_cause);
+        } else {
+            eprintln!("{}: {}", "Error".red().bold(), err);
+        }
+    }
+}
+
+/// Emits a GitLab Code Quality report, which follows the Code Climate issue
+/// schema: <https://docs.gitlab.com/ci/testing/code_quality/#code-quality-report-format>
+pub struct GitlabEmitter<'a> {
+    /// Source code read from stdin, if any.
+    pub stdin_source: Option<StdinSource<'a>>,
+}
+
+/// GitLab only knows `info`, `minor`, `major`, `critical`, and `blocker`.
+fn gitlab_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "major",
+        Severity::Warning => "minor",
+        Severity::Info => "info",
+    }
+}
+
+impl Emitter for GitlabEmitter<'_> {
+    fn emit<W: Write>(
+        &self,
+        writer: &mut W,
+        diagnostics: &[&Diagnostic],
+        errors: &[(String, anyhow::Error)],
+    ) -> anyhow::Result<()> {
+        let mut writer = BufWriter::new(writer);
+
+        // The report only contains issues, so errors go to stderr to keep
+        // stdout parseable.
+        print_errors_to_stderr(errors);
+
+        let mut file_cache: std::collections::HashMap<&std::path::Path, String> =
+            std::collections::HashMap::new();
+        // Number of times a (file, rule, source) triplet was already seen, so
+        // that identical violations in the same file get distinct fingerprints.
+        let mut seen: std::collections::HashMap<(String, String, String), usize> =
+            std::collections::HashMap::new();
+
+        let issues: Vec<serde_json::Value> = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let (begin, end) = match diagnostic.location {
+                    Some(loc) => (loc.row(), loc.row()),
+                    None => {
+                        unreachable!("Row/col locations must have been parsed successfully before.")
+                    }
+                };
+
+                let path = sarif_uri(&diagnostic.filename);
+                let source = file_cache
+                    .entry(diagnostic.filename.as_path())
+                    .or_insert_with(|| {
+                        read_source(&diagnostic.filename, self.stdin_source).unwrap_or_default()
+                    });
+
+                let start: usize = diagnostic.range.start().into();
+                let stop: usize = diagnostic.range.end().into();
+                let snippet = source.get(start..stop).unwrap_or_default();
+                let end = end + snippet.matches('\n').count();
+
+                // Fingerprints must not depend on the position of the
+                // violation, otherwise adding a line at the top of the file
+                // would change all of them. Whitespace is normalized so that
+                // reformatting doesn't change them either.
+                let normalized = snippet.split_whitespace().collect::<Vec<_>>().join(" ");
+                let occurrence = seen
+                    .entry((
+                        path.clone(),
+                        diagnostic.message.name.clone(),
+                        normalized.clone(),
+                    ))
+                    .or_insert(0);
+                let fingerprint = fnv1a_hash(&[
+                    &path,
+                    &diagnostic.message.name,
+                    &normalized,
+                    &occurrence.to_string(),
+                ]);
+                *occurrence += 1;
+
+                serde_json::json!({
+                    "type": "issue",
+                    "check_name": diagnostic.message.name,
+                    "description": diagnostic.message.body,
+                    "fingerprint": format!("{:016x}", fingerprint),
+                    "severity": gitlab_severity(diagnostic.severity),
+                    "location": {
+                        "path": path,
+                        "lines": { "begin": begin, "end": end },
+                    },
+                })
+            })
+            .collect();
+
+        serde_json::to_writer_pretty(&mut writer, &issues)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Emits diagnostics in the Reviewdog Diagnostic Format:
+/// <https://github.com/reviewdog/reviewdog/tree/master/proto/rdf>
+///
+/// Safe fixes are included as suggestions so that reviewdog can post them as
+/// suggested changes on pull requests.
+pub struct RdjsonEmitter<'a> {
+    /// Source code read from stdin, if any.
+    pub stdin_source: Option<StdinSource<'a>>,
+}
+
+/// Convert a byte offset to a 1-based (line, column) position, where the
+/// column is counted in UTF-8 bytes as required by rdjson.
+fn rdjson_position(source: &str, offset: usize) -> serde_json::Value {
+    let offset = offset.min(source.len());
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    serde_json::json!({ "line": line, "column": offset - line_start + 1 })
+}
+
+fn rdjson_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "ERROR",
+        Severity::Warning => "WARNING",
+        Severity::Info => "INFO",
+    }
+}
+
+impl Emitter for RdjsonEmitter<'_> {
+    fn emit<W: Write>(
+        &self,
+        writer: &mut W,
+        diagnostics: &[&Diagnostic],
+        errors: &[(String, anyhow::Error)],
+    ) -> anyhow::Result<()> {
+        let mut writer = BufWriter::new(writer);
+
+        print_errors_to_stderr(errors);
+
+        let mut file_cache: std::collections::HashMap<&std::path::Path, String> =
+            std::collections::HashMap::new();
+
+        let rd_diagnostics: Vec<serde_json::Value> = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let source = file_cache
+                    .entry(diagnostic.filename.as_path())
+                    .or_insert_with(|| {
+                        read_source(&diagnostic.filename, self.stdin_source).unwrap_or_default()
+                    });
+
+                let message = if let Some(suggestion) = &diagnostic.message.suggestion {
+                    format!("{} {}", diagnostic.message.body, suggestion)
+                } else {
+                    diagnostic.message.body.clone()
+                };
+
+                let mut rd_diagnostic = serde_json::json!({
+                    "message": message,
+                    "location": {
+                        "path": sarif_uri(&diagnostic.filename),
+                        "range": {
+                            "start": rdjson_position(source, diagnostic.range.start().into()),
+                            "end": rdjson_position(source, diagnostic.range.end().into()),
+                        },
+                    },
+                    "severity": rdjson_severity(diagnostic.severity),
+                    "code": {
+                        "value": diagnostic.message.name,
+                        "url": format!("{RULES_DOCS_URL}{}", diagnostic.message.name),
+                    },
+                });
+
+                if diagnostic.has_safe_fix() {
+                    let fix = &diagnostic.fix;
+                    rd_diagnostic["suggestions"] = serde_json::json!([{
+                        "range": {
+                            "start": rdjson_position(source, fix.start),
+                            "end": rdjson_position(source, fix.end),
+                        },
+                        "text": fix.content,
+                    }]);
+                }
+
+                rd_diagnostic
+            })
+            .collect();
+
+        let output = serde_json::json!({
+            "source": {
+                "name": "jarl",
+                "url": "https://jarl.etiennebacher.com",
+            },
+            "diagnostics": rd_diagnostics,
+        });
+
+        serde_json::to_writer_pretty(&mut writer, &output)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use colored::Colorize;
 use jarl_core::diagnostic::Diagnostic;
+use jarl_core::rule_set::Severity;
 use std::{collections::HashMap, path::PathBuf};
 
 use crate::status::ExitStatus;
@@ -7,6 +8,7 @@ use crate::status::ExitStatus;
 pub fn print_statistics(
     diagnostics: &[&Diagnostic],
     parent_config_path: Option<PathBuf>,
+    fail_on: Severity,
 ) -> anyhow::Result<ExitStatus> {
     if diagnostics.is_empty() {
         println!("All checks passed!");
@@ -47,5 +49,9 @@ pub fn print_statistics(
         println!("\nUsed '{}'", config_path.display());
     }
 
+    if !diagnostics.iter().any(|d| d.severity >= fail_on) {
+        return Ok(ExitStatus::Success);
+    }
+
     Ok(ExitStatus::Failure)
 }
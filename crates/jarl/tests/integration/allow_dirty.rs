@@ -60,7 +60,7 @@ fn test_dirty_git_repo_does_not_block_lint() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -47,7 +47,7 @@ y <- 2
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ── Warnings ─────────────────────────────────────
@@ -89,7 +89,7 @@ y <- 2
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ── Warnings ─────────────────────────────────────
@@ -214,7 +214,7 @@ operator = "<-"
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -258,7 +258,7 @@ operator = "="
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -400,7 +400,7 @@ operator = "<-"
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ── Warnings ─────────────────────────────────────
@@ -464,7 +464,7 @@ assignment = "<-"
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ── Warnings ─────────────────────────────────────
@@ -511,7 +511,7 @@ assignment = "="
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ── Warnings ─────────────────────────────────────
@@ -652,7 +652,7 @@ assignment = "<-"
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ── Warnings ─────────────────────────────────────
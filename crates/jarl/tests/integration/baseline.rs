@@ -0,0 +1,207 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_generate_baseline() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "R/foo.R",
+            "any(is.na(x))\nany(is.na(x))\nlist(x = 1, x = 2)\n",
+        ),
+        ("R/bar.R", "x <- 1\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--generate-baseline")
+            .arg("jarl-baseline.json")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Info: Recorded 3 violation(s) in jarl-baseline.json.
+
+    ----- stderr -----
+    "
+    );
+
+    insta::assert_snapshot!(
+        case.read_file("jarl-baseline.json")?,
+        @r#"
+    {
+      "version": 1,
+      "violations": [
+        {
+          "file": "R/foo.R",
+          "rule": "any_is_na",
+          "fingerprint": "2e66a7168fc50dff",
+          "count": 2
+        },
+        {
+          "file": "R/foo.R",
+          "rule": "duplicated_arguments",
+          "fingerprint": "3c1699c124c7f4ed",
+          "count": 1
+        }
+      ]
+    }
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_baseline_hides_known_violations() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "[lint]\nbaseline = \"jarl-baseline.json\"\n"),
+        ("R/foo.R", "any(is.na(x))\nlist(x = 1, x = 2)\n"),
+    ])?;
+
+    case.command()
+        .arg("check")
+        .arg(".")
+        .arg("--generate-baseline")
+        .arg("jarl-baseline.json")
+        .run();
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    All checks passed!
+
+    ── Notes ────────────────────────────────────────
+    2 violations were hidden by the baseline.
+
+    ----- stderr -----
+    "
+    );
+
+    // Violations are still hidden when lines are added before them or when
+    // the code is reformatted, but new violations are reported.
+    case.write_file(
+        "R/foo.R",
+        "y <- 1\n\nany(is.na(y))\nany(is.na(x))\nlist(x = 1,   x = 2)\n",
+    )?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/foo.R [3:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ── Notes ────────────────────────────────────────
+    2 violations were hidden by the baseline.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_baseline_counts_occurrences() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "[lint]\nbaseline = \"jarl-baseline.json\"\n"),
+        ("R/foo.R", "any(is.na(x))\n"),
+    ])?;
+
+    case.command()
+        .arg("check")
+        .arg(".")
+        .arg("--generate-baseline")
+        .arg("jarl-baseline.json")
+        .run();
+
+    // The same violation appears a second time: only one of them is known.
+    case.write_file("R/foo.R", "any(is.na(x))\nany(is.na(x))\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/foo.R [2:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ── Notes ────────────────────────────────────────
+    1 violation was hidden by the baseline.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_baseline_missing_file() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "[lint]\nbaseline = \"jarl-baseline.json\"\n"),
+        ("R/foo.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Failed to read the baseline file jarl-baseline.json.
+    Use `jarl check --generate-baseline jarl-baseline.json` to create it.
+    "
+    );
+
+    Ok(())
+}
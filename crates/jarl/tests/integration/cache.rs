@@ -0,0 +1,259 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_cache_is_created() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(is.na(x))\n")?;
+
+    case.command().arg("check").arg(".").run();
+
+    assert!(case.root().join(".jarl_cache").is_dir());
+    insta::assert_snapshot!(
+        case.read_file(".jarl_cache/.gitignore")?,
+        @"
+    # Automatically created by Jarl.
+    *
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_no_cache() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(is.na(x))\n")?;
+
+    case.command().arg("check").arg(".").arg("--no-cache").run();
+
+    assert!(!case.root().join(".jarl_cache").exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_cache_is_invalidated() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(is.na(x))\n")?;
+
+    case.command().arg("check").arg(".").run();
+
+    // Cached results are reused when nothing changed.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    // Changing the settings invalidates the cache.
+    case.write_file("jarl.toml", "[lint]\nignore = [\"any_is_na\"]\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    All checks passed!
+
+    ----- stderr -----
+    "
+    );
+
+    // Changing the file invalidates the cache.
+    case.write_file("test.R", "any(is.na(x))\nany(duplicated(x))\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.R [2:1] warning any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_cache_is_pruned() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("kept.R", "any(is.na(x))\n"),
+        ("removed.R", "any(is.na(x))\n"),
+        (".jarl_cache/0.0.1/lint.json", "{}"),
+    ])?;
+
+    case.command().arg("check").arg(".").run();
+
+    // Results of other versions of Jarl are removed.
+    assert!(!case.root().join(".jarl_cache/0.0.1").exists());
+
+    let versions: Vec<_> = std::fs::read_dir(case.root().join(".jarl_cache"))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    assert_eq!(versions.len(), 1);
+    let lint_json = versions[0].join("lint.json");
+    assert!(std::fs::read_to_string(&lint_json)?.contains("removed.R"));
+
+    // Files that don't exist anymore are removed from the cache.
+    std::fs::remove_file(case.root().join("removed.R"))?;
+    case.command().arg("check").arg(".").run();
+
+    let contents = std::fs::read_to_string(&lint_json)?;
+    assert!(contents.contains("kept.R"));
+    assert!(!contents.contains("removed.R"));
+
+    Ok(())
+}
+
+#[test]
+fn test_cache_with_package_specific_rules() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "[lint]\nselect = [\"DPLYR\"]\n"),
+        ("test.R", "library(dplyr)\nx |> filter(a > 1 | is.na(a))\n"),
+        // Installed packages are read from the library without R.
+        ("lib/dplyr/DESCRIPTION", "Package: dplyr\nVersion: 1.2.0\n"),
+        ("lib/dplyr/NAMESPACE", "export(filter)\n"),
+    ])?;
+
+    let check = || {
+        case.command()
+            .arg("check")
+            .arg(".")
+            .arg("--statistics")
+            .env("HOME", case.root())
+            .env("XDG_CACHE_HOME", case.root().join("cache"))
+            .env("PATH", "")
+            .env("R_LIBS", case.root().join("lib"))
+            .run()
+            .normalize_os_executable_name()
+    };
+
+    insta::assert_snapshot!(
+        &mut check(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+        1 [*] dplyr_filter_out
+
+    Rules with `[*]` have an automatic fix.
+
+    ----- stderr -----
+    "
+    );
+
+    // Results of files checked with package-specific rules are cached too.
+    let versions: Vec<_> = std::fs::read_dir(case.root().join(".jarl_cache"))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    assert_eq!(versions.len(), 1);
+    let lint_json = versions[0].join("lint.json");
+    assert!(std::fs::read_to_string(&lint_json)?.contains("dplyr_filter_out"));
+
+    // Installing another version of the package invalidates the cache.
+    // `filter_out()` doesn't exist before dplyr 1.2.0.
+    case.write_file("lib/dplyr/DESCRIPTION", "Package: dplyr\nVersion: 1.1.4\n")?;
+
+    insta::assert_snapshot!(
+        &mut check(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    All checks passed!
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_clean() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(is.na(x))\n")?;
+
+    case.command().arg("check").arg(".").run();
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("clean")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Info: Removed .jarl_cache.
+
+    ----- stderr -----
+    "
+    );
+
+    assert!(!case.root().join(".jarl_cache").exists());
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("clean")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Info: No cache to remove.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
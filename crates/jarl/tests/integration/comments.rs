@@ -145,7 +145,7 @@ any(is.na(w))
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -190,7 +190,7 @@ any(is.na(y))
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -323,7 +323,7 @@ any(is.na(z))
 
 
     ── Summary ──────────────────────────────────────
-    Found 3 errors.
+    Found 3 issues.
     3 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -333,6 +333,51 @@ any(is.na(z))
     Ok(())
 }
 
+#[test]
+fn test_nolint_with_lintr_compat_suppressions() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "[lint]\nlintr-compat-suppressions = true\n"),
+        (
+            "test.R",
+            "any(is.na(x)) # nolint
+any(is.na(y)) # nolint: any_is_na_linter.
+any(is.na(z)) # nolint: seq_linter.
+# nolint start: any_is_na.
+any(is.na(a))
+# nolint end
+any(is.na(b))
+",
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.R [3:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [7:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 2 issues.
+    2 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_fix_skips_internal_comments_with_outer_comments_460() -> anyhow::Result<()> {
     let original = "# leading comment\n!(x \n # hello there \n >= y)\n";
@@ -364,7 +409,7 @@ fn test_fix_skips_internal_comments_with_outer_comments_460() -> anyhow::Result<
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
@@ -0,0 +1,281 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_config_inline_options() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_na", "any_duplicated"]
+"#,
+        ),
+        ("test.R", "any(is.na(x))\nany(duplicated(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--config")
+            .arg(r#"lint.ignore = ["any_duplicated"]"#)
+            .arg("--config")
+            .arg(r#"lint.severity.any_is_na = "error""#)
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.R [1:1] error any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_config_inline_options_without_config_file() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(is.na(x))\nany(duplicated(x))\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--config")
+            .arg(r#"lint.select = ["any_duplicated"]"#)
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.R [2:1] warning any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_config_file() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_duplicated"]
+"#,
+        ),
+        (
+            "ci.toml",
+            r#"
+[lint]
+select = ["any_is_na"]
+"#,
+        ),
+        ("test.R", "any(is.na(x))\nany(duplicated(x))\n"),
+    ])?;
+
+    // `jarl.toml` is ignored when a configuration file is passed.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--config")
+            .arg("ci.toml")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    // Inline options are merged over the configuration file.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--config")
+            .arg("ci.toml")
+            .arg("--config")
+            .arg(r#"lint.extend-select = ["any_duplicated"]"#)
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [2:1] warning any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 2 issues.
+    2 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_config_file_outside_current_directory() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "ci.toml",
+            r#"
+[lint]
+select = ["any_is_na", "any_duplicated"]
+exclude = ["other/excluded.R"]
+"#,
+        ),
+        ("work/README.md", ""),
+        ("other/test.R", "any(is.na(x))\n"),
+        ("other/excluded.R", "any(duplicated(x))\n"),
+    ])?;
+
+    // The configuration file applies to paths outside of the current
+    // directory, and its patterns are relative to its own directory.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .current_dir(case.root().join("work"))
+            .arg("check")
+            .arg("../other")
+            .arg("--config")
+            .arg("../ci.toml")
+            .arg("--statistics")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+        1 [*] any_is_na
+
+    Rules with `[*]` have an automatic fix.
+
+    Used '[TEMP_DIR]/ci.toml'
+
+    ----- stderr -----
+    "
+    );
+
+    // Same for inline options when no `jarl.toml` applies.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .current_dir(case.root().join("work"))
+            .arg("check")
+            .arg("../other")
+            .arg("--config")
+            .arg(r#"lint.select = ["any_duplicated"]"#)
+            .arg("--statistics")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+        1 [*] any_duplicated
+
+    Rules with `[*]` have an automatic fix.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_config_errors() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(is.na(x))\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--config")
+            .arg("missing.toml")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: The configuration file missing.toml passed with `--config` doesn't exist.
+    "
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--config")
+            .arg("lint.foo = 1")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid `--config` option `lint.foo = 1`:
+    Unknown field `foo` in `[lint]`. Expected one of: `select`, `extend-select`, `ignore`, `fixable`, `unfixable`, `exclude`, `default-exclude`, `include`, `check-roxygen`, `fix-roxygen`, `lintr-compat-suppressions`, `preview`, `baseline`, `per-file-ignores`, `overrides`.
+    "
+    );
+
+    Ok(())
+}
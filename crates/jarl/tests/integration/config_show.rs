@@ -0,0 +1,214 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_config_show() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))"),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_na", "any_duplicated"]
+unfixable = ["any_duplicated"]
+exclude = ["renv/"]
+
+[lint.severity]
+any_is_na = "error"
+
+[lint.quotes]
+quote = "single"
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("config")
+            .arg("show")
+            .arg("test.R")
+            .run()
+            .normalize_os_executable_name(),
+        @r#"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Configuration file: jarl.toml (project)
+    Minimum R version: unknown
+
+    Rules (2):
+      any_duplicated
+      any_is_na
+
+    Fixable: all
+    Unfixable: any_duplicated
+    Severity overrides:
+      any_is_na = error
+    Include: none
+    Exclude: renv/
+    Default exclude: true
+    Check roxygen examples: true
+    Fix roxygen examples: false
+    Recognize `# nolint` comments: false
+    Preview: false
+
+    Rule options:
+      assignment.operator = <-
+      duplicated_arguments.skipped-functions = c, mutate, summarize, transmute
+      implicit_assignment.skipped-functions = expect_error, expect_message, expect_snapshot, expect_warning, quote, suppressMessages, suppressWarnings
+      pipe_consistency.pipe = |>
+      quotes.quote = single
+      undesirable_function.functions = browser
+      unreachable_code.stopping-functions = .Defunct, abort, cli_abort, q, quit, stop
+      unused_function.skipped-functions = none
+      unused_function.threshold-ignore = 50
+
+    ----- stderr -----
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_config_show_cli_args() -> anyhow::Result<()> {
+    let case = CliTest::with_files([("test.R", "any(is.na(x))")])?;
+
+    let output = case
+        .command()
+        .arg("config")
+        .arg("show")
+        .arg("--select")
+        .arg("PERF")
+        .arg("--ignore")
+        .arg("any_is_na")
+        .arg("--min-r-version")
+        .arg("4.5")
+        .arg("--output-format")
+        .arg("json")
+        .run();
+
+    let config: serde_json::Value = serde_json::from_str(&output.stdout)?;
+    assert_eq!(config["config_file"], serde_json::Value::Null);
+    assert_eq!(config["min_r_version"], "4.5.0");
+    let rules: Vec<&str> = config["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r.as_str().unwrap())
+        .collect();
+    assert!(rules.contains(&"any_duplicated"));
+    assert!(!rules.contains(&"any_is_na"));
+
+    Ok(())
+}
+
+#[test]
+fn test_config_show_nested() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "[lint]\nselect = [\"any_is_na\"]"),
+        ("sub/test.R", "any(is.na(x))"),
+        ("sub/jarl.toml", "[lint]\nselect = [\"any_duplicated\"]"),
+    ])?;
+
+    let output = case
+        .command()
+        .arg("config")
+        .arg("show")
+        .arg("sub/test.R")
+        .arg("--output-format")
+        .arg("json")
+        .run();
+
+    let config: serde_json::Value = serde_json::from_str(&output.stdout)?;
+    assert_eq!(config["config_file"]["source"], "nested");
+    assert_eq!(config["rules"], serde_json::json!(["any_duplicated"]));
+
+    Ok(())
+}
+
+#[test]
+fn test_config_show_config_option() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "[lint]\nselect = [\"any_is_na\"]"),
+        ("ci.toml", "[lint]\nselect = [\"any_duplicated\"]"),
+        ("test.R", "any(is.na(x))"),
+    ])?;
+
+    let output = case
+        .command()
+        .arg("config")
+        .arg("show")
+        .arg("test.R")
+        .arg("--config")
+        .arg("ci.toml")
+        .arg("--config")
+        .arg(r#"lint.extend-select = ["class_equals"]"#)
+        .arg("--output-format")
+        .arg("json")
+        .run();
+
+    let config: serde_json::Value = serde_json::from_str(&output.stdout)?;
+    assert_eq!(config["config_file"]["path"], "ci.toml");
+    assert_eq!(config["config_file"]["source"], "passed with `--config`");
+    assert_eq!(
+        config["rules"],
+        serde_json::json!(["any_duplicated", "class_equals"])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_config_show_preview() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(is.na(x))")?;
+
+    let preview = |case: &CliTest, args: &[&str]| -> anyhow::Result<serde_json::Value> {
+        let output = case
+            .command()
+            .arg("config")
+            .arg("show")
+            .args(args)
+            .arg("--output-format")
+            .arg("json")
+            .run();
+        let config: serde_json::Value = serde_json::from_str(&output.stdout)?;
+        Ok(config["preview"].clone())
+    };
+
+    assert_eq!(preview(&case, &[])?, false);
+    assert_eq!(preview(&case, &["--preview"])?, true);
+
+    case.write_file("jarl.toml", "[lint]\npreview = true\n")?;
+    assert_eq!(preview(&case, &[])?, true);
+
+    Ok(())
+}
+
+#[test]
+fn test_config_show_missing_path() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("config")
+            .arg("show")
+            .arg("foo.R")
+            .run()
+            .normalize_os_executable_name(),
+        @r#"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Path "foo.R" does not exist.
+    "#
+    );
+
+    Ok(())
+}
@@ -0,0 +1,223 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_description_config() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "DESCRIPTION",
+            "Package: foo\nVersion: 0.1.0\nConfig/jarl/ignore: any_is_na\nConfig/jarl/exclude: R/generated.R\n",
+        ),
+        ("R/foo.R", "any(is.na(x))\nany(duplicated(x))\n"),
+        ("R/generated.R", "any(duplicated(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/foo.R [2:1] warning any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_description_config_select() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "DESCRIPTION",
+            "Package: foo\nVersion: 0.1.0\nConfig/jarl/select: any_is_na,\n    any_duplicated\n",
+        ),
+        ("R/foo.R", "any(is.na(x))\nany(duplicated(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/foo.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    R/foo.R [2:1] warning any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 2 issues.
+    2 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_jarl_toml_takes_precedence_over_description() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "DESCRIPTION",
+            "Package: foo\nVersion: 0.1.0\nConfig/jarl/ignore: any_is_na\nConfig/jarl/exclude: R/generated.R\n",
+        ),
+        ("jarl.toml", "[lint]\n"),
+        ("R/foo.R", "any(is.na(x))\nany(duplicated(x))\n"),
+        ("R/generated.R", "any(duplicated(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/foo.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    R/foo.R [2:1] warning any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+    R/generated.R [1:1] warning any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 3 issues.
+    3 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_parent_jarl_toml_takes_precedence_over_description() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "[lint]\nselect = [\"any_is_na\"]\n"),
+        (
+            "pkg/DESCRIPTION",
+            "Package: foo\nVersion: 0.1.0\nConfig/jarl/select: any_duplicated\n",
+        ),
+        ("pkg/R/foo.R", "any(is.na(x))\nany(duplicated(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    pkg/R/foo.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_description_config_unknown_field() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "DESCRIPTION",
+            "Package: foo\nVersion: 0.1.0\nConfig/jarl/extend-select: PERF\n",
+        ),
+        ("R/foo.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Unknown field `Config/jarl/extend-select` in [TEMP_DIR]/DESCRIPTION. Expected one of: `Config/jarl/select`, `Config/jarl/ignore`, `Config/jarl/exclude`.
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_description_config_unknown_rule() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "DESCRIPTION",
+            "Package: foo\nVersion: 0.1.0\nConfig/jarl/select: any_is_naa\n",
+        ),
+        ("R/foo.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/DESCRIPTION:
+    [TEMP_DIR]/DESCRIPTION:3:21: Unknown rule `any_is_naa` in `Config/jarl/select`. Did you mean `any_is_na`?
+    "
+    );
+
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_diff_does_not_modify_files() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "x <- 1\nany(is.na(x))\nlist(x = 1, x = 2)\n")?;
+
+    // No VCS is needed since files are not modified.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--fix")
+            .arg("--diff")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    --- a/test.R
+    +++ b/test.R
+    @@ -1,3 +1,3 @@
+     x <- 1
+    -any(is.na(x))
+    +anyNA(x)
+     list(x = 1, x = 2)
+
+    ----- stderr -----
+    1 file would be fixed.
+    "
+    );
+
+    assert_eq!(
+        case.read_file("test.R")?,
+        "x <- 1\nany(is.na(x))\nlist(x = 1, x = 2)\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_diff_unsafe_fixes() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(is.na(x))\nclass(x) == 'foo'")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--diff")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    --- a/test.R
+    +++ b/test.R
+    @@ -1,2 +1,2 @@
+    -any(is.na(x))
+    +anyNA(x)
+     class(x) == 'foo'
+    \\ No newline at end of file
+
+    ----- stderr -----
+    1 file would be fixed.
+    "
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--unsafe-fixes")
+            .arg("--diff")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    --- a/test.R
+    +++ b/test.R
+    @@ -1,2 +1,2 @@
+    -any(is.na(x))
+    -class(x) == 'foo'
+    \\ No newline at end of file
+    +anyNA(x)
+    +inherits(x, 'foo')
+    \\ No newline at end of file
+
+    ----- stderr -----
+    1 file would be fixed.
+    "
+    );
+
+    assert_eq!(
+        case.read_file("test.R")?,
+        "any(is.na(x))\nclass(x) == 'foo'"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_diff_no_changes() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("clean.R", "x <- 1\n"),
+        ("no_fix.R", "list(x = 1, x = 2)\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--fix")
+            .arg("--diff")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_diff_multiple_files() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("b.R", "any(is.na(y))\n"),
+        ("a.R", "any(is.na(x))\n"),
+        ("c.R", "x <- 1\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--fix")
+            .arg("--diff")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    --- a/a.R
+    +++ b/a.R
+    @@ -1 +1 @@
+    -any(is.na(x))
+    +anyNA(x)
+    --- a/b.R
+    +++ b/b.R
+    @@ -1 +1 @@
+    -any(is.na(y))
+    +anyNA(y)
+
+    ----- stderr -----
+    2 files would be fixed.
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_diff_parsing_error() -> anyhow::Result<()> {
+    let case = CliTest::with_files([("test.R", "any(is.na(x))\n"), ("test2.R", "f <-")])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--fix")
+            .arg("--diff")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+    --- a/test.R
+    +++ b/test.R
+    @@ -1 +1 @@
+    -any(is.na(x))
+    +anyNA(x)
+
+    ----- stderr -----
+    Error: Failed to parse test2.R due to syntax errors.
+    "
+    );
+
+    Ok(())
+}
@@ -0,0 +1,232 @@
+use std::path::Path;
+use std::process::Command;
+
+use crate::helpers::{CliTest, CommandExt, create_commit, git_init};
+
+fn git(dir: &Path, args: &[&str]) -> anyhow::Result<()> {
+    Command::new("git").args(args).current_dir(dir).output()?;
+    Ok(())
+}
+
+#[test]
+fn test_diff_base_only_reports_changed_lines() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))\nx <- 1\n"),
+        ("other.R", "any(is.na(y))\n"),
+    ])?;
+
+    git_init(case.root())?;
+    create_commit(&case.root().join("test.R"), case.root())?;
+    create_commit(&case.root().join("other.R"), case.root())?;
+
+    // Modified file: only the new line is reported.
+    case.write_file("test.R", "any(is.na(x))\nx <- 1\nany(is.na(z))\n")?;
+    // Untracked file: all lines are new.
+    case.write_file("new.R", "any(is.na(w))\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--diff-base")
+            .arg("HEAD")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    new.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [3:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 2 issues.
+    2 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_diff_base_multiline_violation() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(\n  is.na(x)\n)\n")?;
+
+    git_init(case.root())?;
+    create_commit(&case.root().join("test.R"), case.root())?;
+
+    // Only the second line of the violation changed.
+    case.write_file("test.R", "any(\n  is.na(y)\n)\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--diff-base")
+            .arg("HEAD")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_diff_base_ignores_changes_in_diverged_base() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(is.na(a))\nx <- 1\n")?;
+
+    git_init(case.root())?;
+    create_commit(&case.root().join("test.R"), case.root())?;
+
+    // The base branch moves on after the current branch was created, and
+    // removes the first line.
+    git(case.root(), &["branch", "upstream"])?;
+    git(case.root(), &["checkout", "upstream"])?;
+    case.write_file("test.R", "x <- 1\n")?;
+    create_commit(&case.root().join("test.R"), case.root())?;
+    git(case.root(), &["checkout", "-"])?;
+
+    // Only the third line was added on the current branch.
+    case.write_file("test.R", "any(is.na(a))\nx <- 1\nany(is.na(b))\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--diff-base")
+            .arg("upstream")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.R [3:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_diff_base_no_changes() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(is.na(x))\n")?;
+
+    git_init(case.root())?;
+    create_commit(&case.root().join("test.R"), case.root())?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--diff-base")
+            .arg("HEAD")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    All checks passed!
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_diff_base_unknown_ref() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(is.na(x))\n")?;
+
+    git_init(case.root())?;
+    create_commit(&case.root().join("test.R"), case.root())?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--diff-base")
+            .arg("does-not-exist")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Failed to get the changes compared to `does-not-exist`:
+    fatal: Not a valid object name does-not-exist
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_diff_base_requires_git() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(is.na(x))\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--diff-base")
+            .arg("HEAD")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: `--diff-base` requires the files to be in a Git repository, but test.R is not.
+    "
+    );
+
+    Ok(())
+}
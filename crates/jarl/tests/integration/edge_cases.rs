@@ -66,7 +66,7 @@ fn test_jarl_with_tabs() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
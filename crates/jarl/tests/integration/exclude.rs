@@ -169,7 +169,7 @@ exclude = ["R/foo.R"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
@@ -219,7 +219,7 @@ include = ["R/foo2.R"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
@@ -302,7 +302,7 @@ fn test_must_start_with_generated_by_to_be_ignored() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
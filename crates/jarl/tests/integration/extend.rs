@@ -0,0 +1,405 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_extend() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "shared/base.toml",
+            r#"
+[lint]
+select = ["any_is_na", "any_duplicated"]
+"#,
+        ),
+        (
+            "project/jarl.toml",
+            r#"
+extend = "../shared/base.toml"
+
+[lint]
+ignore = ["any_duplicated"]
+"#,
+        ),
+        (
+            "project/test.R",
+            "any(is.na(x))\nany(duplicated(x))\nx = 1\n",
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .current_dir(case.root().join("project"))
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_extend_precedence() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "base.toml",
+            r#"
+[lint]
+select = ["any_is_na"]
+extend-select = ["any_duplicated"]
+
+[lint.assignment]
+operator = "="
+"#,
+        ),
+        (
+            "jarl.toml",
+            r#"
+extend = "base.toml"
+
+[lint]
+extend-select = ["assignment"]
+"#,
+        ),
+        ("test.R", "any(is.na(x))\nany(duplicated(x))\nx <- 1\n"),
+    ])?;
+
+    // `extend-select` is appended to the one of the base file, and rule
+    // options of the base file are kept.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [2:1] warning any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+    test.R [3:1] warning assignment Use `=` for assignment.
+
+    ── Summary ──────────────────────────────────────
+    Found 3 issues.
+    3 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    // `select` replaces the whole selection of the base file.
+    case.write_file(
+        "jarl.toml",
+        r#"
+extend = "base.toml"
+
+[lint]
+select = ["assignment"]
+
+[lint.assignment]
+operator = "<-"
+"#,
+    )?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    All checks passed!
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_extend_rule_options_are_merged() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "base.toml",
+            r#"
+[lint]
+select = ["undesirable_function"]
+
+[lint.undesirable_function]
+functions = ["browser", "debug"]
+"#,
+        ),
+        (
+            "jarl.toml",
+            r#"
+extend = "base.toml"
+
+[lint.undesirable_function]
+extend-functions = ["print"]
+"#,
+        ),
+        ("test.R", "browser()\ndebug(f)\nprint(x)\nstop()\n"),
+    ])?;
+
+    // `extend-functions` adds to the functions of the base file instead of
+    // replacing its table of options.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.R [1:1] warning undesirable_function `browser()` is listed as an undesirable function.
+    test.R [2:1] warning undesirable_function `debug()` is listed as an undesirable function.
+    test.R [3:1] warning undesirable_function `print()` is listed as an undesirable function.
+
+    ── Summary ──────────────────────────────────────
+    Found 3 issues.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_extend_cycle() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "extend = \"base.toml\"\n"),
+        ("base.toml", "extend = \"jarl.toml\"\n"),
+        ("test.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Circular `extend` in configuration files:
+      [TEMP_DIR]/jarl.toml
+      extends [TEMP_DIR]/base.toml
+      extends [TEMP_DIR]/jarl.toml
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_extend_missing_file() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "extend = \"base.toml\"\n"),
+        ("test.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Failed to find [TEMP_DIR]/base.toml, extended by [TEMP_DIR]/jarl.toml.
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_extend_error_in_base_file() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "extend = \"base.toml\"\n"),
+        ("base.toml", "[lint]\nselect = [\"foo\"]\n"),
+        ("test.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/base.toml:
+    [TEMP_DIR]/base.toml:2:11: Unknown rule `foo` in `select`.
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_extend_patterns_from_sibling_directory() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "shared/base.toml",
+            r#"
+[lint]
+exclude = ["generated.R"]
+
+[lint.per-file-ignores]
+"tests/**" = ["any_is_na"]
+
+[[lint.overrides]]
+files = ["data-raw/"]
+ignore = ["any_duplicated"]
+"#,
+        ),
+        ("project/jarl.toml", "extend = \"../shared/base.toml\"\n"),
+        ("project/main.R", "any(is.na(x))\n"),
+        ("project/generated.R", "any(is.na(x))\n"),
+        (
+            "project/tests/test.R",
+            "any(is.na(x))\nany(duplicated(x))\n",
+        ),
+        (
+            "project/data-raw/data.R",
+            "any(is.na(x))\nany(duplicated(x))\n",
+        ),
+    ])?;
+
+    // Patterns of the base file are resolved relative to the project.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .current_dir(case.root().join("project"))
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    data-raw/data.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    main.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    tests/test.R [2:1] warning any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 3 issues.
+    3 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_extend_baseline_from_sibling_directory() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "shared/base.toml",
+            "[lint]\nbaseline = \"jarl-baseline.json\"\n",
+        ),
+        ("project/jarl.toml", "extend = \"../shared/base.toml\"\n"),
+        ("project/main.R", "any(is.na(x))\n"),
+    ])?;
+
+    case.command()
+        .current_dir(case.root().join("project"))
+        .arg("check")
+        .arg(".")
+        .arg("--generate-baseline")
+        .arg("jarl-baseline.json")
+        .run();
+
+    // The baseline of the base file is resolved relative to the project, like
+    // its patterns.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .current_dir(case.root().join("project"))
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    All checks passed!
+
+    ── Notes ────────────────────────────────────────
+    1 violation was hidden by the baseline.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
@@ -18,9 +18,15 @@ fn test_help() -> anyhow::Result<()> {
     Usage: jarl [OPTIONS] <COMMAND>
 
     Commands:
-      check   Check a set of files or directories
-      server  Start a language server
-      help    Print this message or the help of the given subcommand(s)
+      check    Check a set of files or directories
+      rule     Explain a rule
+      rules    List all rules
+      config   Inspect the configuration
+      init     Create a `jarl.toml` suited to the project
+      migrate  Migrate the configuration of another linter
+      clean    Remove the cache of lint results
+      server   Start a language server
+      help     Print this message or the help of the given subcommand(s)
 
     Options:
       -h, --help     Print help
@@ -49,9 +55,15 @@ fn test_help() -> anyhow::Result<()> {
     Usage: jarl [OPTIONS] <COMMAND>
 
     Commands:
-      check   Check a set of files or directories
-      server  Start a language server
-      help    Print this message or the help of the given subcommand(s)
+      check    Check a set of files or directories
+      rule     Explain a rule
+      rules    List all rules
+      config   Inspect the configuration
+      init     Create a `jarl.toml` suited to the project
+      migrate  Migrate the configuration of another linter
+      clean    Remove the cache of lint results
+      server   Start a language server
+      help     Print this message or the help of the given subcommand(s)
 
     Options:
       -h, --help     Print help
@@ -80,9 +92,15 @@ fn test_help() -> anyhow::Result<()> {
     Usage: jarl [OPTIONS] <COMMAND>
 
     Commands:
-      check   Check a set of files or directories
-      server  Start a language server
-      help    Print this message or the help of the given subcommand(s)
+      check    Check a set of files or directories
+      rule     Explain a rule
+      rules    List all rules
+      config   Inspect the configuration
+      init     Create a `jarl.toml` suited to the project
+      migrate  Migrate the configuration of another linter
+      clean    Remove the cache of lint results
+      server   Start a language server
+      help     Print this message or the help of the given subcommand(s)
 
     Options:
       -h, --help     Print help
@@ -131,6 +149,9 @@ fn test_help_check() -> anyhow::Result<()> {
           --fix-only
               Apply fixes to resolve lint violations, but don't report on leftover violations. Implies `--fix`.
 
+          --diff
+              Print a diff of the fixes instead of writing them to disk. Implies `--fix`.
+
           --allow-dirty
               Apply fixes even if the Git branch is not clean, meaning that there are uncommitted files.
 
@@ -166,9 +187,19 @@ fn test_help_check() -> anyhow::Result<()> {
               - concise: Print diagnostics in a concise format, one per line
               - github:  Print diagnostics as GitHub format
               - json:    Print diagnostics as JSON
+              - sarif:   Print diagnostics as SARIF 2.1.0
+              - junit:   Print diagnostics as JUnit XML
+              - gitlab:  Print diagnostics as GitLab Code Quality report
+              - rdjson:  Print diagnostics as Reviewdog Diagnostic Format (rdjson)
               
               [default: full]
 
+          --fail-on <LEVEL>
+              Minimum severity of violations that make jarl exit with a non-zero status.
+              
+              [default: info]
+              [possible values: info, warning, error]
+
           --assignment <ASSIGNMENT>
               [DEPRECATED: use `[lint.assignment]` in jarl.toml] Assignment operator to use, can be either `<-` or `=`.
 
@@ -182,6 +213,24 @@ fn test_help_check() -> anyhow::Result<()> {
               Automatically insert a `# jarl-ignore` comment to suppress all violations.
               The default reason can be customized with `--add-jarl-ignore="my_reason"`.
 
+          --stdin-filename <PATH>
+              Lint source code read from stdin as if it was the content of this file. Pass `-` instead of files to check.
+
+          --generate-baseline <PATH>
+              Record all current violations in a baseline file. Use `baseline` in jarl.toml to only report violations that are not in this file.
+
+          --diff-base <REF>
+              Only report violations on lines that were added or modified compared to this Git reference, for example `origin/main`.
+
+          --no-cache
+              Don't read or write the cache of lint results.
+
+          --preview
+              Enable preview rules and behaviors, which are not stable yet.
+
+          --config <CONFIG_OPTION>
+              Path to a configuration file to use instead of the discovered `jarl.toml`, or a TOML option overriding the configuration, for example `lint.quotes.quote = "single"`. Can be repeated.
+
       -h, --help
               Print help (see a summary with '-h')
 
@@ -214,6 +263,7 @@ fn test_help_check() -> anyhow::Result<()> {
       -f, --fix                            Automatically fix issues detected by the linter.
       -u, --unsafe-fixes                   Include fixes that may not retain the original intent of the  code.
           --fix-only                       Apply fixes to resolve lint violations, but don't report on leftover violations. Implies `--fix`.
+          --diff                           Print a diff of the fixes instead of writing them to disk. Implies `--fix`.
           --allow-dirty                    Apply fixes even if the Git branch is not clean, meaning that there are uncommitted files.
           --allow-no-vcs                   Apply fixes even if there is no version control system.
       -s, --select <SELECT>                Names of rules to include, separated by a comma (no spaces). This also accepts names of groups of rules, such as "PERF". [default: ""]
@@ -221,12 +271,19 @@ fn test_help_check() -> anyhow::Result<()> {
       -i, --ignore <IGNORE>                Names of rules to exclude, separated by a comma (no spaces). This also accepts names of groups of rules, such as "PERF". [default: ""]
       -w, --with-timing                    Show the time taken by the function.
       -m, --min-r-version <MIN_R_VERSION>  The mimimum R version to be used by the linter. Some rules only work starting from a specific version.
-          --output-format <OUTPUT_FORMAT>  Output serialization format for violations. [default: full] [possible values: full, concise, github, json]
+          --output-format <OUTPUT_FORMAT>  Output serialization format for violations. [default: full] [possible values: full, concise, github, json, sarif, junit, gitlab, rdjson]
+          --fail-on <LEVEL>                Minimum severity of violations that make jarl exit with a non-zero status. [default: info] [possible values: info, warning, error]
           --assignment <ASSIGNMENT>        [DEPRECATED: use `[lint.assignment]` in jarl.toml] Assignment operator to use, can be either `<-` or `=`.
           --no-default-exclude             Do not apply the default set of file patterns that should be excluded.
           --statistics                     Show counts for every rule with at least one violation.
           --add-jarl-ignore[=<REASON>]     Automatically insert a `# jarl-ignore` comment to suppress all violations.
                                            The default reason can be customized with `--add-jarl-ignore="my_reason"`.
+          --stdin-filename <PATH>          Lint source code read from stdin as if it was the content of this file. Pass `-` instead of files to check.
+          --generate-baseline <PATH>       Record all current violations in a baseline file. Use `baseline` in jarl.toml to only report violations that are not in this file.
+          --diff-base <REF>                Only report violations on lines that were added or modified compared to this Git reference, for example `origin/main`.
+          --no-cache                       Don't read or write the cache of lint results.
+          --preview                        Enable preview rules and behaviors, which are not stable yet.
+          --config <CONFIG_OPTION>         Path to a configuration file to use instead of the discovered `jarl.toml`, or a TOML option overriding the configuration, for example `lint.quotes.quote = "single"`. Can be repeated.
       -h, --help                           Print help (see more with '--help')
 
     Global options:
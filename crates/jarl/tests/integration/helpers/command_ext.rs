@@ -9,6 +9,10 @@ pub trait CommandExt {
     ///
     /// The [Output] has a suitable [Display] method for capturing with insta
     fn run(&mut self) -> Output;
+
+    /// Like [CommandExt::run], but writes `stdin` to the standard input of the
+    /// child process
+    fn run_with_stdin(&mut self, stdin: &str) -> Output;
 }
 
 /// Like [std::process::Output], but augmented with `arguments` and a few extra methods
@@ -58,20 +62,43 @@ impl Output {
 impl CommandExt for Command {
     fn run(&mut self) -> Output {
         let output = self.output().unwrap();
+        to_output(self, output)
+    }
 
-        // Go ahead and turn these into `String`
-        let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
-        let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+    fn run_with_stdin(&mut self, stdin: &str) -> Output {
+        use std::io::Write;
+        use std::process::Stdio;
 
-        let arguments: Vec<String> = self
-            .get_args()
-            .map(|x| x.to_string_lossy().into_owned())
-            .collect();
+        let mut child = self
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(stdin.as_bytes())
+            .unwrap();
+        let output = child.wait_with_output().unwrap();
+        to_output(self, output)
+    }
+}
 
-        let arguments = arguments.join(" ");
+fn to_output(command: &Command, output: std::process::Output) -> Output {
+    // Go ahead and turn these into `String`
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
 
-        Output { status: output.status, stdout, stderr, arguments }
-    }
+    let arguments: Vec<String> = command
+        .get_args()
+        .map(|x| x.to_string_lossy().into_owned())
+        .collect();
+
+    let arguments = arguments.join(" ");
+
+    Output { status: output.status, stdout, stderr, arguments }
 }
 
 impl Display for Output {
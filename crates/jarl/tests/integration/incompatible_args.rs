@@ -216,3 +216,35 @@ fn test_statistics_and_unsafe_fixes_incompatible() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_fix_and_generate_baseline_incompatible() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(is.na(x))")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--fix")
+            .arg("--generate-baseline")
+            .arg("jarl-baseline.json")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--fix' cannot be used with '--generate-baseline <PATH>'
+
+    Usage: jarl check --fix <FILES>...
+
+    For more information, try '--help'.
+    "
+    );
+
+    Ok(())
+}
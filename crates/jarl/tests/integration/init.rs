@@ -0,0 +1,163 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_init_package() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "DESCRIPTION",
+            "Package: foo\nVersion: 0.1.0\nDepends: R (>= 4.2.0)\nSuggests: testthat\n",
+        ),
+        ("R/foo.R", "x <- 1 |> sqrt()\ny <- paste('a', x)\n"),
+        ("tests/testthat/test-foo.R", "expect_equal(y, 'a 1')\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("init")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Info: Wrote jarl.toml for an R package.
+
+    ----- stderr -----
+    "
+    );
+
+    let content = case.read_file("jarl.toml")?;
+    insta::assert_snapshot!(
+        content,
+        @r#"
+    # Generated by `jarl init` for an R package.
+    # All settings are described in https://jarl.etiennebacher.com/reference/config-file
+
+    [lint]
+    # Rules enabled by default are used, as well as the rules about packages
+    # used in the project: testthat.
+    extend-select = ["TESTTHAT"]
+
+    # Rules that require a recent version of R are enabled based on the
+    # minimum R version in the `Depends` field of DESCRIPTION (4.2.0).
+
+    # The options below follow the style of the existing code. The rules they
+    # configure are disabled by default, add them to `extend-select` to enforce
+    # them: "assignment", "pipe_consistency", "quotes".
+
+    [lint.assignment]
+    # Number of uses: "<-" = 2, "=" = 0.
+    operator = "<-"
+
+    [lint.pipe_consistency]
+    # Number of uses: "|>" = 1, "%>%" = 0.
+    pipe = "|>"
+
+    [lint.quotes]
+    # Number of uses: "double" = 0, "single" = 2.
+    quote = "single"
+    "#
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    All checks passed!
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_init_scripts() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("analysis.R", "x = c(\"a\", \"b\")\ny = x %>% rev()\n"),
+        ("jarl.toml", "[lint]\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("init")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: jarl.toml already exists. Use `--force` to overwrite it.
+    "
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("init")
+            .arg("--force")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Info: Wrote jarl.toml for R scripts.
+
+    ----- stderr -----
+    "
+    );
+
+    let content = case.read_file("jarl.toml")?;
+    insta::assert_snapshot!(
+        content,
+        @r#"
+    # Generated by `jarl init` for R scripts.
+    # All settings are described in https://jarl.etiennebacher.com/reference/config-file
+
+    [lint]
+    # Rules enabled by default are used. Use `select`, `extend-select`, and
+    # `ignore` to change the set of rules, e.g. `extend-select = ["TESTTHAT"]`.
+
+    # Rules that require a recent version of R are disabled because the
+    # minimum R version of the project is unknown. Pass `--min-r-version` to
+    # enable them.
+
+    # The options below follow the style of the existing code. The rules they
+    # configure are disabled by default, add them to `extend-select` to enforce
+    # them: "assignment", "pipe_consistency", "quotes".
+
+    [lint.assignment]
+    # Number of uses: "<-" = 0, "=" = 2.
+    operator = "="
+
+    [lint.pipe_consistency]
+    # Number of uses: "|>" = 0, "%>%" = 1.
+    pipe = "%>%"
+
+    [lint.quotes]
+    # Number of uses: "double" = 2, "single" = 0.
+    quote = "double"
+    "#
+    );
+
+    Ok(())
+}
@@ -27,6 +27,7 @@ fn test_must_pass_path() -> anyhow::Result<()> {
       -f, --fix                            Automatically fix issues detected by the linter.
       -u, --unsafe-fixes                   Include fixes that may not retain the original intent of the  code.
           --fix-only                       Apply fixes to resolve lint violations, but don't report on leftover violations. Implies `--fix`.
+          --diff                           Print a diff of the fixes instead of writing them to disk. Implies `--fix`.
           --allow-dirty                    Apply fixes even if the Git branch is not clean, meaning that there are uncommitted files.
           --allow-no-vcs                   Apply fixes even if there is no version control system.
       -s, --select <SELECT>                Names of rules to include, separated by a comma (no spaces). This also accepts names of groups of rules, such as "PERF". [default: ""]
@@ -34,12 +35,19 @@ fn test_must_pass_path() -> anyhow::Result<()> {
       -i, --ignore <IGNORE>                Names of rules to exclude, separated by a comma (no spaces). This also accepts names of groups of rules, such as "PERF". [default: ""]
       -w, --with-timing                    Show the time taken by the function.
       -m, --min-r-version <MIN_R_VERSION>  The mimimum R version to be used by the linter. Some rules only work starting from a specific version.
-          --output-format <OUTPUT_FORMAT>  Output serialization format for violations. [default: full] [possible values: full, concise, github, json]
+          --output-format <OUTPUT_FORMAT>  Output serialization format for violations. [default: full] [possible values: full, concise, github, json, sarif, junit, gitlab, rdjson]
+          --fail-on <LEVEL>                Minimum severity of violations that make jarl exit with a non-zero status. [default: info] [possible values: info, warning, error]
           --assignment <ASSIGNMENT>        [DEPRECATED: use `[lint.assignment]` in jarl.toml] Assignment operator to use, can be either `<-` or `=`.
           --no-default-exclude             Do not apply the default set of file patterns that should be excluded.
           --statistics                     Show counts for every rule with at least one violation.
           --add-jarl-ignore[=<REASON>]     Automatically insert a `# jarl-ignore` comment to suppress all violations.
                                            The default reason can be customized with `--add-jarl-ignore="my_reason"`.
+          --stdin-filename <PATH>          Lint source code read from stdin as if it was the content of this file. Pass `-` instead of files to check.
+          --generate-baseline <PATH>       Record all current violations in a baseline file. Use `baseline` in jarl.toml to only report violations that are not in this file.
+          --diff-base <REF>                Only report violations on lines that were added or modified compared to this Git reference, for example `origin/main`.
+          --no-cache                       Don't read or write the cache of lint results.
+          --preview                        Enable preview rules and behaviors, which are not stable yet.
+          --config <CONFIG_OPTION>         Path to a configuration file to use instead of the discovered `jarl.toml`, or a TOML option overriding the configuration, for example `lint.quotes.quote = "single"`. Can be repeated.
       -h, --help                           Print help (see more with '--help')
 
     Global options:
@@ -124,7 +132,7 @@ fn test_parsing_error_for_some_files() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -235,7 +243,7 @@ fn test_one_lint() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -279,7 +287,7 @@ fn test_several_lints_one_file() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -326,7 +334,7 @@ fn test_several_lints_several_files() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -372,7 +380,7 @@ fn test_not_all_fixable_lints() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -442,7 +450,7 @@ fn test_fix_options() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "#
@@ -473,7 +481,7 @@ fn test_fix_options() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "#
@@ -586,7 +594,7 @@ fn test_safe_and_unsafe_lints() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     1 fixable with the `--fix` option (1 hidden fix can be enabled with the `--unsafe-fixes` option).
 
     ----- stderr -----
@@ -622,7 +630,7 @@ fn test_newline_character_in_string() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
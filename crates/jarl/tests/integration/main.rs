@@ -10,20 +10,35 @@ mod add_jarl_ignore;
 mod allow_dirty;
 mod allow_no_vcs;
 mod assignment;
+mod baseline;
+mod cache;
 mod comments;
+mod config_option;
+mod config_show;
+mod description_config;
+mod diff;
+mod diff_base;
 mod edge_cases;
 mod exclude;
+mod extend;
 mod help;
 mod helpers;
 mod incompatible_args;
+mod init;
 mod jarl;
+mod migrate;
 mod min_r_version;
 mod no_default_exclude;
 mod output_format;
+mod overrides;
+mod required_version;
 mod rmd;
 mod roxygen;
+mod rule_command;
 mod rules;
+mod severity;
 mod statistics;
+mod stdin;
 mod toml;
 mod toml_hierarchical;
 mod toml_rule_args;
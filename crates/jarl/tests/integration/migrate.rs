@@ -0,0 +1,234 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_migrate_lintr() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            ".lintr",
+            r#"linters: linters_with_defaults(
+    line_length_linter(120),
+    any_is_na_linter = NULL,
+    quotes_linter("'")
+  )
+exclusions: list("R/generated.R", "tests/testthat/test-foo.R" = list(quotes_linter = Inf))
+"#,
+        ),
+        ("R/foo.R", "x = \"a\"\nany(is.na(x))\n"),
+        ("R/generated.R", "x = 'a'\n"),
+        ("tests/testthat/test-foo.R", "x <- \"a\"\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("migrate")
+            .arg("lintr")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Info: Wrote jarl.toml from .lintr.
+    Warning: Some settings of .lintr couldn't be migrated:
+      - Linter `line_length_linter` has no equivalent in Jarl.
+
+    ----- stderr -----
+    "
+    );
+
+    let content = case.read_file("jarl.toml")?;
+    insta::assert_snapshot!(
+        content,
+        @r#"
+    # Generated by `jarl migrate lintr` from `.lintr`.
+
+    [lint]
+    extend-select = ["quotes", "assignment"]
+    ignore = ["any_is_na"]
+    exclude = ["R/generated.R"]
+
+    [lint.per-file-ignores]
+    "tests/testthat/test-foo.R" = ["quotes"]
+
+    [lint.quotes]
+    quote = "single"
+    "#
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/foo.R [1:1] warning assignment Use `<-` for assignment.
+    R/foo.R [1:5] warning quotes Prefer single-quotes for string delimiters.
+
+    ── Summary ──────────────────────────────────────
+    Found 2 issues.
+    2 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_migrate_lintr_errors() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("migrate")
+            .arg("lintr")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Could not find .lintr.
+    "
+    );
+
+    case.write_file(".lintr", "linters: list(any_is_na_linter())\n")?;
+    case.write_file("jarl.toml", "[lint]\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("migrate")
+            .arg("lintr")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: jarl.toml already exists. Use `--force` to overwrite it.
+    "
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("migrate")
+            .arg("lintr")
+            .arg("--force")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Info: Wrote jarl.toml from .lintr.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_migrate_nolint() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "R/foo.R",
+            "x <- T # nolint: T_and_F_symbol_linter.
+y <- any(is.na(x)) # nolint
+",
+        ),
+        (
+            "R/bar.R",
+            "# nolint start: any_is_na_linter.
+any(is.na(x))
+# nolint end
+",
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("migrate")
+            .arg("nolint")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Info: Found 3 `# nolint` comments to convert in 2 files. Use `--fix` to convert them.
+    Warning: Some `# nolint` comments couldn't be converted:
+      - R/foo.R:2: Blanket `# nolint` comments can't be converted since `# jarl-ignore` requires a rule name.
+    These comments are still recognized with `lintr-compat-suppressions = true`.
+
+    ----- stderr -----
+    "
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("migrate")
+            .arg("nolint")
+            .arg("--fix")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Info: Converted 3 `# nolint` comments in 2 files.
+    Warning: Some `# nolint` comments couldn't be converted:
+      - R/foo.R:2: Blanket `# nolint` comments can't be converted since `# jarl-ignore` requires a rule name.
+    These comments are still recognized with `lintr-compat-suppressions = true`.
+
+    ----- stderr -----
+    "
+    );
+
+    insta::assert_snapshot!(
+        case.read_file("R/foo.R")?,
+        @"
+    # jarl-ignore true_false_symbol: <reason>
+    x <- T
+    y <- any(is.na(x)) # nolint
+    "
+    );
+    insta::assert_snapshot!(
+        case.read_file("R/bar.R")?,
+        @"
+    # jarl-ignore-start any_is_na: <reason>
+    any(is.na(x))
+    # jarl-ignore-end any_is_na
+    "
+    );
+
+    Ok(())
+}
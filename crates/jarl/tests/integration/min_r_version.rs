@@ -74,7 +74,7 @@ fn test_min_r_version_from_cli_only() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -146,7 +146,7 @@ Depends: R (>= 4.6.0), utils, stats"#,
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -37,7 +37,7 @@ fn test_output_default() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -68,11 +68,11 @@ fn test_output_concise() -> anyhow::Result<()> {
     success: false
     exit_code: 1
     ----- stdout -----
-    test.R [1:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test2.R [1:1] any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+    test.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test2.R [1:1] warning any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -121,7 +121,7 @@ fn test_output_full() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -160,6 +160,7 @@ fn test_output_json() -> anyhow::Result<()> {
             "body": "`any(is.na(...))` is inefficient.",
             "suggestion": "Use `anyNA(...)` instead."
           },
+          "severity": "warning",
           "filename": "test.R",
           "range": [
             0,
@@ -182,6 +183,7 @@ fn test_output_json() -> anyhow::Result<()> {
             "body": "`any(duplicated(...))` is inefficient.",
             "suggestion": "Use `anyDuplicated(...) > 0` instead."
           },
+          "severity": "warning",
           "filename": "test2.R",
           "range": [
             0,
@@ -229,6 +231,7 @@ fn test_output_json() -> anyhow::Result<()> {
             "body": "`any(is.na(...))` is inefficient.",
             "suggestion": "Use `anyNA(...)` instead."
           },
+          "severity": "warning",
           "filename": "test.R",
           "range": [
             0,
@@ -251,6 +254,7 @@ fn test_output_json() -> anyhow::Result<()> {
             "body": "`any(duplicated(...))` is inefficient.",
             "suggestion": "Use `anyDuplicated(...) > 0` instead."
           },
+          "severity": "warning",
           "filename": "test2.R",
           "range": [
             0,
@@ -359,7 +363,7 @@ fn test_with_parsing_error() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -381,10 +385,10 @@ fn test_with_parsing_error() -> anyhow::Result<()> {
     success: false
     exit_code: 255
     ----- stdout -----
-    test.R [1:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -414,6 +418,7 @@ fn test_with_parsing_error() -> anyhow::Result<()> {
             "body": "`any(is.na(...))` is inefficient.",
             "suggestion": "Use `anyNA(...)` instead."
           },
+          "severity": "warning",
           "filename": "test.R",
           "range": [
             0,
@@ -464,3 +469,301 @@ fn test_with_parsing_error() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_output_sarif() -> anyhow::Result<()> {
+    let case = CliTest::with_files([("test.R", "any(is.na(x))"), ("test2.R", "any(")])?;
+
+    let output = case
+        .command()
+        .arg("check")
+        .arg(".")
+        .arg("--output-format")
+        .arg("sarif")
+        .run();
+
+    let sarif: serde_json::Value = serde_json::from_str(&output.stdout)?;
+    let run = &sarif["runs"][0];
+
+    assert_eq!(sarif["version"], "2.1.0");
+    assert_eq!(run["tool"]["driver"]["name"], "jarl");
+    assert_eq!(
+        run["tool"]["driver"]["rules"].as_array().unwrap().len(),
+        jarl_core::rule_set::Rule::all().len()
+    );
+    assert_eq!(run["tool"]["driver"]["rules"][2]["id"], "any_is_na");
+    assert_eq!(run["invocations"][0]["executionSuccessful"], false);
+
+    insta::assert_snapshot!(
+        serde_json::to_string_pretty(&run["results"])?,
+        @r#"
+    [
+      {
+        "fixes": [
+          {
+            "artifactChanges": [
+              {
+                "artifactLocation": {
+                  "uri": "test.R"
+                },
+                "replacements": [
+                  {
+                    "deletedRegion": {
+                      "byteLength": 13,
+                      "byteOffset": 0
+                    },
+                    "insertedContent": {
+                      "text": "anyNA(x)"
+                    }
+                  }
+                ]
+              }
+            ],
+            "description": {
+              "text": "Safe fix"
+            }
+          }
+        ],
+        "level": "warning",
+        "locations": [
+          {
+            "physicalLocation": {
+              "artifactLocation": {
+                "uri": "test.R"
+              },
+              "region": {
+                "byteLength": 13,
+                "byteOffset": 0,
+                "startColumn": 1,
+                "startLine": 1
+              }
+            }
+          }
+        ],
+        "message": {
+          "text": "`any(is.na(...))` is inefficient. Use `anyNA(...)` instead."
+        },
+        "ruleId": "any_is_na",
+        "ruleIndex": 2
+      }
+    ]
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_output_junit() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))\nany(duplicated(x))"),
+        ("clean.R", "x <- 1"),
+        ("test2.R", "any("),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("junit")
+            .run()
+            .normalize_os_executable_name(),
+        @r#"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+    <?xml version="1.0" encoding="UTF-8"?>
+    <testsuites name="jarl" tests="4" failures="2" errors="1">
+      <testsuite name="clean.R" tests="1" failures="0" errors="0">
+        <testcase name="jarl" classname="clean.R" file="clean.R"/>
+      </testsuite>
+      <testsuite name="test.R" tests="2" failures="2" errors="0">
+        <testcase name="any_is_na (1:1)" classname="test.R" file="test.R" line="1">
+          <failure message="`any(is.na(...))` is inefficient." type="any_is_na">line 1, column 1: `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.</failure>
+        </testcase>
+        <testcase name="any_duplicated (2:1)" classname="test.R" file="test.R" line="2">
+          <failure message="`any(duplicated(...))` is inefficient." type="any_duplicated">line 2, column 1: `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) &gt; 0` instead.</failure>
+        </testcase>
+      </testsuite>
+      <testsuite name="test2.R" tests="1" failures="0" errors="1">
+        <testcase name="jarl" classname="test2.R" file="test2.R">
+          <error message="Failed to get checks for file: test2.R: Failed to parse test2.R due to syntax errors."/>
+        </testcase>
+      </testsuite>
+    </testsuites>
+
+    ----- stderr -----
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_output_gitlab() -> anyhow::Result<()> {
+    let case = CliTest::with_files([(
+        "test.R",
+        "any(is.na(x))\nany(is.na(x))\nany(duplicated(\n  x\n))",
+    )])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("gitlab")
+            .run()
+            .normalize_os_executable_name(),
+        @r#"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    [
+      {
+        "check_name": "any_is_na",
+        "description": "`any(is.na(...))` is inefficient.",
+        "fingerprint": "14dd9a996dc7fc59",
+        "location": {
+          "lines": {
+            "begin": 1,
+            "end": 1
+          },
+          "path": "test.R"
+        },
+        "severity": "minor",
+        "type": "issue"
+      },
+      {
+        "check_name": "any_is_na",
+        "description": "`any(is.na(...))` is inefficient.",
+        "fingerprint": "14da34996dc51930",
+        "location": {
+          "lines": {
+            "begin": 2,
+            "end": 2
+          },
+          "path": "test.R"
+        },
+        "severity": "minor",
+        "type": "issue"
+      },
+      {
+        "check_name": "any_duplicated",
+        "description": "`any(duplicated(...))` is inefficient.",
+        "fingerprint": "ad3c69528ad91412",
+        "location": {
+          "lines": {
+            "begin": 3,
+            "end": 5
+          },
+          "path": "test.R"
+        },
+        "severity": "minor",
+        "type": "issue"
+      }
+    ]
+    ----- stderr -----
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_output_gitlab_stable_fingerprints() -> anyhow::Result<()> {
+    let case = CliTest::with_files([("test.R", "any(is.na(x))")])?;
+
+    let fingerprint = |case: &CliTest| -> anyhow::Result<serde_json::Value> {
+        let output = case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("gitlab")
+            .run();
+        let issues: serde_json::Value = serde_json::from_str(&output.stdout)?;
+        Ok(issues[0]["fingerprint"].clone())
+    };
+
+    let before = fingerprint(&case)?;
+
+    // Moving and re-indenting the code doesn't change the fingerprint.
+    case.write_file("test.R", "x <- 1\n\nif (TRUE) {\n  any(is.na(x))\n}")?;
+    assert_eq!(before, fingerprint(&case)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_output_rdjson() -> anyhow::Result<()> {
+    let case = CliTest::with_files([("test.R", "x <- 1\n  any(is.na(x))")])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("rdjson")
+            .run()
+            .normalize_os_executable_name(),
+        @r#"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {
+      "diagnostics": [
+        {
+          "code": {
+            "url": "https://jarl.etiennebacher.com/rules/any_is_na",
+            "value": "any_is_na"
+          },
+          "location": {
+            "path": "test.R",
+            "range": {
+              "end": {
+                "column": 16,
+                "line": 2
+              },
+              "start": {
+                "column": 3,
+                "line": 2
+              }
+            }
+          },
+          "message": "`any(is.na(...))` is inefficient. Use `anyNA(...)` instead.",
+          "severity": "WARNING",
+          "suggestions": [
+            {
+              "range": {
+                "end": {
+                  "column": 16,
+                  "line": 2
+                },
+                "start": {
+                  "column": 3,
+                  "line": 2
+                }
+              },
+              "text": "anyNA(x)"
+            }
+          ]
+        }
+      ],
+      "source": {
+        "name": "jarl",
+        "url": "https://jarl.etiennebacher.com"
+      }
+    }
+    ----- stderr -----
+    "#
+    );
+
+    Ok(())
+}
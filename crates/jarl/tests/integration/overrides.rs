@@ -0,0 +1,391 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_per_file_ignores() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[lint.per-file-ignores]
+"tests/**" = ["any_is_na"]
+"#,
+        ),
+        ("R/foo.R", "any(is.na(x))\n"),
+        ("tests/test-foo.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/foo.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_overrides_rule_selection() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_na"]
+
+[[lint.overrides]]
+files = ["tests/"]
+ignore = ["any_is_na"]
+extend-select = ["any_duplicated"]
+"#,
+        ),
+        ("R/foo.R", "any(is.na(x))\nany(duplicated(x))\n"),
+        ("tests/test-foo.R", "any(is.na(x))\nany(duplicated(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/foo.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    tests/test-foo.R [2:1] warning any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 2 issues.
+    2 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    // `--ignore` in the CLI applies to all files.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--ignore")
+            .arg("any_duplicated")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/foo.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_overrides_select_with_cli_extend_select() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[[lint.overrides]]
+files = ["tests/"]
+select = ["any_is_na"]
+"#,
+        ),
+        ("R/foo.R", "any(is.na(x))\nany(duplicated(x))\n"),
+        ("tests/test-foo.R", "any(is.na(x))\nany(duplicated(x))\n"),
+    ])?;
+
+    // `--extend-select` in the CLI applies to all files, including those
+    // matched by an override that sets `select`.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--extend-select")
+            .arg("any_duplicated")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/foo.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    R/foo.R [2:1] warning any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+    tests/test-foo.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    tests/test-foo.R [2:1] warning any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 4 issues.
+    4 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_overrides_rule_options() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["assignment"]
+
+[[lint.overrides]]
+files = ["data-raw/**"]
+
+[lint.overrides.assignment]
+operator = "="
+"#,
+        ),
+        ("R/foo.R", "x = 1\ny <- 2\n"),
+        ("data-raw/foo.R", "x = 1\ny <- 2\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/foo.R [1:1] warning assignment Use `<-` for assignment.
+    data-raw/foo.R [2:1] warning assignment Use `=` for assignment.
+
+    ── Summary ──────────────────────────────────────
+    Found 2 issues.
+    2 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_overrides_are_applied_in_order() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_na"]
+
+[[lint.overrides]]
+files = ["tests/"]
+ignore = ["any_is_na"]
+
+[[lint.overrides]]
+files = ["tests/test-keep.R"]
+extend-select = ["any_is_na"]
+"#,
+        ),
+        ("tests/test-foo.R", "any(is.na(x))\n"),
+        ("tests/test-keep.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    tests/test-keep.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_overrides_unknown_rule_is_error() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[[lint.overrides]]
+files = ["tests/"]
+ignore = ["foo"]
+"#,
+        ),
+        ("test.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:4:11: Unknown rule `foo` in `ignore` of `[[lint.overrides]]`.
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_overrides_without_files_is_error() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[[lint.overrides]]
+files = []
+ignore = ["any_is_na"]
+"#,
+        ),
+        ("test.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    Field `files` in `[[lint.overrides]]` must contain at least one pattern.
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_overrides_package_specific_rules() -> anyhow::Result<()> {
+    let code = "library(dplyr)\nx |> filter(a > 1 | is.na(a))\n";
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_na"]
+
+[[lint.overrides]]
+files = ["R/dplyr/**"]
+extend-select = ["DPLYR"]
+"#,
+        ),
+        ("R/foo.R", code),
+        ("R/dplyr/foo.R", code),
+        // Installed packages are read from the library without R.
+        ("lib/dplyr/DESCRIPTION", "Package: dplyr\nVersion: 1.2.0\n"),
+        ("lib/dplyr/NAMESPACE", "export(filter)\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .env("HOME", case.root())
+            .env("XDG_CACHE_HOME", case.root().join("cache"))
+            .env("PATH", "")
+            .env("R_LIBS", case.root().join("lib"))
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/dplyr/foo.R [2:6] warning dplyr_filter_out This `filter()` contains complex condition(s).
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_required_version_satisfied() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+required-version = ">=0.1"
+
+[lint]
+select = ["any_is_na"]
+"#,
+        ),
+        ("test.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.R [1:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 issue.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_required_version_not_satisfied() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "required-version = \">=100.0\"\n"),
+        ("test.R", "any(is.na(x))\n"),
+    ])?;
+
+    // Don't depend on the current version of Jarl in the snapshot.
+    let mut output = case
+        .command()
+        .arg("check")
+        .arg(".")
+        .run()
+        .normalize_os_executable_name()
+        .normalize_temp_paths();
+    output.stderr = output
+        .stderr
+        .replace(env!("CARGO_PKG_VERSION"), "[VERSION]");
+
+    insta::assert_snapshot!(
+        output,
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: [TEMP_DIR]/jarl.toml requires Jarl >=100.0 but the installed version is [VERSION].
+    Install a version of Jarl that matches `required-version`, or update it.
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_required_version_invalid() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "required-version = \"foo\"\n"),
+        ("test.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @r#"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:1:20: Invalid `required-version`: "foo" (unexpected character 'f' while parsing major version number).
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_required_version_checked_before_unknown_fields() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            "required-version = \">=100.0\"\n\n[lint]\nfield-from-the-future = true\n",
+        ),
+        ("test.R", "any(is.na(x))\n"),
+    ])?;
+
+    // A file written for a newer version of Jarl reports the version mismatch
+    // rather than the fields that this version doesn't know.
+    let mut output = case
+        .command()
+        .arg("check")
+        .arg(".")
+        .run()
+        .normalize_os_executable_name()
+        .normalize_temp_paths();
+    output.stderr = output
+        .stderr
+        .replace(env!("CARGO_PKG_VERSION"), "[VERSION]");
+
+    insta::assert_snapshot!(
+        output,
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: [TEMP_DIR]/jarl.toml requires Jarl >=100.0 but the installed version is [VERSION].
+    Install a version of Jarl that matches `required-version`, or update it.
+    "
+    );
+
+    Ok(())
+}
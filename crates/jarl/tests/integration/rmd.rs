@@ -43,7 +43,7 @@ any(is.na(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
@@ -90,7 +90,7 @@ any(is.na(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
@@ -147,7 +147,7 @@ any(is.na(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
 
     ----- stderr -----
     "
@@ -306,7 +306,7 @@ any(is.na(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
@@ -399,7 +399,7 @@ any(is.na(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
 
     ----- stderr -----
     "
@@ -453,7 +453,7 @@ any(is.na(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
 
     ----- stderr -----
     "
@@ -531,7 +531,7 @@ any(is.na(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 4 errors.
+    Found 4 issues.
 
     ----- stderr -----
     "
@@ -583,7 +583,7 @@ any(is.na(y))
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
@@ -634,7 +634,7 @@ any(is.na(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
 
     ----- stderr -----
     "
@@ -723,7 +723,7 @@ any(is.na(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
@@ -861,7 +861,7 @@ any(is.na(1))
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
 
     ----- stderr -----
     "
@@ -913,7 +913,7 @@ any(is.na(1))
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
 
     ----- stderr -----
     "
@@ -1001,7 +1001,7 @@ fn test_rmd_ignore_file_truly_unused_outdated() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
@@ -1048,7 +1048,7 @@ any(is.na(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
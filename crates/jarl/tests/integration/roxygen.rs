@@ -45,7 +45,7 @@ foo <- function(x) x
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
@@ -94,7 +94,7 @@ foo <- function(x) x
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
@@ -248,7 +248,7 @@ bar <- function(y) y
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
 
     ----- stderr -----
     "
@@ -394,7 +394,7 @@ foo <- function(x) x
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
@@ -446,7 +446,7 @@ foo <- function(x) x
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
@@ -507,7 +507,7 @@ foo <- function(x) x
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
 
     ----- stderr -----
     "
@@ -567,7 +567,7 @@ f <- function() 1
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
@@ -734,7 +734,7 @@ foo <- function(x) x
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
@@ -844,7 +844,7 @@ foo <- function(x) x
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
 
     ----- stderr -----
     "
@@ -0,0 +1,174 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_rule() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("rule")
+            .arg("any_is_na")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # any_is_na
+
+    Categories: PERF
+    Enabled by default: yes
+    Fix: safe
+    Default severity: warning
+    Added in: 0.0.8
+
+    ## What it does
+
+    Checks for usage of `any(is.na(...))`, `NA %in% x`, and `NA %notin% x`.
+
+    ## Why is this bad?
+
+    While both cases are valid R code, the base R function `anyNA()` is more
+    efficient (both in speed and memory used).
+
+    ## Example
+
+    ```r
+    x <- c(1:10000, NA)
+    any(is.na(x))
+    NA %in% x
+    NA %notin% x
+    ```
+
+    Use instead:
+    ```r
+    x <- c(1:10000, NA)
+    anyNA(x)
+    !anyNA(x)
+    ```
+
+    ## References
+
+    See `?anyNA`
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_rule_json() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+
+    let output = case
+        .command()
+        .arg("rule")
+        .arg("browser")
+        .arg("--output-format")
+        .arg("json")
+        .run();
+
+    let info: serde_json::Value = serde_json::from_str(&output.stdout)?;
+    assert_eq!(info["name"], "browser");
+    assert_eq!(info["categories"], serde_json::json!(["CORR"]));
+    assert_eq!(info["fix"], "safe");
+    assert_eq!(info["deprecated"]["version"], "0.5.0");
+    assert_eq!(info["deprecated"]["replacement"], "undesirable_function");
+    assert!(
+        info["documentation"]
+            .as_str()
+            .unwrap()
+            .starts_with("## What it does")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_rule_unknown() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("rule")
+            .arg("foo")
+            .run()
+            .normalize_os_executable_name(),
+        @r#"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Unknown rule: "foo". Use `jarl rules` to list all available rules.
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_rules() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("rules")
+            .arg("--category")
+            .arg("DPLYR")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    dplyr_filter_out        DPLYR             fix: safe, disabled by default
+    dplyr_group_by_ungroup  DPLYR             fix: safe, disabled by default
+
+    ----- stderr -----
+    "
+    );
+
+    let output = case
+        .command()
+        .arg("rules")
+        .arg("--output-format")
+        .arg("json")
+        .run();
+    let rules: serde_json::Value = serde_json::from_str(&output.stdout)?;
+    assert_eq!(
+        rules.as_array().unwrap().len(),
+        jarl_core::rule_set::Rule::all().len()
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("rules")
+            .arg("--category")
+            .arg("perf")
+            .run()
+            .normalize_os_executable_name(),
+        @r#"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Unknown category: "perf". Expected one of: COMM, CORR, SUSP, PERF, READ, TESTTHAT, DPLYR.
+    "#
+    );
+
+    Ok(())
+}
@@ -165,7 +165,7 @@ fn test_correct_rule_selection_and_exclusion() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -209,7 +209,7 @@ any(is.na(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fix is available with the `--fix --unsafe-fixes` option.
 
     ----- stderr -----
@@ -248,7 +248,7 @@ any(is.na(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     1 fixable with the `--fix` option (1 hidden fix can be enabled with the `--unsafe-fixes` option).
 
     ----- stderr -----
@@ -279,7 +279,7 @@ any(is.na(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fix is available with the `--fix --unsafe-fixes` option.
 
     ----- stderr -----
@@ -323,7 +323,7 @@ any(is.na(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -375,7 +375,7 @@ any(is.na(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -470,7 +470,7 @@ any(is.na(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fix is available with the `--fix --unsafe-fixes` option.
 
     ----- stderr -----
@@ -513,7 +513,7 @@ expect_equal(foo(x), TRUE)
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -565,7 +565,7 @@ expect_equal(foo(x), TRUE)
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -598,7 +598,7 @@ expect_equal(foo(x), TRUE)
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -650,7 +650,7 @@ expect_equal(foo(x), TRUE)
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -689,7 +689,7 @@ expect_equal(foo(x), TRUE)
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -752,7 +752,7 @@ fn test_deprecated_rule_warning_from_cli() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ── Warnings ─────────────────────────────────────
     Rule `browser` is deprecated since v0.5.0. Use `undesirable_function` instead.
@@ -798,7 +798,7 @@ select = ["browser"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ── Warnings ─────────────────────────────────────
     Rule `browser` is deprecated since v0.5.0. Use `undesirable_function` instead.
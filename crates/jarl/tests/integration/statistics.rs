@@ -113,28 +113,28 @@ any(is.na(x))
     success: false
     exit_code: 1
     ----- stdout -----
-    test.R [2:2] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [3:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [4:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [5:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [6:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [7:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [8:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [9:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [10:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [11:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [12:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [13:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [14:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [15:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [16:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [17:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [18:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [2:2] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [3:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [4:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [5:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [6:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [7:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [8:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [9:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [10:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [11:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [12:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [13:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [14:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [15:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [16:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [17:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [18:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
 
     ── Summary ──────────────────────────────────────
-    Found 17 errors.
+    Found 17 issues.
     17 fixable with the `--fix` option.
-    More than 15 errors reported, use `--statistics` to get the count by rule.
+    More than 15 issues reported, use `--statistics` to get the count by rule.
 
     ----- stderr -----
     "
@@ -183,26 +183,26 @@ any(is.na(x))
     success: false
     exit_code: 1
     ----- stdout -----
-    test.R [2:2] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [3:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [4:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [5:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [6:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [7:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [8:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [9:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [10:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [11:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [12:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [13:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [14:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [15:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [16:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [17:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
-    test.R [18:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [2:2] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [3:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [4:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [5:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [6:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [7:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [8:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [9:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [10:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [11:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [12:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [13:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [14:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [15:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [16:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [17:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [18:1] warning any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
 
     ── Summary ──────────────────────────────────────
-    Found 17 errors.
+    Found 17 issues.
     17 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -0,0 +1,156 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_stdin() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("--stdin-filename")
+            .arg("R/foo.R")
+            .arg("-")
+            .run_with_stdin("x <- 1\nany(is.na(x))\n")
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    warning: any_is_na
+     --> R/foo.R:2:1
+      |
+    2 | any(is.na(x))
+      | ------------- `any(is.na(...))` is inefficient.
+      |
+      = help: Use `anyNA(...)` instead.
+
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_stdin_uses_config_of_filename() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "[lint]\nselect = [\"any_is_na\"]"),
+        ("sub/jarl.toml", "[lint]\nselect = [\"any_duplicated\"]"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("--stdin-filename")
+            .arg("sub/foo.R")
+            .arg("--output-format")
+            .arg("concise")
+            .arg("-")
+            .run_with_stdin("any(is.na(x))\nany(duplicated(x))\n")
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    sub/foo.R [2:1] warning any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_stdin_fix() -> anyhow::Result<()> {
+    let case = CliTest::with_files([("R/foo.R", "# content on disk is ignored\n")])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("--stdin-filename")
+            .arg("R/foo.R")
+            .arg("--fix")
+            .arg("--output-format")
+            .arg("concise")
+            .arg("-")
+            .run_with_stdin("any(is.na(x))\nx == NA\n")
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyNA(x)
+    is.na(x)
+
+    ----- stderr -----
+    "
+    );
+
+    // Violations without fixes are reported on stderr.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("--stdin-filename")
+            .arg("R/foo.R")
+            .arg("--fix")
+            .arg("--output-format")
+            .arg("concise")
+            .arg("-")
+            .run_with_stdin("any(is.na(x))\nfor (x in x) {}\n")
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    anyNA(x)
+    for (x in x) {}
+
+    ----- stderr -----
+    R/foo.R [2:6] warning for_loop_index Don't re-use any sequence symbols as the index symbol in a for loop.
+    "
+    );
+
+    // The file on disk is not modified.
+    assert_eq!(
+        std::fs::read_to_string(case.root().join("R/foo.R"))?,
+        "# content on disk is ignored\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_stdin_requires_filename() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("-")
+            .run_with_stdin("any(is.na(x))\n")
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Reading from stdin with `-` requires `--stdin-filename`.
+    "
+    );
+
+    Ok(())
+}
@@ -43,7 +43,7 @@ fn test_empty_toml_uses_all_rules() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -112,7 +112,8 @@ select = [""]
 
     ----- stderr -----
     jarl failed
-      Cause: Unknown rules in field `select` in 'jarl.toml': "" (empty or whitespace-only not allowed)
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:3:11: Rule names in `select` can't be empty.
     "#
     );
 
@@ -163,7 +164,7 @@ ignore = []
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -186,7 +187,8 @@ ignore = [""]
             .arg("check")
             .arg(".")
             .run()
-            .normalize_os_executable_name(),
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
         @r#"
 
     success: false
@@ -195,7 +197,8 @@ ignore = [""]
 
     ----- stderr -----
     jarl failed
-      Cause: Unknown rules in field `ignore` in 'jarl.toml': "" (empty or whitespace-only not allowed)
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:3:11: Rule names in `ignore` can't be empty.
     "#
     );
 
@@ -237,7 +240,7 @@ select = ["any_is_na"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -297,7 +300,7 @@ any(duplicated(x))
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     1 fixable with the `--fix` option (1 hidden fix can be enabled with the `--unsafe-fixes` option).
 
     ----- stderr -----
@@ -343,7 +346,7 @@ ignore = ["any_duplicated"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -403,7 +406,7 @@ length(levels(x))"#,
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -460,7 +463,7 @@ length(levels(x))"#,
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -515,7 +518,7 @@ length(levels(x))"#,
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -601,7 +604,8 @@ select = ["any_is_na", "foo"]
 
     ----- stderr -----
     jarl failed
-      Cause: Unknown rules in field `select` in 'jarl.toml': foo
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:3:24: Unknown rule `foo` in `select`.
     "
     );
 
@@ -637,7 +641,9 @@ ignore = ["foo", "bar"]
 
     ----- stderr -----
     jarl failed
-      Cause: Unknown rules in field `ignore` in 'jarl.toml': foo, bar
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:3:11: Unknown rule `foo` in `ignore`.
+    [TEMP_DIR]/jarl.toml:3:18: Unknown rule `bar` in `ignore`.
     "
     );
 
@@ -717,7 +723,51 @@ unknown_field = ["value"]
     ----- stderr -----
     jarl failed
       Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
-    Unknown field `unknown_field` in `[lint]`. Expected one of: `select`, `extend-select`, `ignore`, `fixable`, `unfixable`, `exclude`, `default-exclude`, `include`, `check-roxygen`, `fix-roxygen`.
+    [TEMP_DIR]/jarl.toml:4:1: Unknown field `unknown_field` in `[lint]`. Expected one of: `select`, `extend-select`, `ignore`, `fixable`, `unfixable`, `exclude`, `default-exclude`, `include`, `check-roxygen`, `fix-roxygen`, `lintr-compat-suppressions`, `preview`, `baseline`, `per-file-ignores`, `overrides`.
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_toml_reports_all_errors_with_suggestions() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_naa", "any_duplicate"]
+ignor = ["PERF"]
+
+[lint.quotes]
+quot = "single"
+"#,
+        ),
+        ("test.R", "any(is.na(x))"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:3:11: Unknown rule `any_is_naa` in `select`. Did you mean `any_is_na`?
+    [TEMP_DIR]/jarl.toml:3:25: Unknown rule `any_duplicate` in `select`. Did you mean `any_duplicated`?
+    [TEMP_DIR]/jarl.toml:4:1: Unknown field `ignor` in `[lint]`. Did you mean `ignore`?
+    [TEMP_DIR]/jarl.toml:7:1: Unknown field `quot` in `[lint.quotes]`. Did you mean `quote`?
     "
     );
 
@@ -766,7 +816,7 @@ fn test_toml_without_linter_section() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -805,7 +855,8 @@ ignore = ["any_duplicated", "", "any_is_na"]
 
     ----- stderr -----
     jarl failed
-      Cause: Unknown rules in field `ignore` in 'jarl.toml': "" (empty or whitespace-only not allowed)
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:3:29: Rule names in `ignore` can't be empty.
     "#
     );
 
@@ -841,7 +892,8 @@ select = ["any_is_na", "   ", "any_duplicated"]
 
     ----- stderr -----
     jarl failed
-      Cause: Unknown rules in field `select` in 'jarl.toml': "" (empty or whitespace-only not allowed)
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:3:24: Rule names in `select` can't be empty.
     "#
     );
 
@@ -882,7 +934,7 @@ fn test_no_toml_file_uses_all_rules() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -961,7 +1013,7 @@ default-exclude = false
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -1079,7 +1131,7 @@ exclude = ["excluded.R"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -1125,7 +1177,7 @@ exclude = ["excluded_dir/"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -1172,7 +1224,7 @@ exclude = ["test-*.R"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -1220,7 +1272,7 @@ exclude = ["excluded.R", "temp/", "*.tmp.R"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -1276,7 +1328,7 @@ exclude = ["custom_exclude.R"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -1323,7 +1375,7 @@ exclude = ["**/test/**"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -1368,7 +1420,7 @@ exclude = []
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -1520,7 +1572,7 @@ fixable = ["any_is_na"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
 
     ----- stderr -----
     "
@@ -1804,7 +1856,8 @@ fixable = ["invalid_rule_name"]
             .arg("check")
             .arg(".")
             .run()
-            .normalize_os_executable_name(),
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
         @"
 
     success: false
@@ -1813,7 +1866,8 @@ fixable = ["invalid_rule_name"]
 
     ----- stderr -----
     jarl failed
-      Cause: Unknown rules in field `fixable` in 'jarl.toml': invalid_rule_name
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:3:12: Unknown rule `invalid_rule_name` in `fixable`.
     "
     );
 
@@ -1839,7 +1893,8 @@ unfixable = ["invalid_rule_name"]
             .arg("check")
             .arg(".")
             .run()
-            .normalize_os_executable_name(),
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
         @"
 
     success: false
@@ -1848,7 +1903,8 @@ unfixable = ["invalid_rule_name"]
 
     ----- stderr -----
     jarl failed
-      Cause: Unknown rules in field `unfixable` in 'jarl.toml': invalid_rule_name
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:3:14: Unknown rule `invalid_rule_name` in `unfixable`.
     "
     );
 
@@ -1900,7 +1956,7 @@ fixable = ["any_is_na"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -1998,7 +2054,7 @@ expect_equal(foo(x), TRUE)
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -2061,7 +2117,7 @@ expect_equal(foo(x), TRUE)
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -2090,7 +2146,8 @@ extend-select = ["FOO"]
             .arg("check")
             .arg(".")
             .run()
-            .normalize_os_executable_name(),
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
         @"
 
     success: false
@@ -2099,7 +2156,8 @@ extend-select = ["FOO"]
 
     ----- stderr -----
     jarl failed
-      Cause: Unknown rules in field `extend-select` in 'jarl.toml': FOO
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:3:18: Unknown rule `FOO` in `extend-select`.
     "
     );
 
@@ -2142,7 +2200,7 @@ include = ["included.R"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -2188,7 +2246,7 @@ include = ["R/"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -2243,7 +2301,7 @@ include = ["R-*.R"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -2288,7 +2346,7 @@ include = []
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -2336,7 +2394,7 @@ exclude = ["R/generated.R"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -2397,7 +2455,7 @@ include = ["**/*.{Rmd,qmd}"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
 
     ----- stderr -----
     "
@@ -2534,7 +2592,7 @@ select = ["any_duplicated"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -2591,7 +2649,7 @@ select = ["any_is_na"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -2657,7 +2715,7 @@ select = ["any_duplicated"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -2725,7 +2783,7 @@ exclude = ["bar.R"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -2793,7 +2851,7 @@ include = ["foo.R"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -42,7 +42,7 @@ fn test_look_for_toml_in_parent_directories() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -85,7 +85,7 @@ ignore = ["any_is_na"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ── Notes ────────────────────────────────────────
@@ -143,7 +143,7 @@ ignore = ["any_duplicated"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -189,7 +189,7 @@ fn test_no_toml_uses_defaults() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 2 errors.
+    Found 2 issues.
     2 fixable with the `--fix` option.
 
     ----- stderr -----
@@ -236,7 +236,7 @@ ignore = ["any_duplicated"]
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 1 issue.
     1 fixable with the `--fix` option.
 
     ── Notes ────────────────────────────────────────
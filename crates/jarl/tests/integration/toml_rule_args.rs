@@ -31,12 +31,8 @@ unknown-option = "foo"
 
     ----- stderr -----
     jarl failed
-      Cause: Failed to parse [TEMP_DIR]/jarl.toml:
-    TOML parse error at line 3, column 1
-      |
-    3 | unknown-option = "foo"
-      | ^^^^^^^^^^^^^^
-    unknown field `unknown-option`, expected `operator`
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:3:1: Unknown field `unknown-option` in `[lint.assignment]`. Expected one of: `operator`.
     "#
     );
 
@@ -116,12 +112,8 @@ unknown-option = ["list"]
 
     ----- stderr -----
     jarl failed
-      Cause: Failed to parse [TEMP_DIR]/jarl.toml:
-    TOML parse error at line 5, column 1
-      |
-    5 | unknown-option = ["list"]
-      | ^^^^^^^^^^^^^^
-    unknown field `unknown-option`, expected `skipped-functions` or `extend-skipped-functions`
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:5:1: Unknown field `unknown-option` in `[lint.duplicated_arguments]`. Expected one of: `skipped-functions`, `extend-skipped-functions`.
     "#
     );
 
@@ -201,12 +193,8 @@ unknown-option = ["list"]
 
     ----- stderr -----
     jarl failed
-      Cause: Failed to parse [TEMP_DIR]/jarl.toml:
-    TOML parse error at line 5, column 1
-      |
-    5 | unknown-option = ["list"]
-      | ^^^^^^^^^^^^^^
-    unknown field `unknown-option`, expected `skipped-functions` or `extend-skipped-functions`
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:5:1: Unknown field `unknown-option` in `[lint.implicit_assignment]`. Expected one of: `skipped-functions`, `extend-skipped-functions`.
     "#
     );
 
@@ -246,12 +234,8 @@ unknown-option = "x"
 
     ----- stderr -----
     jarl failed
-      Cause: Failed to parse [TEMP_DIR]/jarl.toml:
-    TOML parse error at line 5, column 1
-      |
-    5 | unknown-option = "x"
-      | ^^^^^^^^^^^^^^
-    unknown field `unknown-option`, expected `pipe`
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:5:1: Unknown field `unknown-option` in `[lint.pipe_consistency]`. Expected one of: `pipe`.
     "#
     );
 
@@ -331,12 +315,8 @@ unknown-option = "x"
 
     ----- stderr -----
     jarl failed
-      Cause: Failed to parse [TEMP_DIR]/jarl.toml:
-    TOML parse error at line 5, column 1
-      |
-    5 | unknown-option = "x"
-      | ^^^^^^^^^^^^^^
-    unknown field `unknown-option`, expected `quote`
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    [TEMP_DIR]/jarl.toml:5:1: Unknown field `unknown-option` in `[lint.quotes]`. Expected one of: `quote`.
     "#
     );
 
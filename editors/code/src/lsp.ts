@@ -131,6 +131,8 @@ export class Lsp {
 				// Quarto files (language ID registered by the Quarto extension or Positron)
 				{ language: "quarto", scheme: "file" },
 				{ pattern: "**/*.{qmd,Qmd}", scheme: "file" },
+				// Config files, to report unknown fields and rules
+				{ pattern: "**/{jarl,.jarl}.toml", scheme: "file" },
 			],
 			outputChannel: this.channel,
 			initializationOptions: initializationOptions,